@@ -77,19 +77,15 @@ fn build_household_context(data_dir: &Path) -> Result<String, ChatError> {
     ctx.push_str(&members.join(", "));
     ctx.push('\n');
 
-    // Giant items summary
-    let tier1 = household.giant_items_by_tier(FrequencyTier::EveryOrder);
-    ctx.push_str(&format!(
-        "\nGiant: {} recurring items ({} staples/every-order)\n",
-        household.giant_recurring.len(),
-        tier1.len()
-    ));
-
-    // Amazon summary
-    if !household.amazon_recurring.is_empty() {
+    // Recurring items summary, per store
+    ctx.push('\n');
+    for store in household.recurring_stores() {
+        let staples = household.items_by_tier(store, FrequencyTier::EveryOrder);
         ctx.push_str(&format!(
-            "Amazon: {} recurring items\n",
-            household.amazon_recurring.len()
+            "{}: {} recurring items ({} staples/every-order)\n",
+            store,
+            household.items_for_store(store).len(),
+            staples.len()
         ));
     }
 
@@ -110,7 +106,7 @@ fn build_household_context(data_dir: &Path) -> Result<String, ChatError> {
         }
     }
     let mut proteins: Vec<_> = protein_counts.into_iter().collect();
-    proteins.sort_by(|a, b| b.1.cmp(&a.1));
+    proteins.sort_by_key(|p| std::cmp::Reverse(p.1));
 
     if !proteins.is_empty() {
         ctx.push_str("Top proteins: ");
@@ -155,13 +151,11 @@ async fn stream_response(session: &mut Session) -> Result<bool, butler_claude::E
     while let Some(msg) = session.next_message().await? {
         if let ClaudeMessage::Assistant(ref assistant) = msg {
             // Prefer content_block for streaming display
-            if let Some(ref block) = assistant.content_block {
-                if let butler_claude::ContentBlock::Text { ref text } = block {
-                    print!("{text}");
-                    let _ = std::io::stdout().flush();
-                    had_text = true;
-                    had_content_block = true;
-                }
+            if let Some(butler_claude::ContentBlock::Text { ref text }) = assistant.content_block {
+                print!("{text}");
+                let _ = std::io::stdout().flush();
+                had_text = true;
+                had_content_block = true;
             }
 
             // Fall back to full message text if no content_block was streamed
@@ -0,0 +1,55 @@
+use std::path::Path;
+
+use grocery_core::check::check_household_model;
+use grocery_core::diagnostics::{Diagnostic, Severity};
+use grocery_recipes::{check_recipe_links, DimensionRegistry};
+
+type Checker<'a> = &'a dyn Fn(&str) -> Vec<Diagnostic>;
+
+/// Run the `check` subcommand.
+///
+/// Prints every problem as `file:line:column: severity: message` and fails
+/// if any errors were found. Scoring weights are checked against
+/// `dimensions`.
+pub fn run(data_dir: &Path, dimensions: &DimensionRegistry) -> Result<(), Box<dyn std::error::Error>> {
+    let check_scoring_config = |yaml: &str| dimensions.check_config(yaml);
+    // Data files `check` validates, with the checker for each
+    let checks: [(&str, Checker); 3] = [
+        ("household-model.yaml", &check_household_model),
+        ("recipe-scoring-config.yaml", &check_scoring_config),
+        ("recipe-links.json", &check_recipe_links),
+    ];
+
+    let mut errors = 0;
+    let mut warnings = 0;
+
+    for (file, check) in checks {
+        let path = data_dir.join(file);
+        let diagnostics = match std::fs::read_to_string(&path) {
+            Ok(content) => check(&content),
+            Err(e) => {
+                println!("{}: error: {e}", path.display());
+                errors += 1;
+                continue;
+            }
+        };
+
+        for diagnostic in &diagnostics {
+            println!("{}:{diagnostic}", path.display());
+            match diagnostic.severity {
+                Severity::Error => errors += 1,
+                Severity::Warning => warnings += 1,
+            }
+        }
+    }
+
+    println!(
+        "\nChecked {} files: {errors} errors, {warnings} warnings",
+        checks.len()
+    );
+
+    if errors > 0 {
+        return Err(format!("{errors} errors in data files").into());
+    }
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use std::path::Path;
+
+use chrono::NaiveDate;
+use grocery_core::cooking::{CookingEvent, CookingLog};
+use grocery_recipes::{RecipeCollection, CURRENT_VERSION};
+
+/// Run the `cooked` subcommand.
+///
+/// Appends the event to cooking-log.jsonl, then folds it into the recipe's
+/// `times_made`, `last_made`, `rating` and `feedback` and atomically
+/// rewrites recipe-links.json. The log is written first, so it stays the
+/// full record even if the rewrite fails.
+pub fn run(
+    data_dir: &Path,
+    recipe_name: &str,
+    rating: Option<u8>,
+    note: Option<String>,
+    date: Option<NaiveDate>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let recipes_path = data_dir.join("recipe-links.json");
+    let mut collection = RecipeCollection::from_json_file(&recipes_path)?;
+
+    let results = collection.find_by_name(recipe_name);
+    let (index, recipe, similarity) = results
+        .first()
+        .ok_or_else(|| format!("No recipe found matching \"{}\"", recipe_name))?;
+    eprintln!("Matched \"{}\" -> \"{}\" ({:.0}%)", recipe_name, recipe.name, similarity * 100.0);
+
+    let event = CookingEvent {
+        recipe: recipe.name.clone(),
+        date: date.unwrap_or_else(|| chrono::Local::now().date_naive()),
+        rating,
+        note,
+    };
+    let index = *index;
+
+    CookingLog::append(&data_dir.join("cooking-log.jsonl"), &event)?;
+    let updated = collection
+        .record_cooked(index, &event)
+        .ok_or_else(|| format!("recipe #{index} disappeared from the collection"))?;
+    let times = if updated.times_made == 1 { "time" } else { "times" };
+    let mut summary = format!("Recorded {} on {} (made {} {times}", updated.name, event.date, updated.times_made);
+    if let Some(rating) = updated.rating {
+        summary.push_str(&format!(", rated {rating}/5"));
+    }
+    summary.push(')');
+
+    if collection.schema_version() != CURRENT_VERSION {
+        eprintln!("Note: rewriting {} as schema v{CURRENT_VERSION}", recipes_path.display());
+    }
+    collection.save(&recipes_path)?;
+    println!("{summary}");
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use std::path::Path;
+
+use chrono::Datelike;
+use grocery_core::cooking::CookingLog;
+use grocery_core::order::{parse_order_history, OrderHistory};
+use grocery_core::types::Store;
+
+/// Run the `history import` subcommand.
+///
+/// Parses an order history markdown file and merges its orders into
+/// `order-history.json` in the data directory.
+pub fn import(
+    data_dir: &Path,
+    file: Option<&Path>,
+    store: &Store,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let source = file.map_or_else(|| data_dir.join("grocery-orders-history.md"), Path::to_path_buf);
+    let markdown = std::fs::read_to_string(&source)
+        .map_err(|e| format!("{}: {e}", source.display()))?;
+
+    let parsed = parse_order_history(&markdown, store);
+
+    for error in &parsed.errors {
+        eprintln!("  {}:{}", source.display(), error);
+    }
+
+    if parsed.orders.is_empty() {
+        return Err(format!("no orders found in {}", source.display()).into());
+    }
+
+    let history_path = data_dir.join("order-history.json");
+    let mut history = if history_path.exists() {
+        OrderHistory::from_file(&history_path)?
+    } else {
+        OrderHistory::default()
+    };
+
+    let parsed_count = parsed.orders.len();
+    let line_count: usize = parsed.orders.iter().map(|o| o.lines.len()).sum();
+    let added = history.merge(parsed.orders);
+    history.save(&history_path)?;
+
+    println!(
+        "Imported {} orders ({} new, {} lines) from {}",
+        parsed_count,
+        added,
+        line_count,
+        source.display()
+    );
+    if !parsed.errors.is_empty() {
+        println!("  {} lines skipped with errors (see above)", parsed.errors.len());
+    }
+    println!("Order history: {} orders in {}", history.len(), history_path.display());
+
+    Ok(())
+}
+
+/// Run the `history cooked` subcommand.
+///
+/// Lists cooking-log.jsonl by week, most recent `weeks` weeks.
+pub fn cooked(data_dir: &Path, weeks: usize) -> Result<(), Box<dyn std::error::Error>> {
+    let path = data_dir.join("cooking-log.jsonl");
+    let log = CookingLog::from_file(&path).map_err(|e| match e.location() {
+        Some(location) => format!("{}:{location}: {e}", path.display()),
+        None => format!("{}: {e}", path.display()),
+    })?;
+    if log.events.is_empty() {
+        println!("Nothing cooked yet. Record meals with `grocery cooked \"<recipe>\"`.");
+        return Ok(());
+    }
+
+    let by_week = log.by_week();
+    for (i, (week, events)) in by_week.iter().rev().take(weeks).rev().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        let meals = if events.len() == 1 { "meal" } else { "meals" };
+        println!("Week of {week} ({} {meals})", events.len());
+        for event in events {
+            let mut line = format!("  {} {}  {}", event.date.weekday(), event.date, event.recipe);
+            if let Some(rating) = event.rating {
+                line.push_str(&format!("  {rating}/5"));
+            }
+            if let Some(note) = &event.note {
+                line.push_str(&format!("  \"{note}\""));
+            }
+            println!("{line}");
+        }
+    }
+    Ok(())
+}
@@ -1,4 +1,10 @@
 pub mod chat;
+pub mod check;
+pub mod cooked;
+pub mod history;
+pub mod model;
 pub mod plan;
+pub mod recipes;
 pub mod score;
 pub mod status;
+pub mod suggest;
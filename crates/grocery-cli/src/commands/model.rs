@@ -0,0 +1,114 @@
+use std::path::Path;
+
+use grocery_core::frequency::{refresh_frequencies, DecayModel, HistoryWindow};
+use grocery_core::household::HouseholdModel;
+use grocery_core::order::OrderHistory;
+
+/// Run the `model refresh` subcommand.
+///
+/// Recomputes every recurring item's frequency and recency-weighted
+/// propensity from its own store's orders in `order-history.json` and writes
+/// the result back to `household-model.yaml`. Stores with no orders in the
+/// window are left unchanged.
+pub fn refresh(
+    data_dir: &Path,
+    window: HistoryWindow,
+    decay: &DecayModel,
+    dry_run: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let history_path = data_dir.join("order-history.json");
+    if !history_path.exists() {
+        return Err(format!(
+            "{} not found; run `grocery history import` first",
+            history_path.display()
+        )
+        .into());
+    }
+    let history = OrderHistory::from_file(&history_path)?;
+
+    let model_path = data_dir.join("household-model.yaml");
+    let mut model = HouseholdModel::from_file(&model_path)?;
+
+    let stores: Vec<_> = model.recurring_stores().into_iter().cloned().collect();
+    let mut refreshed = 0;
+    for store in &stores {
+        let orders = window.select(&history.for_store(store));
+        let item_count = model.items_for_store(store).len();
+        if orders.is_empty() {
+            println!("{store}: no orders in the selected window; {item_count} items left unchanged\n");
+            continue;
+        }
+        refreshed += 1;
+
+        let report = refresh_frequencies(&mut model, store, &orders, decay);
+
+        println!(
+            "{store}: refreshed {} of {item_count} items from {} orders (half-life {:.1} weeks)",
+            report.updated,
+            report.orders_counted,
+            decay.half_life_days / 7.0
+        );
+
+        if report.changes.is_empty() {
+            println!("No tier changes.");
+        } else {
+            println!("\nTier changes:");
+            for change in &report.changes {
+                println!(
+                    "  {:<40} {:?} ({}) → {:?} ({}, propensity {:.2})",
+                    change.item,
+                    change.old_tier,
+                    change.old_frequency.as_deref().unwrap_or("none"),
+                    change.new_tier,
+                    change.new_frequency,
+                    change.new_propensity
+                );
+            }
+        }
+
+        if !report.unmatched.is_empty() {
+            println!("\nNot found in any order (left unchanged):");
+            for item in &report.unmatched {
+                println!("  {item}");
+            }
+        }
+        println!();
+    }
+
+    if refreshed == 0 {
+        return Err("no orders for any recurring store in the selected window".into());
+    }
+
+    if dry_run {
+        println!("Dry run: {} not modified", model_path.display());
+    } else {
+        model.save(&model_path)?;
+        println!("Updated {}", model_path.display());
+    }
+
+    Ok(())
+}
+
+/// Run the `model migrate` subcommand.
+///
+/// Older files list recurring items under `giant_recurring`,
+/// `amazon_recurring` and `acme_recurring`; they already load into the
+/// unified list, and this writes them back that way.
+pub fn migrate(data_dir: &Path, dry_run: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let path = data_dir.join("household-model.yaml");
+    let model = HouseholdModel::from_file(&path)?;
+
+    for store in model.recurring_stores() {
+        println!("  {store}: {} recurring items", model.items_for_store(store).len());
+    }
+    println!("Loaded {} recurring items", model.recurring.len());
+
+    if dry_run {
+        println!("Dry run: {} not modified", path.display());
+        return Ok(());
+    }
+
+    model.save(&path)?;
+    println!("Rewrote {} with a single `recurring` list", path.display());
+    Ok(())
+}
@@ -1,9 +1,17 @@
 use std::path::Path;
 
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+use grocery_core::diet::Diners;
 use grocery_core::household::HouseholdModel;
+use grocery_core::ingredient::format_number;
+use grocery_core::order::OrderHistory;
+use grocery_core::replenish::{predict_replenishments, DEFAULT_LOOKAHEAD_DAYS};
 use grocery_core::scoring::ScoringConfig;
-use grocery_list::{consolidate, generate_list, ShoppingList};
-use grocery_recipes::RecipeCollection;
+use grocery_list::{
+    apply_package_sizes, consolidate, generate_list, replenishment_items, route_to_stores,
+    ShoppingList,
+};
+use grocery_recipes::{plan_meals, DimensionRegistry, MealPlan, PlanConstraints, RecipeCollection};
 
 /// Output format for the shopping list.
 #[derive(Clone, Debug, Default)]
@@ -26,13 +34,34 @@ impl std::str::FromStr for OutputFormat {
     }
 }
 
+/// What goes into a planned list besides the recipes.
+#[derive(Clone, Debug, Default)]
+pub struct PlanOptions {
+    pub include_staples: bool,
+    pub include_replenishment: bool,
+    /// Day the meals are for, to check vegetarian days.
+    pub day: Option<Weekday>,
+    /// Servings to scale recipes to; sized to the household when `None`.
+    pub servings: Option<u32>,
+    /// Let the planner pick this many recipes instead of naming them.
+    pub auto: Option<usize>,
+}
+
 /// Run the `plan` subcommand.
 pub fn run(
     data_dir: &Path,
     recipe_names: &[String],
     format: &OutputFormat,
-    include_staples: bool,
+    options: &PlanOptions,
+    dimensions: &DimensionRegistry,
 ) -> Result<(), Box<dyn std::error::Error>> {
+    let PlanOptions {
+        include_staples,
+        include_replenishment,
+        day,
+        servings,
+        auto,
+    } = *options;
     let household = HouseholdModel::from_file(&data_dir.join("household-model.yaml"))?;
     let config = ScoringConfig::from_file(&data_dir.join("recipe-scoring-config.yaml"))?;
     let collection = RecipeCollection::from_json_file(&data_dir.join("recipe-links.json"))?;
@@ -49,22 +78,71 @@ pub fn run(
         }
     }
 
-    if matched_recipes.is_empty() && !include_staples {
+    let mut diners = Diners::everyone(&household.family);
+    diners.day = day;
+    if let Some(meals) = auto {
+        let today = chrono::Local::now().date_naive();
+        let start = first_day(today, day);
+        let plan = plan_meals(&collection, &config, dimensions, &diners, &PlanConstraints::new(start, meals));
+        print_plan(&plan, meals);
+        matched_recipes.extend(plan.recipes());
+    } else {
+        for recipe in &matched_recipes {
+            for conflict in diners.conflicts(recipe) {
+                let level = if conflict.hard { "Warning" } else { "Note" };
+                eprintln!("  {level}: {}: {conflict}", recipe.name);
+            }
+        }
+    }
+
+    if matched_recipes.is_empty() && !include_staples && !include_replenishment {
         eprintln!("No recipes matched and staples disabled. Nothing to generate.");
         return Ok(());
     }
 
+    let servings = servings.map(f64::from).or_else(|| household.family.servings());
+    if let Some(target) = servings {
+        for recipe in &matched_recipes {
+            match (recipe.servings_range(), recipe.scale_factor(target)) {
+                (Some(serves), Some(factor)) if (factor - 1.0).abs() > f64::EPSILON => eprintln!(
+                    "  Scaling \"{}\" from {} to {} servings (×{})",
+                    recipe.name,
+                    serves.min,
+                    format_number(target),
+                    format_number(factor),
+                ),
+                (None, _) if recipe.has_ingredients() => eprintln!(
+                    "  Note: \"{}\" doesn't say how many it serves; not scaled",
+                    recipe.name
+                ),
+                _ => {}
+            }
+        }
+    }
+
     // Generate the list
-    let household_for_gen = if include_staples {
-        &household
-    } else {
-        // Use an empty household to skip staples
-        &HouseholdModel::from_yaml("family:\n  members: []\nstores: {}")?
-    };
+    let mut items = generate_list(&matched_recipes, &household, &config, servings, include_staples);
+
+    if include_replenishment {
+        let history_path = data_dir.join("order-history.json");
+        let history = if history_path.exists() {
+            Some(OrderHistory::from_file(&history_path)?)
+        } else {
+            None
+        };
+        let predictions = predict_replenishments(&household, history.as_ref());
+        let today = chrono::Local::now().date_naive();
+        let due = replenishment_items(&predictions, today, DEFAULT_LOOKAHEAD_DAYS);
+        if !due.is_empty() {
+            eprintln!("  Adding {} cycle items due within {DEFAULT_LOOKAHEAD_DAYS} days", due.len());
+        }
+        items.extend(due);
+    }
 
-    let items = generate_list(&matched_recipes, household_for_gen, &config);
     let items = consolidate(items);
-    let list = ShoppingList::new(items);
+    let items = apply_package_sizes(items, &config);
+    let items = route_to_stores(items, &household);
+    let list = ShoppingList::new(items).with_layouts(&household.stores);
 
     if list.is_empty() {
         eprintln!("Shopping list is empty — selected recipes may not have ingredient data.");
@@ -80,3 +158,35 @@ pub fn run(
 
     Ok(())
 }
+
+/// The plan's first day: the next `day` after today, or tomorrow.
+fn first_day(today: NaiveDate, day: Option<Weekday>) -> NaiveDate {
+    let tomorrow = today.succ_opt().unwrap_or(today);
+    let Some(day) = day else {
+        return tomorrow;
+    };
+    let ahead = (7 + day.num_days_from_monday() - tomorrow.weekday().num_days_from_monday()) % 7;
+    tomorrow.checked_add_days(Days::new(u64::from(ahead))).unwrap_or(tomorrow)
+}
+
+/// Each pick with its reasons, then what was passed over and why.
+fn print_plan(plan: &MealPlan<'_>, wanted: usize) {
+    eprintln!(
+        "  Planned {} of {wanted} meals (total score {:.0})",
+        plan.meals.len(),
+        plan.total_score()
+    );
+    for meal in &plan.meals {
+        eprintln!("  {} {}: {}", meal.date.weekday(), meal.date, meal.recipe.name);
+        eprintln!("      {}", meal.reasons.join("; "));
+    }
+    if !plan.passed_over.is_empty() {
+        eprintln!("  Passed over:");
+        for skipped in &plan.passed_over {
+            eprintln!("      {} ({:.0}): {}", skipped.recipe.name, skipped.overall, skipped.reason);
+        }
+    }
+    if plan.meals.len() < wanted {
+        eprintln!("  Warning: not enough recipes fit the constraints for {wanted} meals");
+    }
+}
@@ -0,0 +1,43 @@
+use std::path::Path;
+
+use grocery_recipes::{RecipeCollection, CURRENT_VERSION};
+
+/// Run the `recipes migrate` subcommand.
+///
+/// Loads recipe-links.json in whatever schema version it's in and rewrites it
+/// in the current canonical form, keeping the original as `.json.bak`.
+/// Unknown keys are carried over; if any record can't be read the file is
+/// left alone, since rewriting it would drop that record.
+pub fn migrate(data_dir: &Path, dry_run: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let path = data_dir.join("recipe-links.json");
+    let collection = RecipeCollection::from_json_file(&path)?;
+
+    for warning in collection.warnings() {
+        eprintln!("  {}: {warning}", path.display());
+    }
+
+    println!(
+        "Loaded {} recipes from schema v{} ({} warnings)",
+        collection.len(),
+        collection.schema_version(),
+        collection.warnings().len()
+    );
+
+    let skipped = collection.skipped().count();
+    if skipped > 0 {
+        return Err(format!(
+            "{skipped} record(s) couldn't be read and would be dropped; fix the skipped records above, then migrate again"
+        )
+        .into());
+    }
+
+    if dry_run {
+        println!("Dry run: {} not modified", path.display());
+        return Ok(());
+    }
+
+    std::fs::copy(&path, path.with_extension("json.bak"))?;
+    collection.save(&path)?;
+    println!("Rewrote {} as schema v{CURRENT_VERSION}", path.display());
+    Ok(())
+}
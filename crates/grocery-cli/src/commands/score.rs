@@ -1,13 +1,22 @@
 use std::path::Path;
 
+use chrono::Weekday;
+use grocery_core::diet::Diners;
+use grocery_core::household::HouseholdModel;
+use grocery_core::recipe::Recipe;
 use grocery_core::scoring::ScoringConfig;
-use grocery_recipes::{score_recipe, RecipeCollection};
+use grocery_core::stage::{AgeStage, Suitability};
+use grocery_recipes::{DimensionRegistry, IngredientTrace, RecipeCollection, ScoringContext};
 
 /// Run the `score` subcommand.
 pub fn run(
     data_dir: &Path,
     recipe_name: &str,
+    day: Option<Weekday>,
+    explain: bool,
+    dimensions: &DimensionRegistry,
 ) -> Result<(), Box<dyn std::error::Error>> {
+    let household = HouseholdModel::from_file(&data_dir.join("household-model.yaml"))?;
     let config = ScoringConfig::from_file(&data_dir.join("recipe-scoring-config.yaml"))?;
     let collection = RecipeCollection::from_json_file(&data_dir.join("recipe-links.json"))?;
 
@@ -22,7 +31,20 @@ pub fn run(
         eprintln!("Warning: this recipe has no ingredient data — scoring will be limited.\n");
     }
 
-    let result = score_recipe(recipe, &config);
+    let mut diners = Diners::everyone(&household.family);
+    diners.day = day;
+    let conflicts = diners.conflicts(recipe);
+    for conflict in &conflicts {
+        let level = if conflict.hard { "Warning" } else { "Note" };
+        eprintln!("{level}: {conflict}");
+    }
+    if !conflicts.is_empty() {
+        eprintln!();
+    }
+
+    let today = chrono::Local::now().date_naive();
+    let context = ScoringContext::new(diners.clone(), today);
+    let result = dimensions.score(recipe, &config, &context);
 
     println!("{}: {:.0}/100 — {}", recipe.name, result.overall, result.label);
     println!();
@@ -35,9 +57,20 @@ pub fn run(
             dim.weight * 100.0,
             dim.weighted,
         );
+        let notes = dimensions
+            .get(dim.key)
+            .map(|d| d.explain(recipe, &config, &context))
+            .unwrap_or_default();
+        for note in notes {
+            println!("      {note}");
+        }
     }
 
     println!();
+    if explain {
+        print_trace(&result.ingredients);
+    }
+    print_stage_notes(recipe, &diners);
 
     if let Some(ref protein) = recipe.primary_protein {
         println!("  Primary protein: {}", protein);
@@ -53,3 +86,65 @@ pub fn run(
 
     Ok(())
 }
+
+/// One line per ingredient: what it matched and the points it earned or
+/// cost, then the ones ingredient_map is missing.
+fn print_trace(trace: &[IngredientTrace]) {
+    if trace.is_empty() {
+        println!("  No ingredients to trace.\n");
+        return;
+    }
+
+    println!("  Ingredient trace:");
+    for t in trace {
+        let matched = match (&t.key, &t.alias, t.tier) {
+            (Some(key), Some(alias), Some(tier)) => format!("{key} via {alias:?}, tier {tier}"),
+            _ => "unmapped".to_string(),
+        };
+        let mut effects = vec![format!("{:+.1} overlap", t.points)];
+        if !t.boosters.is_empty() {
+            effects.push(format!("{:+.0} boosters ({})", t.booster_bonus, t.boosters.join(", ")));
+        }
+        if t.friction < 0.0 {
+            effects.push(format!("{:+.0} friction", t.friction));
+        }
+        println!("    {:<28} {:<36} {}", t.ingredient, matched, effects.join(", "));
+    }
+
+    let unmapped: Vec<&str> = trace
+        .iter()
+        .filter(|t| t.key.is_none())
+        .map(|t| t.ingredient.as_str())
+        .collect();
+    if !unmapped.is_empty() {
+        println!("  Not in ingredient_map: {}", unmapped.join(", "));
+    }
+    println!();
+}
+
+/// How the recipe works for each child at the table, with ways to adapt it.
+fn print_stage_notes(recipe: &Recipe, diners: &Diners<'_>) {
+    let mut printed = false;
+    for member in diners.members.iter().filter(|m| m.stage() < AgeStage::Adult) {
+        let suitability = Suitability::for_member(recipe, member);
+        let stage = suitability.stage;
+        let verdict = if suitability.concerns.is_empty() {
+            format!("{stage}-friendly as is")
+        } else if suitability.is_adaptable() {
+            format!("{stage}-adaptable")
+        } else {
+            format!("not {stage}-friendly")
+        };
+        println!("  {} ({stage}): {verdict}", member.name);
+        for concern in &suitability.concerns {
+            match &concern.adaptation {
+                Some(fix) => println!("    - {fix}: {:?}", concern.ingredient),
+                None => println!("    - {concern}"),
+            }
+        }
+        printed = true;
+    }
+    if printed {
+        println!();
+    }
+}
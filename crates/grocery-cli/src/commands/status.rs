@@ -20,27 +20,31 @@ pub fn run(data_dir: &Path) -> Result<(), Box<dyn std::error::Error>> {
     }
     println!();
 
-    // Giant recurring items by tier
-    let tier1 = household.giant_items_by_tier(FrequencyTier::EveryOrder);
-    let tier2 = household.giant_items_by_tier(FrequencyTier::MostOrders);
-    let tier3 = household.giant_items_by_tier(FrequencyTier::Occasional);
-    let rare = household.giant_items_by_tier(FrequencyTier::Rare);
-
-    println!("Giant Recurring Items: {} total", household.giant_recurring.len());
-    println!("  Every order (tier 1): {}", tier1.len());
-    println!("  Most orders (tier 2): {}", tier2.len());
-    println!("  Occasional  (tier 3): {}", tier3.len());
-    println!("  Rare:                 {}", rare.len());
-    println!();
+    // Recurring items by store and tier
+    for store in household.recurring_stores() {
+        let tier1 = household.items_by_tier(store, FrequencyTier::EveryOrder);
+        let tier2 = household.items_by_tier(store, FrequencyTier::MostOrders);
+        let tier3 = household.items_by_tier(store, FrequencyTier::Occasional);
+        let rare = household.items_by_tier(store, FrequencyTier::Rare);
 
-    // Amazon recurring
-    println!("Amazon Recurring Items: {}", household.amazon_recurring.len());
-    println!();
+        println!("{} Recurring Items: {} total", store, household.items_for_store(store).len());
+        println!("  Every order (tier 1): {}", tier1.len());
+        println!("  Most orders (tier 2): {}", tier2.len());
+        println!("  Occasional  (tier 3): {}", tier3.len());
+        println!("  Rare:                 {}", rare.len());
+        println!();
+    }
 
     // Recipe collection stats
     let total = collection.len();
     let with_ingredients = collection.with_ingredients().len();
     println!("Recipe Collection: {} total ({} with ingredients)", total, with_ingredients);
+    if !collection.warnings().is_empty() {
+        println!(
+            "  {} records need attention (run `grocery recipes migrate --dry-run` for details)",
+            collection.warnings().len()
+        );
+    }
 
     // Top proteins
     let mut protein_counts: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
@@ -50,7 +54,7 @@ pub fn run(data_dir: &Path) -> Result<(), Box<dyn std::error::Error>> {
         }
     }
     let mut proteins: Vec<_> = protein_counts.into_iter().collect();
-    proteins.sort_by(|a, b| b.1.cmp(&a.1));
+    proteins.sort_by_key(|p| std::cmp::Reverse(p.1));
 
     if !proteins.is_empty() {
         println!("  Top proteins:");
@@ -0,0 +1,82 @@
+use std::path::Path;
+
+use chrono::Weekday;
+use grocery_core::diet::Diners;
+use grocery_core::household::HouseholdModel;
+use grocery_core::recipe::Recipe;
+use grocery_core::scoring::ScoringConfig;
+use grocery_recipes::{suggest_complements, DimensionRegistry, RecipeCollection, ScoringContext, Sharing};
+
+/// Run the `suggest` subcommand.
+pub fn run(
+    data_dir: &Path,
+    with: &[String],
+    limit: usize,
+    day: Option<Weekday>,
+    dimensions: &DimensionRegistry,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let household = HouseholdModel::from_file(&data_dir.join("household-model.yaml"))?;
+    let config = ScoringConfig::from_file(&data_dir.join("recipe-scoring-config.yaml"))?;
+    let collection = RecipeCollection::from_json_file(&data_dir.join("recipe-links.json"))?;
+
+    let mut fixed: Vec<&Recipe> = Vec::new();
+    for name in with {
+        let results = collection.find_by_name(name);
+        let (_, recipe, similarity) = results
+            .first()
+            .ok_or_else(|| format!("No recipe found matching \"{}\"", name))?;
+        eprintln!("Matched \"{}\" -> \"{}\" ({:.0}%)", name, recipe.name, similarity * 100.0);
+        fixed.push(*recipe);
+    }
+    eprintln!();
+
+    let sharing = Sharing::of(&fixed, &config);
+    let bought: Vec<&str> = sharing
+        .shared
+        .keys()
+        .chain(&sharing.leftover)
+        .map(String::as_str)
+        .collect();
+    if bought.is_empty() {
+        println!("No perishables in the ingredient map for these recipes; nothing to use up.");
+        return Ok(());
+    }
+    println!("Perishables bought: {}", bought.join(", "));
+    if fixed.len() > 1 {
+        println!("Already shared: {:.0}%", sharing.score());
+    }
+    println!();
+
+    let mut diners = Diners::everyone(&household.family);
+    diners.day = day;
+    let context = ScoringContext::new(diners, chrono::Local::now().date_naive());
+    let suggestions = suggest_complements(&collection, &fixed, &config, dimensions, &context, limit);
+    if suggestions.is_empty() {
+        println!("No other recipes use these perishables.");
+        return Ok(());
+    }
+
+    for (i, suggestion) in suggestions.iter().enumerate() {
+        println!("{}. {} ({:.0}/100)", i + 1, suggestion.recipe.name, suggestion.overall);
+        println!("     uses up: {}", suggestion.uses_up.join(", "));
+        if !suggestion.adds.is_empty() {
+            println!("     adds: {}", suggestion.adds.join(", "));
+        }
+    }
+
+    if let Some(best) = suggestions.first() {
+        let mut with_best = fixed.clone();
+        with_best.push(best.recipe);
+        let after = Sharing::of(&with_best, &config);
+        if !after.leftover.is_empty() {
+            println!();
+            println!(
+                "Still left over with {}: {}",
+                best.recipe.name,
+                after.leftover.iter().cloned().collect::<Vec<_>>().join(", ")
+            );
+        }
+    }
+
+    Ok(())
+}
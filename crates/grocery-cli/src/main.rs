@@ -1,7 +1,11 @@
 use std::path::PathBuf;
 use std::process;
 
+use chrono::{NaiveDate, Weekday};
 use clap::{Parser, Subcommand};
+use grocery_core::frequency::{DecayModel, HistoryWindow, DEFAULT_HALF_LIFE_WEEKS};
+use grocery_core::types::Store;
+use grocery_recipes::DimensionRegistry;
 
 mod commands;
 
@@ -21,9 +25,13 @@ enum Commands {
     /// Generate a shopping list from recipes + household staples
     Plan {
         /// Recipe names to include (fuzzy matched)
-        #[arg(required = true)]
+        #[arg(required_unless_present = "auto")]
         recipes: Vec<String>,
 
+        /// Pick this many recipes automatically, one per day from --day (or tomorrow)
+        #[arg(long, value_name = "N")]
+        auto: Option<usize>,
+
         /// Output format: text, json, or compact
         #[arg(long, default_value = "text")]
         format: String,
@@ -31,17 +39,91 @@ enum Commands {
         /// Exclude household staples from the list
         #[arg(long)]
         no_staples: bool,
+
+        /// Don't add Amazon/Giant cycle items that are due for replenishment
+        #[arg(long)]
+        no_replenish: bool,
+
+        /// Day the meals are for (e.g. fri), to check vegetarian days
+        #[arg(long)]
+        day: Option<Weekday>,
+
+        /// Servings to scale each recipe to (default: sized to the household)
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+        servings: Option<u32>,
     },
 
     /// Score a recipe against household purchasing patterns
     Score {
         /// Recipe name (fuzzy matched)
         recipe: String,
+
+        /// Day the meal is for (e.g. fri), to check vegetarian days
+        #[arg(long)]
+        day: Option<Weekday>,
+
+        /// Show how each ingredient matched ingredient_map and what it earned
+        #[arg(long)]
+        explain: bool,
+    },
+
+    /// Suggest recipes that use up the perishables bought for the given ones
+    Suggest {
+        /// Recipe(s) already planned (fuzzy matched; repeat for several)
+        #[arg(long = "with", value_name = "RECIPE", required = true)]
+        with: Vec<String>,
+
+        /// Number of suggestions to show
+        #[arg(long, default_value_t = 5)]
+        limit: usize,
+
+        /// Day the meal is for (e.g. fri), to check vegetarian days
+        #[arg(long)]
+        day: Option<Weekday>,
     },
 
     /// Show household model and recipe collection stats
     Status,
 
+    /// Validate data files and report problems with file, line and column
+    Check,
+
+    /// Record that we cooked a recipe, updating its stats in recipe-links.json
+    Cooked {
+        /// Recipe name (fuzzy matched)
+        recipe: String,
+
+        /// Star rating, 0-5
+        #[arg(long, value_parser = clap::value_parser!(u8).range(0..=5))]
+        rating: Option<u8>,
+
+        /// Feedback to keep with the recipe
+        #[arg(long)]
+        note: Option<String>,
+
+        /// When it was cooked, YYYY-MM-DD (default: today)
+        #[arg(long)]
+        date: Option<NaiveDate>,
+    },
+
+    /// Import and query past grocery orders
+    History {
+        #[command(subcommand)]
+        action: HistoryAction,
+    },
+
+    /// Maintain recipe-links.json
+    Recipes {
+        #[command(subcommand)]
+        action: RecipesAction,
+    },
+
+    /// Maintain household-model.yaml
+    Model {
+        #[command(subcommand)]
+        action: ModelAction,
+    },
+
     /// Chat with Cart Blanche (Claude + household context)
     Chat {
         /// Initial message (omit for interactive REPL)
@@ -58,6 +140,65 @@ enum Commands {
     },
 }
 
+#[derive(Subcommand)]
+enum HistoryAction {
+    /// Parse an order history markdown file and save it to order-history.json
+    Import {
+        /// Markdown file to import (default: <data-dir>/grocery-orders-history.md)
+        #[arg(long)]
+        file: Option<PathBuf>,
+
+        /// Store the orders were placed at
+        #[arg(long, default_value = "giant")]
+        store: Store,
+    },
+
+    /// Show what we cooked each week, from cooking-log.jsonl
+    Cooked {
+        /// Number of most recent weeks to show
+        #[arg(long, default_value_t = 8)]
+        weeks: usize,
+    },
+}
+
+#[derive(Subcommand)]
+enum RecipesAction {
+    /// Rewrite recipe-links.json in the current schema version
+    Migrate {
+        /// Report what would change without writing the file
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum ModelAction {
+    /// Recompute recurring item frequency tiers from imported order history, per store
+    Refresh {
+        /// Only count the N most recent orders
+        #[arg(long, conflicts_with = "last_weeks")]
+        last_orders: Option<usize>,
+
+        /// Only count orders from the N weeks before the most recent order
+        #[arg(long)]
+        last_weeks: Option<u32>,
+
+        /// Weeks after which an order counts half as much toward propensity
+        #[arg(long, default_value_t = DEFAULT_HALF_LIFE_WEEKS)]
+        half_life_weeks: f64,
+
+        /// Show tier changes without writing household-model.yaml
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Rewrite household-model.yaml with per-store lists merged into `recurring`
+    Migrate {
+        /// Report what would change without writing the file
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
 #[tokio::main]
 async fn main() {
     // Init tracing (try_init to avoid panic if already set)
@@ -75,11 +216,18 @@ async fn main() {
     let state_dir = cli.data_dir.join(".state");
     std::env::set_var("BUTLER_HOME", &state_dir);
 
+    // Every command scores on the same dimensions
+    let dimensions = DimensionRegistry::builtin();
+
     let result = match cli.command {
         Commands::Plan {
             recipes,
             format,
             no_staples,
+            no_replenish,
+            day,
+            servings,
+            auto,
         } => {
             let fmt = match format.parse::<commands::plan::OutputFormat>() {
                 Ok(f) => f,
@@ -88,13 +236,63 @@ async fn main() {
                     process::exit(1);
                 }
             };
-            commands::plan::run(&cli.data_dir, &recipes, &fmt, !no_staples)
-                .map_err(|e| e.to_string())
+            let options = commands::plan::PlanOptions {
+                include_staples: !no_staples,
+                include_replenishment: !no_replenish,
+                day,
+                servings,
+                auto,
+            };
+            commands::plan::run(&cli.data_dir, &recipes, &fmt, &options, &dimensions).map_err(|e| e.to_string())
+        }
+        Commands::Score { recipe, day, explain } => {
+            commands::score::run(&cli.data_dir, &recipe, day, explain, &dimensions).map_err(|e| e.to_string())
         }
-        Commands::Score { recipe } => {
-            commands::score::run(&cli.data_dir, &recipe).map_err(|e| e.to_string())
+        Commands::Suggest { with, limit, day } => {
+            commands::suggest::run(&cli.data_dir, &with, limit, day, &dimensions).map_err(|e| e.to_string())
         }
         Commands::Status => commands::status::run(&cli.data_dir).map_err(|e| e.to_string()),
+        Commands::Check => commands::check::run(&cli.data_dir, &dimensions).map_err(|e| e.to_string()),
+        Commands::Cooked {
+            recipe,
+            rating,
+            note,
+            date,
+        } => commands::cooked::run(&cli.data_dir, &recipe, rating, note, date).map_err(|e| e.to_string()),
+        Commands::History { action } => match action {
+            HistoryAction::Import { file, store } => {
+                commands::history::import(&cli.data_dir, file.as_deref(), &store)
+                    .map_err(|e| e.to_string())
+            }
+            HistoryAction::Cooked { weeks } => {
+                commands::history::cooked(&cli.data_dir, weeks).map_err(|e| e.to_string())
+            }
+        },
+        Commands::Recipes { action } => match action {
+            RecipesAction::Migrate { dry_run } => {
+                commands::recipes::migrate(&cli.data_dir, dry_run).map_err(|e| e.to_string())
+            }
+        },
+        Commands::Model { action } => match action {
+            ModelAction::Refresh {
+                last_orders,
+                last_weeks,
+                half_life_weeks,
+                dry_run,
+            } => {
+                let window = match (last_orders, last_weeks) {
+                    (Some(n), _) => HistoryWindow::LastOrders(n),
+                    (None, Some(weeks)) => HistoryWindow::LastWeeks(weeks),
+                    (None, None) => HistoryWindow::All,
+                };
+                let decay = DecayModel::from_weeks(half_life_weeks);
+                commands::model::refresh(&cli.data_dir, window, &decay, dry_run)
+                    .map_err(|e| e.to_string())
+            }
+            ModelAction::Migrate { dry_run } => {
+                commands::model::migrate(&cli.data_dir, dry_run).map_err(|e| e.to_string())
+            }
+        },
         Commands::Chat {
             initial_message,
             model,
@@ -111,3 +309,7 @@ async fn main() {
         process::exit(1);
     }
 }
+
+#[cfg(test)]
+#[path = "main_tests.rs"]
+mod tests;
@@ -0,0 +1,19 @@
+use super::*;
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+#[test]
+fn test_plan_rejects_zero_servings() {
+    let parsed = Cli::try_parse_from(["grocery", "plan", "tacos", "--servings", "0"]);
+    assert!(parsed.is_err());
+}
+
+#[test]
+fn test_plan_accepts_positive_servings() -> TestResult {
+    let cli = Cli::try_parse_from(["grocery", "plan", "tacos", "--servings", "2"])?;
+    match cli.command {
+        Commands::Plan { servings, .. } => assert_eq!(servings, Some(2)),
+        _ => return Err("expected the plan command".into()),
+    }
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::error::GroceryResult;
+
+/// Replace `path` with `contents` so readers see the old file or the new
+/// one, never a half-written mix.
+///
+/// Writes a sibling temp file, syncs it, then renames it over `path`.
+pub fn write_atomic(path: &Path, contents: &str) -> GroceryResult<()> {
+    let temp = temp_path(path);
+    let result = (|| {
+        let mut file = File::create(&temp)?;
+        file.write_all(contents.as_bytes())?;
+        file.sync_all()?;
+        fs::rename(&temp, path)
+    })();
+    if result.is_err() {
+        let _ = fs::remove_file(&temp);
+    }
+    Ok(result?)
+}
+
+/// `.name.tmp` next to `path`, so the rename stays on one filesystem.
+fn temp_path(path: &Path) -> PathBuf {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    path.with_file_name(format!(".{name}.tmp"))
+}
+
+#[cfg(test)]
+#[path = "atomic_tests.rs"]
+mod tests;
@@ -0,0 +1,18 @@
+use super::*;
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+#[test]
+fn test_write_atomic_replaces_contents() -> TestResult {
+    let dir = std::env::temp_dir().join(format!("grocery-atomic-{}", std::process::id()));
+    fs::create_dir_all(&dir)?;
+    let path = dir.join("recipe-links.json");
+
+    write_atomic(&path, "[1]")?;
+    write_atomic(&path, "[1, 2]")?;
+    assert_eq!(fs::read_to_string(&path)?, "[1, 2]");
+    assert!(!temp_path(&path).exists());
+
+    fs::remove_dir_all(&dir)?;
+    Ok(())
+}
@@ -0,0 +1,298 @@
+use crate::household::HouseholdModel;
+use crate::scoring::IngredientMapping;
+use crate::types::Category;
+
+/// Category for anything no rule recognizes: center-store shelf goods.
+pub const FALLBACK_CATEGORY: Category = Category::Canned;
+
+/// Keywords that place an ingredient in a store category.
+///
+/// Matched as whole words (a trailing "s" or "es" is allowed) against the
+/// lowercased name; the longest match wins, so "coconut milk" beats "milk"
+/// and "chicken broth" beats "chicken".
+const KEYWORDS: &[(&str, Category)] = &[
+    // Produce
+    ("apple", Category::Produce),
+    ("arugula", Category::Produce),
+    ("asparagus", Category::Produce),
+    ("avocado", Category::Produce),
+    ("banana", Category::Produce),
+    ("basil", Category::Produce),
+    ("bean sprout", Category::Produce),
+    ("beet", Category::Produce),
+    ("bell pepper", Category::Produce),
+    ("berries", Category::Produce),
+    ("bok choy", Category::Produce),
+    ("broccoli", Category::Produce),
+    ("butter lettuce", Category::Produce),
+    ("cabbage", Category::Produce),
+    ("carrot", Category::Produce),
+    ("cauliflower", Category::Produce),
+    ("celery", Category::Produce),
+    ("chive", Category::Produce),
+    ("cilantro", Category::Produce),
+    ("corn", Category::Produce),
+    ("cucumber", Category::Produce),
+    ("dill", Category::Produce),
+    ("eggplant", Category::Produce),
+    ("fennel", Category::Produce),
+    ("garlic", Category::Produce),
+    ("ginger", Category::Produce),
+    ("grape", Category::Produce),
+    ("green bean", Category::Produce),
+    ("green onion", Category::Produce),
+    ("herb", Category::Produce),
+    ("jalapeno", Category::Produce),
+    ("jalapeño", Category::Produce),
+    ("kale", Category::Produce),
+    ("leek", Category::Produce),
+    ("lemon", Category::Produce),
+    ("lemon juice", Category::Produce),
+    ("lettuce", Category::Produce),
+    ("lime", Category::Produce),
+    ("lime juice", Category::Produce),
+    ("mango", Category::Produce),
+    ("mint", Category::Produce),
+    ("mushroom", Category::Produce),
+    ("onion", Category::Produce),
+    ("orange", Category::Produce),
+    ("parsley", Category::Produce),
+    ("peach", Category::Produce),
+    ("pear", Category::Produce),
+    ("pineapple", Category::Produce),
+    ("poblano", Category::Produce),
+    ("potato", Category::Produce),
+    ("radish", Category::Produce),
+    ("romaine", Category::Produce),
+    ("rosemary", Category::Produce),
+    ("scallion", Category::Produce),
+    ("serrano", Category::Produce),
+    ("shallot", Category::Produce),
+    ("spinach", Category::Produce),
+    ("squash", Category::Produce),
+    ("strawberries", Category::Produce),
+    ("thyme", Category::Produce),
+    ("tofu", Category::Produce),
+    ("tomato", Category::Produce),
+    ("zucchini", Category::Produce),
+    // Dairy
+    ("butter", Category::Dairy),
+    ("buttermilk", Category::Dairy),
+    ("cheddar", Category::Dairy),
+    ("cheese", Category::Dairy),
+    ("cream", Category::Dairy),
+    ("egg", Category::Dairy),
+    ("feta", Category::Dairy),
+    ("half-and-half", Category::Dairy),
+    ("milk", Category::Dairy),
+    ("mozzarella", Category::Dairy),
+    ("parmesan", Category::Dairy),
+    ("ricotta", Category::Dairy),
+    ("yogurt", Category::Dairy),
+    // Meat and seafood
+    ("bacon", Category::Meat),
+    ("beef", Category::Meat),
+    ("chicken", Category::Meat),
+    ("chorizo", Category::Meat),
+    ("cod", Category::Meat),
+    ("fish", Category::Meat),
+    ("haddock", Category::Meat),
+    ("lamb", Category::Meat),
+    ("pork", Category::Meat),
+    ("salmon", Category::Meat),
+    ("sausage", Category::Meat),
+    ("shrimp", Category::Meat),
+    ("steak", Category::Meat),
+    ("turkey", Category::Meat),
+    // Deli
+    ("ham", Category::Deli),
+    ("hummus", Category::Deli),
+    ("pepperoni", Category::Deli),
+    ("prosciutto", Category::Deli),
+    ("rotisserie chicken", Category::Deli),
+    ("salami", Category::Deli),
+    // Frozen
+    ("edamame", Category::Frozen),
+    ("frozen", Category::Frozen),
+    ("ice cream", Category::Frozen),
+    // Canned and jarred
+    ("beef broth", Category::Canned),
+    ("black bean", Category::Canned),
+    ("broth", Category::Canned),
+    ("chicken bouillon", Category::Canned),
+    ("chicken broth", Category::Canned),
+    ("chicken stock", Category::Canned),
+    ("chickpea", Category::Canned),
+    ("coconut milk", Category::Canned),
+    ("crushed tomato", Category::Canned),
+    ("diced tomato", Category::Canned),
+    ("kidney bean", Category::Canned),
+    ("stock", Category::Canned),
+    ("tomato paste", Category::Canned),
+    ("tomato sauce", Category::Canned),
+    ("tuna", Category::Canned),
+    ("vegetable broth", Category::Canned),
+    // Bread
+    ("bagel", Category::Bread),
+    ("baguette", Category::Bread),
+    ("bread", Category::Bread),
+    ("brioche", Category::Bread),
+    ("bun", Category::Bread),
+    ("naan", Category::Bread),
+    ("pita", Category::Bread),
+    ("roll", Category::Bread),
+    ("tortilla", Category::Bread),
+    // Pasta, rice and grains
+    ("couscous", Category::Pasta),
+    ("egg noodle", Category::Pasta),
+    ("lasagna", Category::Pasta),
+    ("linguine", Category::Pasta),
+    ("noodle", Category::Pasta),
+    ("orzo", Category::Pasta),
+    ("pasta", Category::Pasta),
+    ("penne", Category::Pasta),
+    ("quinoa", Category::Pasta),
+    ("ramen", Category::Pasta),
+    ("rice", Category::Pasta),
+    ("spaghetti", Category::Pasta),
+    ("udon", Category::Pasta),
+    // Beverages
+    ("beer", Category::Beverages),
+    ("coffee", Category::Beverages),
+    ("juice", Category::Beverages),
+    ("tea", Category::Beverages),
+    ("wine", Category::Beverages),
+    // Snacks
+    ("chips", Category::Snacks),
+    ("cracker", Category::Snacks),
+    ("peanut", Category::Snacks),
+    ("tortilla chips", Category::Snacks),
+    // Condiments, sauces and oils
+    ("chili crisp", Category::Condiments),
+    ("curry paste", Category::Condiments),
+    ("dressing", Category::Condiments),
+    ("fish sauce", Category::Condiments),
+    ("gochujang", Category::Condiments),
+    ("hoisin", Category::Condiments),
+    ("honey", Category::Condiments),
+    ("hot sauce", Category::Condiments),
+    ("jam", Category::Condiments),
+    ("ketchup", Category::Condiments),
+    ("maple syrup", Category::Condiments),
+    ("mayo", Category::Condiments),
+    ("mayonnaise", Category::Condiments),
+    ("mirin", Category::Condiments),
+    ("miso", Category::Condiments),
+    ("mustard", Category::Condiments),
+    ("oil", Category::Condiments),
+    ("oyster sauce", Category::Condiments),
+    ("peanut butter", Category::Condiments),
+    ("pickle", Category::Condiments),
+    ("salsa", Category::Condiments),
+    ("sambal", Category::Condiments),
+    ("sauce", Category::Condiments),
+    ("soy sauce", Category::Condiments),
+    ("sriracha", Category::Condiments),
+    ("tahini", Category::Condiments),
+    ("vinegar", Category::Condiments),
+    ("worcestershire", Category::Condiments),
+    // Baking, spices and seasonings
+    ("baking powder", Category::Baking),
+    ("baking soda", Category::Baking),
+    ("bay leaf", Category::Baking),
+    ("bay leaves", Category::Baking),
+    ("black pepper", Category::Baking),
+    ("breadcrumbs", Category::Baking),
+    ("brown sugar", Category::Baking),
+    ("chili powder", Category::Baking),
+    ("chocolate chip", Category::Baking),
+    ("cinnamon", Category::Baking),
+    ("cocoa", Category::Baking),
+    ("coriander", Category::Baking),
+    ("cornstarch", Category::Baking),
+    ("cumin", Category::Baking),
+    ("curry powder", Category::Baking),
+    ("dried", Category::Baking),
+    ("flour", Category::Baking),
+    ("garam masala", Category::Baking),
+    ("garlic powder", Category::Baking),
+    ("nutmeg", Category::Baking),
+    ("onion powder", Category::Baking),
+    ("oregano", Category::Baking),
+    ("panko", Category::Baking),
+    ("paprika", Category::Baking),
+    ("pepper", Category::Baking),
+    ("pepper flakes", Category::Baking),
+    ("salt", Category::Baking),
+    ("seasoning", Category::Baking),
+    ("sesame seed", Category::Baking),
+    ("spice", Category::Baking),
+    ("sugar", Category::Baking),
+    ("turmeric", Category::Baking),
+    ("vanilla", Category::Baking),
+    ("yeast", Category::Baking),
+    // Breakfast
+    ("cereal", Category::Breakfast),
+    ("granola", Category::Breakfast),
+    ("oatmeal", Category::Breakfast),
+    ("oats", Category::Breakfast),
+    ("pancake mix", Category::Breakfast),
+];
+
+/// Pick a store category for an ingredient.
+///
+/// In order: the mapping's own `category`; the category of the recurring
+/// item it maps to (or shares a name with); the keyword lexicon; and
+/// finally [`FALLBACK_CATEGORY`]. `name` is the parsed ingredient name.
+pub fn resolve_category(
+    name: &str,
+    mapping: Option<&IngredientMapping>,
+    household: &HouseholdModel,
+) -> Category {
+    if let Some(category) = mapping.and_then(|m| m.category.clone()) {
+        return category;
+    }
+
+    let model_item = mapping.and_then(|m| m.model_item.as_deref());
+    let recurring = household.recurring.iter().find(|r| {
+        model_item.is_some_and(|m| r.item.eq_ignore_ascii_case(m)) || r.item.eq_ignore_ascii_case(name)
+    });
+    if let Some(item) = recurring.filter(|r| r.category.is_known()) {
+        return item.category.clone();
+    }
+
+    model_item
+        .and_then(category_from_keywords)
+        .or_else(|| category_from_keywords(name))
+        .unwrap_or(FALLBACK_CATEGORY)
+}
+
+/// Category implied by the longest lexicon keyword in `name`, if any.
+pub fn category_from_keywords(name: &str) -> Option<Category> {
+    let lower = name.to_lowercase();
+    KEYWORDS
+        .iter()
+        .filter(|(keyword, _)| contains_word(&lower, keyword))
+        .max_by_key(|(keyword, _)| keyword.len())
+        .map(|(_, category)| category.clone())
+}
+
+/// Whether `keyword` appears in `text` as whole words, allowing a plural
+/// "s" or "es" ending.
+pub fn contains_word(text: &str, keyword: &str) -> bool {
+    let is_word_char = |c: char| c.is_alphanumeric();
+    text.match_indices(keyword).any(|(start, _)| {
+        let before_ok = text[..start].chars().next_back().is_none_or(|c| !is_word_char(c));
+        let rest = &text[start + keyword.len()..];
+        let rest = rest
+            .strip_prefix("es")
+            .filter(|r| r.chars().next().is_none_or(|c| !is_word_char(c)))
+            .or_else(|| rest.strip_prefix('s'))
+            .unwrap_or(rest);
+        before_ok && rest.chars().next().is_none_or(|c| !is_word_char(c))
+    })
+}
+
+#[cfg(test)]
+#[path = "categorize_tests.rs"]
+mod tests;
@@ -0,0 +1,100 @@
+use super::*;
+use crate::scoring::ScoringConfig;
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+fn household() -> Result<HouseholdModel, Box<dyn std::error::Error>> {
+    let yaml = r#"
+family:
+  members: []
+stores: {}
+recurring:
+  - item: "Kerrygold Butter"
+    category: dairy
+    store: giant
+  - item: "Gochujang"
+    category: condiments
+    store: amazon
+"#;
+    Ok(HouseholdModel::from_yaml(yaml)?)
+}
+
+fn mappings() -> Result<ScoringConfig, Box<dyn std::error::Error>> {
+    let yaml = r#"
+weights:
+  ingredient_overlap: 0.40
+  protein_alignment: 0.20
+  cuisine_affinity: 0.15
+  practical_friction: 0.15
+  family_fit: 0.10
+ingredient_map:
+  butter:
+    model_item: "Kerrygold Butter"
+    tier: 1
+    aliases: ["butter"]
+  tortillas:
+    model_item: "La Banderita Flour Tortillas"
+    tier: 2
+    aliases: ["tortillas"]
+  tofu:
+    model_item: "Extra Firm Tofu"
+    tier: 2
+    aliases: ["tofu"]
+    category: deli
+"#;
+    Ok(ScoringConfig::from_yaml(yaml)?)
+}
+
+#[test]
+fn test_mapping_category_wins() -> TestResult {
+    let config = mappings()?;
+    let tofu = config.ingredient_map.get("tofu");
+    assert_eq!(resolve_category("tofu", tofu, &household()?), Category::Deli);
+    Ok(())
+}
+
+#[test]
+fn test_recurring_item_category_is_used() -> TestResult {
+    let config = mappings()?;
+    let household = household()?;
+    let butter = config.ingredient_map.get("butter");
+    assert_eq!(resolve_category("unsalted butter", butter, &household), Category::Dairy);
+    // Unmapped names that match a recurring item exactly
+    assert_eq!(resolve_category("gochujang", None, &household), Category::Condiments);
+    Ok(())
+}
+
+#[test]
+fn test_keywords_check_model_item_then_name() -> TestResult {
+    let config = mappings()?;
+    let tortillas = config.ingredient_map.get("tortillas");
+    assert_eq!(resolve_category("8 small tortillas", tortillas, &household()?), Category::Bread);
+    assert_eq!(resolve_category("za'atar", None, &household()?), FALLBACK_CATEGORY);
+    Ok(())
+}
+
+#[test]
+fn test_longest_keyword_wins() -> TestResult {
+    assert_eq!(category_from_keywords("coconut milk"), Some(Category::Canned));
+    assert_eq!(category_from_keywords("whole milk"), Some(Category::Dairy));
+    assert_eq!(category_from_keywords("low-sodium chicken broth"), Some(Category::Canned));
+    assert_eq!(category_from_keywords("boneless chicken thighs"), Some(Category::Meat));
+    assert_eq!(category_from_keywords("creamy peanut butter"), Some(Category::Condiments));
+    assert_eq!(category_from_keywords("red bell pepper"), Some(Category::Produce));
+    assert_eq!(category_from_keywords("freshly ground black pepper"), Some(Category::Baking));
+    assert_eq!(category_from_keywords("corn tortillas"), Some(Category::Bread));
+    assert_eq!(category_from_keywords("tortilla chips"), Some(Category::Snacks));
+    Ok(())
+}
+
+#[test]
+fn test_keywords_match_whole_words_and_plurals() -> TestResult {
+    assert_eq!(category_from_keywords("roma tomatoes"), Some(Category::Produce));
+    assert_eq!(category_from_keywords("large eggs"), Some(Category::Dairy));
+    assert_eq!(category_from_keywords("eggplant"), Some(Category::Produce));
+    assert_eq!(category_from_keywords("hamburger buns"), Some(Category::Bread));
+    assert_eq!(category_from_keywords("cornstarch"), Some(Category::Baking));
+    assert_eq!(category_from_keywords("Jalapeño"), Some(Category::Produce));
+    assert_eq!(category_from_keywords("za'atar"), None);
+    Ok(())
+}
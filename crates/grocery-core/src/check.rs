@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+
+use crate::diagnostics::{locate_yaml, Diagnostic, Location, PathSegment};
+use crate::household::HouseholdModel;
+use crate::scoring::ScoringConfig;
+use crate::types::{Category, Store};
+
+use PathSegment::{Index, Key};
+
+/// How far the scoring weights may drift from 1.0 before it's an error.
+const WEIGHT_TOLERANCE: f64 = 0.001;
+
+/// Validate household-model.yaml, reporting every problem found.
+pub fn check_household_model(yaml: &str) -> Vec<Diagnostic> {
+    let (model, sources) = match HouseholdModel::from_yaml_with_sources(yaml) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            let location = e.location().unwrap_or(Location::START);
+            return vec![Diagnostic::error(location, e.to_string())];
+        }
+    };
+
+    let mut diagnostics = Vec::new();
+
+    let mut seen: HashMap<(Store, String), &str> = HashMap::new();
+    for (item, &(list, i)) in model.recurring.iter().zip(&sources) {
+        let at = |key| locate_yaml(yaml, &[Key(list), Index(i), Key(key)]);
+
+        if !item.category.is_known() {
+            diagnostics.push(Diagnostic::error(
+                at("category"),
+                unknown_category_message(&item.category, &item.item),
+            ));
+        }
+        if let Some(freq) = &item.frequency {
+            if !is_valid_frequency(freq) {
+                diagnostics.push(Diagnostic::warning(
+                    at("frequency"),
+                    format!(
+                        "frequency {freq:?} for {:?} isn't \"appearances/orders\" (e.g. \"14/18\"); it will be treated as rare",
+                        item.item
+                    ),
+                ));
+            }
+        }
+        if let Some(p) = item.propensity {
+            if !(0.0..=1.0).contains(&p) {
+                diagnostics.push(Diagnostic::error(
+                    at("propensity"),
+                    format!("propensity {p} for {:?} is outside 0.0–1.0", item.item),
+                ));
+            }
+        }
+        if let Some(first) = seen.insert((item.store.clone(), item.item.to_lowercase()), &item.item) {
+            diagnostics.push(Diagnostic::warning(
+                at("item"),
+                format!(
+                    "duplicate {:?} item {:?} (already listed as {first:?})",
+                    item.store, item.item
+                ),
+            ));
+        }
+    }
+
+    sorted(diagnostics)
+}
+
+/// Validate recipe-scoring-config.yaml, reporting every problem found.
+pub fn check_scoring_config(yaml: &str) -> Vec<Diagnostic> {
+    let config = match ScoringConfig::from_yaml(yaml) {
+        Ok(config) => config,
+        Err(e) => {
+            let location = e.location().unwrap_or(Location::START);
+            return vec![Diagnostic::error(location, e.to_string())];
+        }
+    };
+
+    let mut diagnostics = Vec::new();
+
+    // Disabled dimensions' weights don't count
+    let total: f64 = config
+        .weights
+        .named()
+        .filter(|(key, _)| config.is_enabled(key))
+        .map(|(_, weight)| weight)
+        .sum();
+    if (total - 1.0).abs() > WEIGHT_TOLERANCE {
+        diagnostics.push(Diagnostic::error(
+            locate_yaml(yaml, &[Key("weights")]),
+            format!("weights sum to {total:.3}, expected 1.0"),
+        ));
+    }
+    for (name, weight) in config.weights.named() {
+        if weight < 0.0 {
+            diagnostics.push(Diagnostic::error(
+                locate_yaml(yaml, &[Key("weights"), Key(name)]),
+                format!("weight {name} is negative ({weight})"),
+            ));
+        }
+    }
+
+    for (section, scores) in [
+        ("protein_scores", &config.protein_scores),
+        ("cuisine_scores", &config.cuisine_scores),
+    ] {
+        for (key, score) in scores {
+            if *score > 100 {
+                diagnostics.push(Diagnostic::warning(
+                    locate_yaml(yaml, &[Key(section), Key(key)]),
+                    format!("{section}.{key} is {score}; scores are 0-100"),
+                ));
+            }
+        }
+    }
+
+    // Walk entries in file order so "first" means first in the file
+    let mut entries: Vec<(&String, Location)> = config
+        .ingredient_map
+        .keys()
+        .map(|key| (key, locate_yaml(yaml, &[Key("ingredient_map"), Key(key)])))
+        .collect();
+    entries.sort_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(b.0)));
+
+    let mut alias_owner: HashMap<String, &str> = HashMap::new();
+    for (key, _) in &entries {
+        let Some(mapping) = config.ingredient_map.get(*key) else {
+            continue;
+        };
+        if mapping.tier > 3 {
+            diagnostics.push(Diagnostic::error(
+                locate_yaml(yaml, &[Key("ingredient_map"), Key(key), Key("tier")]),
+                format!("tier {} for {key:?} is outside 0–3", mapping.tier),
+            ));
+        }
+        if let Some(category) = mapping.category.as_ref().filter(|c| !c.is_known()) {
+            diagnostics.push(Diagnostic::error(
+                locate_yaml(yaml, &[Key("ingredient_map"), Key(key), Key("category")]),
+                unknown_category_message(category, key),
+            ));
+        }
+        for (j, alias) in mapping.aliases.iter().enumerate() {
+            if let Some(owner) = alias_owner.insert(alias.to_lowercase(), key) {
+                let location = locate_yaml(yaml, &[Key("ingredient_map"), Key(key), Key("aliases"), Index(j)]);
+                let message = if owner == key.as_str() {
+                    format!("alias {alias:?} is listed twice under {key:?}")
+                } else {
+                    format!("alias {alias:?} under {key:?} is also listed under {owner:?}")
+                };
+                diagnostics.push(Diagnostic::error(location, message));
+            }
+        }
+    }
+
+    for (item, package) in &config.package_sizes {
+        if package.size.amount <= 0.0 {
+            diagnostics.push(Diagnostic::error(
+                locate_yaml(yaml, &[Key("package_sizes"), Key(item)]),
+                format!("package size for {item:?} must be positive"),
+            ));
+        }
+    }
+
+    sorted(diagnostics)
+}
+
+fn unknown_category_message(category: &Category, owner: &str) -> String {
+    let known: Vec<&str> = Category::KNOWN.iter().map(Category::as_str).collect();
+    format!(
+        "unknown category {:?} for {owner:?} (expected one of: {})",
+        category.as_str(),
+        known.join(", ")
+    )
+}
+
+/// "14/18": two whole numbers, appearances no more than orders.
+fn is_valid_frequency(freq: &str) -> bool {
+    let Some((appearances, total)) = freq.split_once('/') else {
+        return false;
+    };
+    match (appearances.trim().parse::<u32>(), total.trim().parse::<u32>()) {
+        (Ok(a), Ok(t)) => t > 0 && a <= t,
+        _ => false,
+    }
+}
+
+fn sorted(mut diagnostics: Vec<Diagnostic>) -> Vec<Diagnostic> {
+    diagnostics.sort_by(|a, b| a.location.cmp(&b.location).then(a.message.cmp(&b.message)));
+    diagnostics
+}
+
+#[cfg(test)]
+#[path = "check_tests.rs"]
+mod tests;
@@ -0,0 +1,116 @@
+use super::*;
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+fn rendered(diagnostics: &[Diagnostic]) -> Vec<String> {
+    diagnostics.iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn test_household_reports_every_problem_with_location() -> TestResult {
+    let yaml = "\
+family:
+  members: []
+stores: {}
+amazon_recurring:
+  - item: Diapers
+    category: nappies
+giant_recurring:
+  - item: Limes
+    category: produce
+    frequency: \"14 of 18\"
+  - item: Eggs
+    category: dairy
+    propensity: 1.4
+  - item: limes
+    category: produce
+";
+    let diagnostics = check_household_model(yaml);
+    let lines = rendered(&diagnostics);
+    assert_eq!(lines.len(), 4, "{lines:#?}");
+    assert!(lines[0].starts_with("6:5: error: unknown category \"nappies\" for \"Diapers\" (expected one of: produce, dairy"));
+    assert!(lines[1].starts_with("10:5: warning: frequency \"14 of 18\" for \"Limes\""));
+    assert_eq!(lines[2], "13:5: error: propensity 1.4 for \"Eggs\" is outside 0.0–1.0");
+    assert_eq!(lines[3], "14:5: warning: duplicate Giant item \"limes\" (already listed as \"Limes\")");
+    Ok(())
+}
+
+#[test]
+fn test_household_parse_error_has_location() -> TestResult {
+    let yaml = "family:\n  members: []\nstores: {}\namazon_recurring:\n  - item: Diapers\n    cycle_days: whenever\n";
+    let diagnostics = check_household_model(yaml);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, crate::diagnostics::Severity::Error);
+    assert!(diagnostics[0].location.line > 1, "{:?}", diagnostics[0]);
+    Ok(())
+}
+
+#[test]
+fn test_clean_household_has_no_diagnostics() -> TestResult {
+    let yaml = "family:\n  members: []\nstores: {}\ngiant_recurring:\n  - item: Limes\n    category: produce\n    frequency: 9/18\n";
+    assert!(check_household_model(yaml).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_scoring_config_reports_every_problem_with_location() -> TestResult {
+    let yaml = "\
+weights:
+  ingredient_overlap: 0.40
+  protein_alignment: 0.20
+  cuisine_affinity: 0.15
+  practical_friction: 0.15
+  family_fit: 0.05
+protein_scores:
+  tofu: 140
+ingredient_map:
+  cilantro_fresh:
+    tier: 1
+    aliases:
+      - cilantro
+      - coriander
+  herbs:
+    tier: 5
+    category: herbs
+    aliases:
+      - parsley
+      - Cilantro
+";
+    let lines = rendered(&check_scoring_config(yaml));
+    assert_eq!(
+        lines,
+        vec![
+            "1:1: error: weights sum to 0.950, expected 1.0",
+            "8:3: warning: protein_scores.tofu is 140; scores are 0-100",
+            "16:5: error: tier 5 for \"herbs\" is outside 0–3",
+            "17:5: error: unknown category \"herbs\" for \"herbs\" (expected one of: produce, dairy, meat, deli, frozen, canned, bread, pasta, beverages, snacks, condiments, baking, breakfast, baby, household, health, personal, pet)",
+            "20:7: error: alias \"Cilantro\" under \"herbs\" is also listed under \"cilantro_fresh\"",
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_scoring_config_parse_error_has_location() -> TestResult {
+    let yaml = "weights:\n  ingredient_overlap: lots\n";
+    let diagnostics = check_scoring_config(yaml);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].location.line, 2);
+    Ok(())
+}
+
+#[test]
+fn test_disabled_dimension_weights_dont_count() -> TestResult {
+    let yaml = "\
+weights:
+  ingredient_overlap: 0.40
+  protein_alignment: 0.20
+  cuisine_affinity: 0.15
+  practical_friction: 0.15
+  family_fit: 0.10
+  recency: 0.20
+disabled_dimensions: [recency]
+";
+    assert!(check_scoring_config(yaml).is_empty());
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use std::collections::BTreeMap;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+
+use chrono::{Datelike, Days, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::diagnostics::Location;
+use crate::error::{GroceryError, GroceryResult};
+use crate::recipe::Recipe;
+
+/// One time we cooked a recipe: a line in cooking-log.jsonl.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CookingEvent {
+    pub recipe: String,
+    pub date: NaiveDate,
+    /// 0–5 stars, replacing the recipe's rating.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rating: Option<u8>,
+    /// Added to the recipe's feedback.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+/// Every cooking event, oldest first.
+///
+/// The log is append-only JSON lines, so a crash mid-write can cost at most
+/// the last line and never the rest of the history.
+#[derive(Debug, Clone, Default)]
+pub struct CookingLog {
+    pub events: Vec<CookingEvent>,
+}
+
+impl CookingLog {
+    /// Load from a JSON-lines file; a missing file is an empty log.
+    pub fn from_file(path: &Path) -> GroceryResult<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)?;
+        Self::from_jsonl(&content)
+    }
+
+    /// Parse JSON lines, skipping blank ones. A bad line is an error that
+    /// names its line number.
+    pub fn from_jsonl(content: &str) -> GroceryResult<Self> {
+        let mut events = Vec::new();
+        for (i, line) in content.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let event = serde_json::from_str(line).map_err(|e| GroceryError::CookingLogParse {
+                message: e.to_string(),
+                location: Some(Location {
+                    line: i + 1,
+                    column: e.column().max(1),
+                }),
+            })?;
+            events.push(event);
+        }
+        events.sort_by_key(|e: &CookingEvent| e.date);
+        Ok(Self { events })
+    }
+
+    /// Append one event to the log file, creating it if needed.
+    pub fn append(path: &Path, event: &CookingEvent) -> GroceryResult<()> {
+        let line = serde_json::to_string(event)?;
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{line}")?;
+        file.sync_all()?;
+        Ok(())
+    }
+
+    /// Events grouped by the Monday of their week, oldest week first.
+    pub fn by_week(&self) -> BTreeMap<NaiveDate, Vec<&CookingEvent>> {
+        let mut weeks: BTreeMap<NaiveDate, Vec<&CookingEvent>> = BTreeMap::new();
+        for event in &self.events {
+            weeks.entry(week_start(event.date)).or_default().push(event);
+        }
+        weeks
+    }
+}
+
+/// The Monday on or before `date`.
+pub fn week_start(date: NaiveDate) -> NaiveDate {
+    let back = u64::from(date.weekday().num_days_from_monday());
+    date.checked_sub_days(Days::new(back)).unwrap_or(date)
+}
+
+impl Recipe {
+    /// Fold a cooking event into the recipe's stats: one more time made,
+    /// `last_made` moved up to the event's date, and any rating or note
+    /// recorded.
+    pub fn record_cooked(&mut self, event: &CookingEvent) {
+        self.times_made += 1;
+        if self.last_made.is_none_or(|d| d < event.date) {
+            self.last_made = Some(event.date);
+        }
+        if let Some(rating) = event.rating {
+            self.rating = Some(rating);
+        }
+        if let Some(note) = event.note.as_ref().filter(|n| !n.trim().is_empty()) {
+            self.feedback.push(note.trim().to_string());
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "cooking_tests.rs"]
+mod tests;
@@ -0,0 +1,62 @@
+use super::*;
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+fn date(y: i32, m: u32, d: u32) -> Result<NaiveDate, Box<dyn std::error::Error>> {
+    NaiveDate::from_ymd_opt(y, m, d).ok_or_else(|| "bad date".into())
+}
+
+#[test]
+fn test_log_parses_and_groups_by_week() -> TestResult {
+    let log = CookingLog::from_jsonl(
+        r#"{"recipe": "Larb", "date": "2026-10-14", "rating": 5}
+
+{"recipe": "Tacos", "date": "2026-10-11", "note": "double the limes"}
+{"recipe": "Soba", "date": "2026-10-12"}
+"#,
+    )?;
+    let weeks = log.by_week();
+    let summary: Vec<(NaiveDate, Vec<&str>)> = weeks
+        .iter()
+        .map(|(week, events)| (*week, events.iter().map(|e| e.recipe.as_str()).collect()))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![(date(2026, 10, 5)?, vec!["Tacos"]), (date(2026, 10, 12)?, vec!["Soba", "Larb"])]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_bad_log_line_reports_its_line() -> TestResult {
+    let err = CookingLog::from_jsonl("{\"recipe\": \"Larb\", \"date\": \"2026-10-14\"}\n{\"recipe\": 5}\n")
+        .err()
+        .ok_or("expected an error")?;
+    assert_eq!(err.location().map(|l| l.line), Some(2));
+    Ok(())
+}
+
+#[test]
+fn test_record_cooked_updates_stats() -> TestResult {
+    let mut recipe: Recipe = serde_json::from_str(
+        r#"{"name": "Larb", "times_made": 2, "last_made": "2026-10-01", "rating": 3, "feedback": ["good"]}"#,
+    )?;
+    recipe.record_cooked(&CookingEvent {
+        recipe: "Larb".to_string(),
+        date: date(2026, 10, 14)?,
+        rating: Some(5),
+        note: Some("  more herbs ".to_string()),
+    });
+    // An older event still counts but doesn't move last_made back
+    recipe.record_cooked(&CookingEvent {
+        recipe: "Larb".to_string(),
+        date: date(2026, 9, 1)?,
+        rating: None,
+        note: None,
+    });
+    assert_eq!(recipe.times_made, 4);
+    assert_eq!(recipe.last_made, Some(date(2026, 10, 14)?));
+    assert_eq!(recipe.rating, Some(5));
+    assert_eq!(recipe.feedback, vec!["good", "more herbs"]);
+    Ok(())
+}
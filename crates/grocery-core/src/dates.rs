@@ -0,0 +1,137 @@
+use std::fmt;
+
+use chrono::NaiveDate;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Date formats found in our data files, tried in order.
+const DATE_FORMATS: &[&str] = &["%Y-%m-%d", "%b %d, %Y", "%B %d, %Y", "%m/%d/%Y"];
+
+/// Parse a date written as "2025-08-28", "Aug 28, 2025", "August 28, 2025"
+/// or "8/28/2025".
+pub fn parse_date(raw: &str) -> Option<NaiveDate> {
+    let raw = raw.trim();
+    DATE_FORMATS
+        .iter()
+        .find_map(|fmt| NaiveDate::parse_from_str(raw, fmt).ok())
+}
+
+/// How often an item runs out, as a range of days ("30-45 days").
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CycleDays {
+    pub min: u32,
+    pub max: u32,
+}
+
+impl CycleDays {
+    pub fn new(min: u32, max: u32) -> Self {
+        Self {
+            min: min.min(max),
+            max: min.max(max),
+        }
+    }
+
+    pub fn exactly(days: u32) -> Self {
+        Self::new(days, days)
+    }
+
+    /// Parse "30", "~30 days", "30-45 days", "2 weeks" or "~3 months".
+    ///
+    /// A month counts as 30 days. Cycles too long to count in days give `None`.
+    pub fn parse(raw: &str) -> Option<Self> {
+        let text = raw.trim().trim_start_matches('~').trim().to_lowercase();
+        let split_at = text
+            .find(|c: char| c.is_alphabetic())
+            .unwrap_or(text.len());
+        let (numbers, unit) = text.split_at(split_at);
+
+        let per_unit = match unit.trim() {
+            "" | "d" | "day" | "days" => 1,
+            "w" | "wk" | "wks" | "week" | "weeks" => 7,
+            "mo" | "month" | "months" => 30,
+            _ => return None,
+        };
+
+        let numbers = numbers.trim();
+        let (low, high) = match numbers.split_once(['-', '–']) {
+            Some((low, high)) => (low, high),
+            None => (numbers, numbers),
+        };
+        let low = low.trim().trim_start_matches('~').parse::<u32>().ok()?;
+        let high = high.trim().trim_start_matches('~').parse::<u32>().ok()?;
+        if low == 0 || high == 0 {
+            return None;
+        }
+        Some(Self::new(low.checked_mul(per_unit)?, high.checked_mul(per_unit)?))
+    }
+}
+
+impl fmt::Display for CycleDays {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.min == self.max {
+            write!(f, "{} days", self.min)
+        } else {
+            write!(f, "{}-{} days", self.min, self.max)
+        }
+    }
+}
+
+impl Serialize for CycleDays {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// A date or cycle as written in a data file: usually text, sometimes a bare number.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawValue {
+    Text(String),
+    Number(u64),
+}
+
+/// Deserialize an optional date in any [`parse_date`] format.
+///
+/// Null and empty strings become `None`; anything else that isn't a date is
+/// an error naming `field`. Call it from a per-field `deserialize_with` fn.
+pub fn deserialize_opt_date<'de, D: Deserializer<'de>>(
+    deserializer: D,
+    field: &str,
+) -> Result<Option<NaiveDate>, D::Error> {
+    let raw = match Option::<RawValue>::deserialize(deserializer)? {
+        None => return Ok(None),
+        Some(RawValue::Text(text)) if text.trim().is_empty() => return Ok(None),
+        Some(RawValue::Text(text)) => text,
+        Some(RawValue::Number(n)) => n.to_string(),
+    };
+    parse_date(&raw).map(Some).ok_or_else(|| {
+        D::Error::custom(format!(
+            "invalid {field} {raw:?}: expected a date like \"2025-08-28\" or \"Aug 28, 2025\""
+        ))
+    })
+}
+
+/// Deserialize an optional cycle ("~30 days", "30-45 days" or a bare `30`).
+///
+/// Null and empty strings become `None`; anything else that isn't a cycle is
+/// an error naming `field`.
+pub fn deserialize_opt_cycle<'de, D: Deserializer<'de>>(
+    deserializer: D,
+    field: &str,
+) -> Result<Option<CycleDays>, D::Error> {
+    let raw = match Option::<RawValue>::deserialize(deserializer)? {
+        None => return Ok(None),
+        Some(RawValue::Text(text)) if text.trim().is_empty() => return Ok(None),
+        Some(RawValue::Text(text)) => text,
+        Some(RawValue::Number(n)) => n.to_string(),
+    };
+    CycleDays::parse(&raw).map(Some).ok_or_else(|| {
+        D::Error::custom(format!(
+            "invalid {field} {raw:?}: expected a cycle like \"~30 days\" or \"30-45 days\""
+        ))
+    })
+}
+
+#[cfg(test)]
+#[path = "dates_tests.rs"]
+mod tests;
@@ -0,0 +1,52 @@
+use super::*;
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+#[test]
+fn test_parse_date_formats() -> TestResult {
+    let expected = NaiveDate::from_ymd_opt(2025, 8, 28).ok_or("bad date")?;
+    assert_eq!(parse_date("2025-08-28"), Some(expected));
+    assert_eq!(parse_date("Aug 28, 2025"), Some(expected));
+    assert_eq!(parse_date(" August 28, 2025 "), Some(expected));
+    assert_eq!(parse_date("8/28/2025"), Some(expected));
+    assert_eq!(parse_date("last week"), None);
+    assert_eq!(parse_date(""), None);
+    Ok(())
+}
+
+#[test]
+fn test_parse_cycle_days() -> TestResult {
+    assert_eq!(CycleDays::parse("30"), Some(CycleDays::exactly(30)));
+    assert_eq!(CycleDays::parse("~30 days"), Some(CycleDays::exactly(30)));
+    assert_eq!(CycleDays::parse("30-45 days"), Some(CycleDays::new(30, 45)));
+    assert_eq!(CycleDays::parse("45–30"), Some(CycleDays::new(30, 45)));
+    assert_eq!(CycleDays::parse("2 weeks"), Some(CycleDays::exactly(14)));
+    assert_eq!(CycleDays::parse("~3 months"), Some(CycleDays::exactly(90)));
+    Ok(())
+}
+
+#[test]
+fn test_parse_cycle_days_rejects_garbage() -> TestResult {
+    assert_eq!(CycleDays::parse("often"), None);
+    assert_eq!(CycleDays::parse("0 days"), None);
+    assert_eq!(CycleDays::parse("30 fortnights"), None);
+    assert_eq!(CycleDays::parse(""), None);
+    Ok(())
+}
+
+#[test]
+fn test_parse_cycle_days_rejects_overflow() -> TestResult {
+    assert_eq!(CycleDays::parse("900000000 weeks"), None);
+    assert_eq!(CycleDays::parse("1-900000000 months"), None);
+    assert_eq!(CycleDays::parse("4294967295 days"), Some(CycleDays::exactly(u32::MAX)));
+    Ok(())
+}
+
+#[test]
+fn test_cycle_days_display_round_trips() -> TestResult {
+    for cycle in [CycleDays::exactly(30), CycleDays::new(30, 45)] {
+        assert_eq!(CycleDays::parse(&cycle.to_string()), Some(cycle));
+    }
+    assert_eq!(serde_json::to_string(&CycleDays::new(30, 45))?, r#""30-45 days""#);
+    Ok(())
+}
@@ -0,0 +1,198 @@
+use std::fmt;
+
+/// A position in a data file, 1-based.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Location {
+    pub const START: Location = Location { line: 1, column: 1 };
+
+    /// Location of a byte offset into `text`.
+    pub fn at_offset(text: &str, offset: usize) -> Self {
+        let offset = offset.min(text.len());
+        let before = text.get(..offset).unwrap_or(text);
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let column = before.get(line_start..).map_or(0, |s| s.chars().count()) + 1;
+        Self { line, column }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Error => write!(f, "error"),
+            Self::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+/// One problem found while checking a data file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub location: Location,
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl Diagnostic {
+    pub fn error(location: Location, message: impl Into<String>) -> Self {
+        Self {
+            location,
+            severity: Severity::Error,
+            message: message.into(),
+        }
+    }
+
+    pub fn warning(location: Location, message: impl Into<String>) -> Self {
+        Self {
+            location,
+            severity: Severity::Warning,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}: {}", self.location, self.severity, self.message)
+    }
+}
+
+/// One step in a path through a YAML document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathSegment<'a> {
+    Key(&'a str),
+    Index(usize),
+}
+
+/// A non-blank, non-comment line of a YAML block.
+#[derive(Debug, Clone, Copy)]
+struct YamlLine<'a> {
+    number: usize,
+    indent: usize,
+    text: &'a str,
+}
+
+/// Find where a value lives in block-style YAML.
+///
+/// serde_yaml doesn't keep spans for parsed values, so checks that run on
+/// the typed model use this to point back at the source. Walks as far down
+/// `path` as it can and returns the deepest location found (flow-style
+/// values like `[a, b]` resolve to their key). Falls back to the start of
+/// the file.
+pub fn locate_yaml(text: &str, path: &[PathSegment<'_>]) -> Location {
+    let mut block: Vec<YamlLine<'_>> = text
+        .lines()
+        .enumerate()
+        .filter_map(|(i, raw)| {
+            let text = raw.trim_start();
+            if text.is_empty() || text.starts_with('#') || text == "---" {
+                return None;
+            }
+            Some(YamlLine {
+                number: i + 1,
+                indent: raw.len() - text.len(),
+                text,
+            })
+        })
+        .collect();
+
+    let mut found = Location::START;
+    for segment in path {
+        let Some(first) = block.first().copied() else {
+            break;
+        };
+        let level = first.indent;
+        let (start, end) = match *segment {
+            PathSegment::Key(key) => {
+                let Some(start) = block
+                    .iter()
+                    .position(|l| l.indent == level && yaml_key(l.text) == Some(key))
+                else {
+                    break;
+                };
+                // A key's value is everything indented deeper, plus a
+                // sequence written at the key's own indent.
+                let end = block[start + 1..]
+                    .iter()
+                    .position(|l| l.indent < level || (l.indent == level && !l.text.starts_with('-')))
+                    .map_or(block.len(), |p| start + 1 + p);
+                (start, end)
+            }
+            PathSegment::Index(index) => {
+                let items: Vec<usize> = block
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, l)| l.indent == level && l.text.starts_with('-'))
+                    .map(|(i, _)| i)
+                    .collect();
+                let Some(&start) = items.get(index) else {
+                    break;
+                };
+                let end = items.get(index + 1).copied().unwrap_or(block.len());
+                (start, end)
+            }
+        };
+
+        let head = block[start];
+        found = Location {
+            line: head.number,
+            column: head.indent + 1,
+        };
+
+        let mut next: Vec<YamlLine<'_>> = Vec::new();
+        match *segment {
+            PathSegment::Key(_) => {
+                // Inline value on the key line has nothing to descend into
+                next.extend_from_slice(&block[start + 1..end]);
+            }
+            PathSegment::Index(_) => {
+                // "- key: value" starts a mapping two columns further in
+                let rest = head.text.trim_start_matches('-');
+                let item = rest.trim_start();
+                if !item.is_empty() {
+                    next.push(YamlLine {
+                        number: head.number,
+                        indent: head.indent + (head.text.len() - item.len()),
+                        text: item,
+                    });
+                }
+                next.extend_from_slice(&block[start + 1..end]);
+            }
+        }
+        block = next;
+    }
+
+    found
+}
+
+/// The key of a `key: value` line, without quotes.
+fn yaml_key(text: &str) -> Option<&str> {
+    if let Some(quote) = text.chars().next().filter(|c| *c == '"' || *c == '\'') {
+        let rest = &text[1..];
+        let close = rest.find(quote)?;
+        return rest[close + 1..].starts_with(':').then(|| &rest[..close]);
+    }
+    let colon = text.find(": ").or_else(|| text.strip_suffix(':').map(str::len))?;
+    Some(text[..colon].trim_end())
+}
+
+#[cfg(test)]
+#[path = "diagnostics_tests.rs"]
+mod tests;
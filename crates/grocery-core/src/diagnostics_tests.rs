@@ -0,0 +1,78 @@
+use super::*;
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+const YAML: &str = "\
+# comment
+weights:
+  ingredient_overlap: 0.40
+  family_fit: 0.10
+ingredient_map:
+  cilantro:
+    tier: 1
+    aliases: [cilantro, coriander]
+  \"ground chicken\":
+    tier: 4
+    aliases:
+      - ground chicken
+      - chicken mince
+giant_recurring:
+- item: Limes
+  category: produce
+- item: Eggs
+  category: veggies
+";
+
+fn at(line: usize, column: usize) -> Location {
+    Location { line, column }
+}
+
+#[test]
+fn test_locate_mapping_keys() -> TestResult {
+    use PathSegment::Key;
+    assert_eq!(locate_yaml(YAML, &[Key("weights")]), at(2, 1));
+    assert_eq!(locate_yaml(YAML, &[Key("weights"), Key("family_fit")]), at(4, 3));
+    assert_eq!(locate_yaml(YAML, &[Key("ingredient_map"), Key("ground chicken"), Key("tier")]), at(10, 5));
+    Ok(())
+}
+
+#[test]
+fn test_locate_sequence_items() -> TestResult {
+    use PathSegment::{Index, Key};
+    let path = [Key("ingredient_map"), Key("ground chicken"), Key("aliases"), Index(1)];
+    assert_eq!(locate_yaml(YAML, &path), at(13, 7));
+
+    // Sequence written at the parent key's indent, keys on the dash line
+    assert_eq!(locate_yaml(YAML, &[Key("giant_recurring"), Index(1)]), at(17, 1));
+    assert_eq!(locate_yaml(YAML, &[Key("giant_recurring"), Index(1), Key("item")]), at(17, 3));
+    assert_eq!(locate_yaml(YAML, &[Key("giant_recurring"), Index(1), Key("category")]), at(18, 3));
+    Ok(())
+}
+
+#[test]
+fn test_locate_falls_back_to_deepest_match() -> TestResult {
+    use PathSegment::{Index, Key};
+    // Flow-style lists resolve to their key
+    let path = [Key("ingredient_map"), Key("cilantro"), Key("aliases"), Index(1)];
+    assert_eq!(locate_yaml(YAML, &path), at(8, 5));
+    assert_eq!(locate_yaml(YAML, &[Key("weights"), Key("missing")]), at(2, 1));
+    assert_eq!(locate_yaml(YAML, &[Key("nope")]), Location::START);
+    Ok(())
+}
+
+#[test]
+fn test_location_at_offset() -> TestResult {
+    let text = "ab\ncdé\nf";
+    assert_eq!(Location::at_offset(text, 0), at(1, 1));
+    assert_eq!(Location::at_offset(text, 4), at(2, 2));
+    assert_eq!(Location::at_offset(text, text.len() - 1), at(3, 1));
+    assert_eq!(Location::at_offset(text, 999), at(3, 2));
+    Ok(())
+}
+
+#[test]
+fn test_diagnostic_display() -> TestResult {
+    let d = Diagnostic::error(at(3, 5), "weights sum to 0.9");
+    assert_eq!(d.to_string(), "3:5: error: weights sum to 0.9");
+    Ok(())
+}
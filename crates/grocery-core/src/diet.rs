@@ -0,0 +1,373 @@
+use std::fmt;
+
+use chrono::Weekday;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::categorize::contains_word;
+use crate::household::{FamilyMember, FamilyProfile};
+use crate::recipe::Recipe;
+
+/// A food allergy or intolerance.
+///
+/// Serialized as its snake_case name; anything else loads as `Other` and
+/// is matched as a keyword on its own ("cilantro", "kiwi").
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Allergen {
+    Peanut,
+    TreeNut,
+    Dairy,
+    Egg,
+    Gluten,
+    Soy,
+    Fish,
+    Shellfish,
+    Sesame,
+    Other(String),
+}
+
+impl Allergen {
+    /// The snake_case name used in household-model.yaml.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Peanut => "peanut",
+            Self::TreeNut => "tree_nut",
+            Self::Dairy => "dairy",
+            Self::Egg => "egg",
+            Self::Gluten => "gluten",
+            Self::Soy => "soy",
+            Self::Fish => "fish",
+            Self::Shellfish => "shellfish",
+            Self::Sesame => "sesame",
+            Self::Other(name) => name,
+        }
+    }
+
+    pub fn from_name(name: &str) -> Self {
+        let normalized = name.trim().to_lowercase().replace([' ', '-'], "_");
+        match normalized.as_str() {
+            "peanut" | "peanuts" => Self::Peanut,
+            "tree_nut" | "tree_nuts" => Self::TreeNut,
+            "dairy" | "milk" | "lactose" => Self::Dairy,
+            "egg" | "eggs" => Self::Egg,
+            "gluten" | "wheat" => Self::Gluten,
+            "soy" => Self::Soy,
+            "fish" => Self::Fish,
+            "shellfish" => Self::Shellfish,
+            "sesame" => Self::Sesame,
+            _ => Self::Other(name.trim().to_lowercase()),
+        }
+    }
+
+    /// Ingredient words that contain this allergen.
+    pub fn keywords(&self) -> Vec<&str> {
+        let words: &[&str] = match self {
+            Self::Peanut => &["peanut", "satay"],
+            Self::TreeNut => &[
+                "almond", "cashew", "walnut", "pecan", "pistachio", "hazelnut", "macadamia", "pine nut",
+                "nutella",
+            ],
+            Self::Dairy => &[
+                "milk", "butter", "cheese", "cream", "yogurt", "ghee", "whey", "parmesan", "mozzarella",
+                "cheddar", "feta", "ricotta", "half-and-half", "paneer",
+            ],
+            Self::Egg => &["egg", "mayonnaise", "mayo", "aioli", "meringue"],
+            Self::Gluten => &[
+                "flour", "bread", "breadcrumbs", "panko", "pasta", "spaghetti", "linguine", "penne",
+                "lasagna", "orzo", "couscous", "noodle", "udon", "ramen", "tortilla", "pita", "naan",
+                "bun", "brioche", "cracker", "wheat", "barley", "farro", "seitan", "soy sauce", "beer",
+            ],
+            Self::Soy => &["soy", "tofu", "edamame", "miso", "tempeh", "tamari", "soybean"],
+            Self::Fish => &[
+                "fish", "salmon", "tuna", "cod", "haddock", "tilapia", "halibut", "trout", "anchovy",
+                "anchovies", "sardine",
+            ],
+            Self::Shellfish => &[
+                "shrimp", "prawn", "crab", "lobster", "scallop", "clam", "mussel", "oyster",
+            ],
+            Self::Sesame => &["sesame", "tahini", "furikake"],
+            Self::Other(name) => return vec![name.as_str()],
+        };
+        words.to_vec()
+    }
+
+    /// Phrases that look like this allergen but aren't ("coconut milk",
+    /// "peanut butter" for dairy). An ingredient containing one is skipped.
+    fn exceptions(&self) -> &'static [&'static str] {
+        match self {
+            Self::Dairy => &[
+                "coconut milk", "coconut cream", "almond milk", "oat milk", "soy milk", "peanut butter",
+                "almond butter", "cashew butter", "cocoa butter", "cream of tartar", "butter lettuce",
+                "butter bean", "dairy-free",
+            ],
+            Self::Gluten => &[
+                "gluten-free", "rice noodle", "rice flour", "almond flour", "coconut flour",
+                "corn tortilla", "tamari",
+            ],
+            _ => &[],
+        }
+    }
+
+    /// Whether an ingredient line contains this allergen.
+    pub fn matches(&self, ingredient: &str) -> bool {
+        let lower = ingredient.to_lowercase();
+        if self.exceptions().iter().any(|e| lower.contains(e)) {
+            return false;
+        }
+        self.keywords().iter().any(|k| contains_word(&lower, k))
+    }
+}
+
+impl fmt::Display for Allergen {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str().replace('_', " "))
+    }
+}
+
+impl Serialize for Allergen {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Allergen {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Ok(Self::from_name(&name))
+    }
+}
+
+/// How hot a dish is, or how much heat someone enjoys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpiceLevel {
+    None,
+    Mild,
+    Medium,
+    Hot,
+}
+
+impl SpiceLevel {
+    /// Steps from `None`, for comparing a dish against a tolerance.
+    pub fn steps(self) -> u8 {
+        self as u8
+    }
+}
+
+impl fmt::Display for SpiceLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::None => "none",
+            Self::Mild => "mild",
+            Self::Medium => "medium",
+            Self::Hot => "hot",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Ingredients that bring heat, by how much.
+const HEAT_WORDS: &[(&str, SpiceLevel)] = &[
+    ("ancho", SpiceLevel::Mild),
+    ("chili powder", SpiceLevel::Mild),
+    ("curry powder", SpiceLevel::Mild),
+    ("green chile", SpiceLevel::Mild),
+    ("poblano", SpiceLevel::Mild),
+    ("cayenne", SpiceLevel::Medium),
+    ("chile de arbol", SpiceLevel::Medium),
+    ("chili crisp", SpiceLevel::Medium),
+    ("chili garlic sauce", SpiceLevel::Medium),
+    ("chili oil", SpiceLevel::Medium),
+    ("chipotle", SpiceLevel::Medium),
+    ("curry paste", SpiceLevel::Medium),
+    ("gochugaru", SpiceLevel::Medium),
+    ("gochujang", SpiceLevel::Medium),
+    ("harissa", SpiceLevel::Medium),
+    ("hot sauce", SpiceLevel::Medium),
+    ("jalapeno", SpiceLevel::Medium),
+    ("jalapeño", SpiceLevel::Medium),
+    ("red pepper flakes", SpiceLevel::Medium),
+    ("sambal", SpiceLevel::Medium),
+    ("serrano", SpiceLevel::Medium),
+    ("sriracha", SpiceLevel::Medium),
+    ("bird's eye", SpiceLevel::Hot),
+    ("ghost pepper", SpiceLevel::Hot),
+    ("habanero", SpiceLevel::Hot),
+    ("scotch bonnet", SpiceLevel::Hot),
+    ("thai chile", SpiceLevel::Hot),
+    ("thai chili", SpiceLevel::Hot),
+];
+
+/// How hot a recipe is, and the ingredient line (or "spicy" tag) that
+/// makes it so. A "spicy" tag or name counts as at least medium.
+pub fn spice_level(recipe: &Recipe) -> (SpiceLevel, Option<String>) {
+    let mut hottest = (SpiceLevel::None, None);
+    for line in &recipe.ingredients {
+        let lower = line.to_lowercase();
+        for (word, level) in HEAT_WORDS {
+            if *level > hottest.0 && contains_word(&lower, word) {
+                hottest = (*level, Some(line.clone()));
+            }
+        }
+    }
+    let tagged = recipe.tags.iter().any(|t| t.eq_ignore_ascii_case("spicy"))
+        || contains_word(&recipe.name.to_lowercase(), "spicy");
+    if tagged && hottest.0 < SpiceLevel::Medium {
+        hottest = (SpiceLevel::Medium, Some("spicy".to_string()));
+    }
+    hottest
+}
+
+/// Ingredient words that make a dish not vegetarian.
+const MEAT_WORDS: &[&str] = &[
+    "chicken", "beef", "pork", "steak", "sausage", "bacon", "ham", "turkey", "lamb", "veal",
+    "prosciutto", "salami", "pepperoni", "chorizo", "pancetta", "gelatin", "fish", "salmon", "tuna",
+    "cod", "haddock", "tilapia", "halibut", "trout", "anchovy", "anchovies", "sardine", "shrimp",
+    "prawn", "crab", "lobster", "scallop", "clam", "mussel",
+];
+
+/// Ingredients that look like meat but aren't.
+const MEAT_EXCEPTIONS: &[&str] = &["vegetable broth", "vegetable stock", "meatless", "plant-based", "vegan"];
+
+/// `primary_protein` values that are fine on vegetarian days.
+const VEGETARIAN_PROTEINS: &[&str] = &["vegetarian", "tofu", "eggs", "beans", "lentils", "chickpeas"];
+
+/// Why a recipe doesn't suit someone at the table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConflictReason {
+    Allergen(Allergen),
+    /// Has meat on a day the member eats vegetarian. Lists all their
+    /// vegetarian days when the meal's day isn't known.
+    Vegetarian(Vec<Weekday>),
+    Dislike(String),
+}
+
+/// One member's problem with one recipe.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DietConflict {
+    pub member: String,
+    pub reason: ConflictReason,
+    /// The ingredient line (or primary protein) that triggered it.
+    pub ingredient: String,
+    /// Allergens, and vegetarian days when the meal falls on one, rule the
+    /// recipe out; dislikes and possible vegetarian days only flag it.
+    pub hard: bool,
+}
+
+impl fmt::Display for DietConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.reason {
+            ConflictReason::Allergen(allergen) => write!(f, "{} avoids {allergen}", self.member)?,
+            ConflictReason::Vegetarian(days) => {
+                let days: Vec<String> = days.iter().map(Weekday::to_string).collect();
+                write!(f, "{} eats vegetarian on {}", self.member, days.join(", "))?
+            }
+            ConflictReason::Dislike(what) => write!(f, "{} dislikes {what}", self.member)?,
+        }
+        write!(f, ": {:?}", self.ingredient)
+    }
+}
+
+/// How to treat recipes with conflicts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DietFilter {
+    /// Drop recipes with a hard conflict; keep the rest, flagged.
+    Exclude,
+    /// Keep every recipe, flagged with its conflicts.
+    Flag,
+}
+
+/// Who's eating a meal, and when.
+#[derive(Debug, Clone, Default)]
+pub struct Diners<'a> {
+    pub members: Vec<&'a FamilyMember>,
+    /// Day of the meal, for vegetarian days; `None` if not yet scheduled.
+    pub day: Option<Weekday>,
+}
+
+impl<'a> Diners<'a> {
+    /// Everyone who eats the family's meals, day unknown.
+    pub fn everyone(family: &'a FamilyProfile) -> Self {
+        Self {
+            members: family.eaters().collect(),
+            day: None,
+        }
+    }
+
+    pub fn on(mut self, day: Weekday) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    /// Every way `recipe` conflicts with someone at the table.
+    pub fn conflicts(&self, recipe: &Recipe) -> Vec<DietConflict> {
+        let mut conflicts = Vec::new();
+        for member in &self.members {
+            for allergen in &member.allergens {
+                if let Some(line) = recipe.ingredients.iter().find(|i| allergen.matches(i)) {
+                    conflicts.push(DietConflict {
+                        member: member.name.clone(),
+                        reason: ConflictReason::Allergen(allergen.clone()),
+                        ingredient: line.clone(),
+                        hard: true,
+                    });
+                }
+            }
+
+            if !member.vegetarian_days.is_empty() {
+                let scheduled = self.day.filter(|d| member.vegetarian_days.contains(d));
+                let applies = self.day.is_none() || scheduled.is_some();
+                if let Some(source) = meat_source(recipe).filter(|_| applies) {
+                    conflicts.push(DietConflict {
+                        member: member.name.clone(),
+                        reason: ConflictReason::Vegetarian(match scheduled {
+                            Some(day) => vec![day],
+                            None => member.vegetarian_days.clone(),
+                        }),
+                        ingredient: source,
+                        hard: scheduled.is_some(),
+                    });
+                }
+            }
+
+            for dislike in &member.dislikes {
+                let lower = dislike.to_lowercase();
+                if let Some(line) = recipe
+                    .ingredients
+                    .iter()
+                    .find(|i| contains_word(&i.to_lowercase(), &lower))
+                {
+                    conflicts.push(DietConflict {
+                        member: member.name.clone(),
+                        reason: ConflictReason::Dislike(dislike.clone()),
+                        ingredient: line.clone(),
+                        hard: false,
+                    });
+                }
+            }
+        }
+        conflicts
+    }
+}
+
+/// The ingredient (or primary protein) that makes a recipe not vegetarian.
+fn meat_source(recipe: &Recipe) -> Option<String> {
+    if let Some(protein) = &recipe.primary_protein {
+        let key = protein.to_lowercase();
+        if !VEGETARIAN_PROTEINS.contains(&key.as_str()) {
+            return Some(protein.clone());
+        }
+    }
+    recipe
+        .ingredients
+        .iter()
+        .find(|line| {
+            let lower = line.to_lowercase();
+            !MEAT_EXCEPTIONS.iter().any(|e| lower.contains(e))
+                && MEAT_WORDS.iter().any(|w| contains_word(&lower, w))
+        })
+        .cloned()
+}
+
+#[cfg(test)]
+#[path = "diet_tests.rs"]
+mod tests;
@@ -0,0 +1,160 @@
+use super::*;
+use crate::household::HouseholdModel;
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+fn household() -> Result<HouseholdModel, Box<dyn std::error::Error>> {
+    let yaml = r#"
+family:
+  members:
+    - name: David
+      vegetarian_days: [friday]
+    - name: Christine
+      allergens: [tree_nut, shellfish]
+    - name: Daughter
+      age: 4
+      allergens: [Peanuts]
+      dislikes: [mushrooms]
+stores: {}
+"#;
+    Ok(HouseholdModel::from_yaml(yaml)?)
+}
+
+fn recipe(ingredients: &[&str], protein: Option<&str>) -> Recipe {
+    Recipe {
+        name: "Test Dish".to_string(),
+        url: None,
+        tags: vec![],
+        rating: None,
+        needs_fixing: false,
+        last_made: None,
+        times_made: 0,
+        feedback: vec![],
+        source: None,
+        ingredients: ingredients.iter().map(|i| i.to_string()).collect(),
+        cook_time: None,
+        servings: None,
+        primary_protein: protein.map(str::to_string),
+        extra: Default::default(),
+    }
+}
+
+#[test]
+fn test_member_restrictions_load() -> TestResult {
+    let model = household()?;
+    let members = &model.family.members;
+    assert_eq!(members[0].vegetarian_days, vec![Weekday::Fri]);
+    assert_eq!(members[1].allergens, vec![Allergen::TreeNut, Allergen::Shellfish]);
+    assert_eq!(members[2].allergens, vec![Allergen::Peanut]);
+    assert_eq!(members[2].dislikes, vec!["mushrooms"]);
+    Ok(())
+}
+
+#[test]
+fn test_allergen_lexicons() -> TestResult {
+    assert!(Allergen::Peanut.matches("2 Tbsp. creamy peanut butter"));
+    assert!(Allergen::TreeNut.matches("1/2 cup toasted cashews"));
+    assert!(Allergen::Dairy.matches("1 cup grated Parmesan"));
+    assert!(!Allergen::Dairy.matches("1 can coconut milk"));
+    assert!(!Allergen::Dairy.matches("3 Tbsp peanut butter"));
+    assert!(Allergen::Egg.matches("2 large eggs"));
+    assert!(!Allergen::Egg.matches("1 eggplant"));
+    assert!(Allergen::Gluten.matches("2 Tbsp soy sauce"));
+    assert!(!Allergen::Gluten.matches("8 oz rice noodles"));
+    assert!(!Allergen::Gluten.matches("8 oz gluten-free pasta"));
+    assert!(Allergen::Shellfish.matches("1 lb shrimp, peeled"));
+    assert!(Allergen::Other("kiwi".to_string()).matches("2 kiwis, sliced"));
+    Ok(())
+}
+
+#[test]
+fn test_allergens_are_hard_conflicts() -> TestResult {
+    let model = household()?;
+    let diners = Diners::everyone(&model.family);
+    let satay = recipe(&["1 lb chicken thighs", "1/4 cup peanut butter", "1 cup cremini mushrooms"], None);
+
+    let conflicts = diners.conflicts(&satay);
+    let rendered: Vec<String> = conflicts.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        rendered,
+        vec![
+            "David eats vegetarian on Fri: \"1 lb chicken thighs\"",
+            "Daughter avoids peanut: \"1/4 cup peanut butter\"",
+            "Daughter dislikes mushrooms: \"1 cup cremini mushrooms\"",
+        ]
+    );
+    let hard: Vec<bool> = conflicts.iter().map(|c| c.hard).collect();
+    // The meal's day isn't known, so the vegetarian day only flags it
+    assert_eq!(hard, vec![false, true, false]);
+    Ok(())
+}
+
+#[test]
+fn test_vegetarian_days_depend_on_the_meal_day() -> TestResult {
+    let model = household()?;
+    let chicken = recipe(&["1 lb chicken thighs"], Some("chicken_thigh"));
+
+    let friday = Diners::everyone(&model.family).on(Weekday::Fri);
+    let conflicts = friday.conflicts(&chicken);
+    assert_eq!(conflicts.len(), 1);
+    assert!(conflicts[0].hard);
+    assert_eq!(conflicts[0].ingredient, "chicken_thigh");
+
+    let monday = Diners::everyone(&model.family).on(Weekday::Mon);
+    assert!(monday.conflicts(&chicken).is_empty());
+
+    let tofu = recipe(&["1 block tofu", "4 cups vegetable broth"], Some("tofu"));
+    assert!(friday.conflicts(&tofu).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_only_members_at_the_table_count() -> TestResult {
+    let model = household()?;
+    let shrimp = recipe(&["1 lb shrimp"], Some("shrimp"));
+    let diners = Diners {
+        members: model.family.members.iter().filter(|m| m.name != "Christine").collect(),
+        day: Some(Weekday::Tue),
+    };
+    assert!(diners.conflicts(&shrimp).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_spice_level_from_ingredients_and_tags() -> TestResult {
+    let mild = recipe(&["1 tsp chili powder", "1 lb ground beef"], None);
+    assert_eq!(spice_level(&mild), (SpiceLevel::Mild, Some("1 tsp chili powder".to_string())));
+
+    let hot = recipe(&["2 Tbsp sriracha", "2 habaneros, minced"], None);
+    assert_eq!(spice_level(&hot).0, SpiceLevel::Hot);
+
+    let mut tagged = recipe(&["1 lb chicken thighs"], None);
+    tagged.tags = vec!["Spicy".to_string()];
+    assert_eq!(spice_level(&tagged), (SpiceLevel::Medium, Some("spicy".to_string())));
+
+    assert_eq!(spice_level(&recipe(&["1 tsp smoked paprika"], None)).0, SpiceLevel::None);
+    Ok(())
+}
+
+#[test]
+fn test_member_preferences_load() -> TestResult {
+    let yaml = r#"
+family:
+  members:
+    - name: Daughter
+      likes: [corn]
+      liked_proteins: [chicken_thigh]
+      disliked_proteins: [salmon]
+      liked_cuisines: [mexican]
+      disliked_cuisines: [indian]
+      spice_tolerance: mild
+stores: {}
+"#;
+    let model = HouseholdModel::from_yaml(yaml)?;
+    let member = &model.family.members[0];
+    assert_eq!(member.likes, vec!["corn"]);
+    assert_eq!(member.disliked_proteins, vec!["salmon"]);
+    assert_eq!(member.disliked_cuisines, vec!["indian"]);
+    assert_eq!(member.spice_tolerance, Some(SpiceLevel::Mild));
+    Ok(())
+}
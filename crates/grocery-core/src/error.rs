@@ -1,15 +1,29 @@
 use thiserror::Error;
 
+use crate::diagnostics::Location;
+
 #[derive(Debug, Error)]
 pub enum GroceryError {
-    #[error("failed to parse household model: {0}")]
-    HouseholdParse(String),
+    #[error("failed to parse household model: {message}")]
+    HouseholdParse {
+        message: String,
+        location: Option<Location>,
+    },
 
     #[error("failed to parse recipe data: {0}")]
     RecipeParse(String),
 
-    #[error("failed to parse scoring config: {0}")]
-    ScoringConfigParse(String),
+    #[error("failed to parse scoring config: {message}")]
+    ScoringConfigParse {
+        message: String,
+        location: Option<Location>,
+    },
+
+    #[error("failed to parse cooking log: {message}")]
+    CookingLogParse {
+        message: String,
+        location: Option<Location>,
+    },
 
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
@@ -27,4 +41,29 @@ pub enum GroceryError {
     RecipeNotFound(String),
 }
 
+impl GroceryError {
+    /// Where in the source file the error is, when known.
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Self::HouseholdParse { location, .. }
+            | Self::ScoringConfigParse { location, .. }
+            | Self::CookingLogParse { location, .. } => *location,
+            Self::Yaml(e) => yaml_location(e),
+            Self::Json(e) => (e.line() > 0).then(|| Location {
+                line: e.line(),
+                column: e.column().max(1),
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Location of a serde_yaml error, 1-based.
+pub(crate) fn yaml_location(e: &serde_yaml::Error) -> Option<Location> {
+    e.location().map(|l| Location {
+        line: l.line(),
+        column: l.column(),
+    })
+}
+
 pub type GroceryResult<T> = Result<T, GroceryError>;
@@ -0,0 +1,208 @@
+use chrono::{Duration, NaiveDate};
+
+use crate::household::HouseholdModel;
+use crate::order::Order;
+use crate::types::{FrequencyTier, Store};
+
+/// Which orders to count when deriving purchase frequency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HistoryWindow {
+    /// Every imported order.
+    #[default]
+    All,
+    /// The N most recent orders.
+    LastOrders(usize),
+    /// Orders placed within N weeks of the most recent order.
+    LastWeeks(u32),
+}
+
+impl HistoryWindow {
+    /// Select the orders inside this window. `orders` may be in any order;
+    /// the result is newest first.
+    pub fn select<'a>(&self, orders: &[&'a Order]) -> Vec<&'a Order> {
+        let mut sorted: Vec<&Order> = orders.to_vec();
+        sorted.sort_by_key(|o| std::cmp::Reverse(o.date));
+
+        match *self {
+            Self::All => sorted,
+            Self::LastOrders(n) => sorted.into_iter().take(n).collect(),
+            Self::LastWeeks(weeks) => {
+                let Some(newest) = sorted.first().map(|o| o.date) else {
+                    return sorted;
+                };
+                let cutoff = newest - Duration::weeks(i64::from(weeks));
+                sorted.into_iter().filter(|o| o.date > cutoff).collect()
+            }
+        }
+    }
+}
+
+/// Default half-life for purchase recency weighting.
+pub const DEFAULT_HALF_LIFE_WEEKS: f64 = 8.0;
+
+/// Weights orders by recency: an order one half-life older than the newest
+/// counts half as much.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DecayModel {
+    pub half_life_days: f64,
+}
+
+impl Default for DecayModel {
+    fn default() -> Self {
+        Self::from_weeks(DEFAULT_HALF_LIFE_WEEKS)
+    }
+}
+
+/// Purchase propensity for one item and the tier it implies.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DecayedFrequency {
+    /// Recency-weighted share of orders containing the item, 0.0–1.0.
+    pub propensity: f64,
+    pub tier: FrequencyTier,
+}
+
+impl DecayModel {
+    pub fn from_weeks(weeks: f64) -> Self {
+        Self {
+            half_life_days: weeks * 7.0,
+        }
+    }
+
+    /// Weight of an order placed on `date`, relative to one on `newest`.
+    pub fn weight(&self, date: NaiveDate, newest: NaiveDate) -> f64 {
+        if self.half_life_days <= 0.0 {
+            return if date >= newest { 1.0 } else { 0.0 };
+        }
+        let age_days = (newest - date).num_days().max(0) as f64;
+        0.5_f64.powf(age_days / self.half_life_days)
+    }
+
+    /// Recency-weighted share of `orders` containing `item`.
+    ///
+    /// Ages are measured from the newest order rather than today, so a gap
+    /// since the last import doesn't decay every item equally.
+    pub fn evaluate(&self, item: &str, orders: &[&Order]) -> DecayedFrequency {
+        let Some(newest) = orders.iter().map(|o| o.date).max() else {
+            return DecayedFrequency {
+                propensity: 0.0,
+                tier: FrequencyTier::Rare,
+            };
+        };
+
+        let mut total = 0.0;
+        let mut bought = 0.0;
+        for order in orders {
+            let weight = self.weight(order.date, newest);
+            total += weight;
+            if order.lines.iter().any(|l| l.matches_item(item)) {
+                bought += weight;
+            }
+        }
+
+        let propensity = if total > 0.0 { bought / total } else { 0.0 };
+        DecayedFrequency {
+            propensity,
+            tier: FrequencyTier::from_ratio(propensity),
+        }
+    }
+}
+
+/// A recurring item whose tier changed during a refresh.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TierChange {
+    pub item: String,
+    pub old_frequency: Option<String>,
+    pub old_tier: FrequencyTier,
+    pub new_frequency: String,
+    pub new_propensity: f64,
+    pub new_tier: FrequencyTier,
+}
+
+/// Outcome of recomputing one store's item frequencies from order history.
+#[derive(Debug, Clone, Default)]
+pub struct RefreshReport {
+    /// Number of orders in the window.
+    pub orders_counted: usize,
+    /// Items whose frequency string was rewritten.
+    pub updated: usize,
+    /// Items that moved to a different tier.
+    pub changes: Vec<TierChange>,
+    /// Items never seen in any order line; left unchanged so a naming
+    /// mismatch doesn't silently demote a staple.
+    pub unmatched: Vec<String>,
+}
+
+/// Recompute `frequency` ("appearances/orders"), the decayed `propensity`
+/// and `oos_count` for every recurring item at `store` from that store's
+/// orders, so tiers are relative to how often we shop there.
+pub fn refresh_frequencies(
+    model: &mut HouseholdModel,
+    store: &Store,
+    orders: &[&Order],
+    decay: &DecayModel,
+) -> RefreshReport {
+    let total = orders.len() as u32;
+    let mut report = RefreshReport {
+        orders_counted: orders.len(),
+        ..RefreshReport::default()
+    };
+
+    if orders.is_empty() {
+        return report;
+    }
+
+    for item in model.items_for_store_mut(store) {
+        let mut appearances = 0u32;
+        let mut out_of_stock = 0u32;
+        for order in orders {
+            let matching: Vec<_> = order
+                .lines
+                .iter()
+                .filter(|l| l.matches_item(&item.item))
+                .collect();
+            if matching.is_empty() {
+                continue;
+            }
+            appearances += 1;
+            if matching.iter().all(|l| l.out_of_stock) {
+                out_of_stock += 1;
+            }
+        }
+
+        if appearances == 0 {
+            report.unmatched.push(item.item.clone());
+            continue;
+        }
+
+        let old_tier = item.tier();
+        let old_frequency = item.frequency.clone();
+        let new_frequency = format!("{appearances}/{total}");
+        let decayed = decay.evaluate(&item.item, orders);
+        // Two decimals keeps household-model.yaml readable
+        let new_propensity = (decayed.propensity * 100.0).round() / 100.0;
+
+        item.frequency = Some(new_frequency.clone());
+        item.propensity = Some(new_propensity);
+        item.oos_count = (out_of_stock > 0).then_some(out_of_stock);
+        report.updated += 1;
+
+        let new_tier = item.tier();
+
+        if old_tier != new_tier {
+            report.changes.push(TierChange {
+                item: item.item.clone(),
+                old_frequency,
+                old_tier,
+                new_frequency,
+                new_propensity,
+                new_tier,
+            });
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+#[path = "frequency_tests.rs"]
+mod tests;
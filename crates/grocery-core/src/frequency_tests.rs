@@ -0,0 +1,191 @@
+use super::*;
+use crate::order::parse_order_history;
+use crate::types::Store;
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+const ORDERS: &str = "\
+## Order #m3 - September 20, 2025
+### Produce
+- Limes - 2
+- Seedless Cucumbers - 3 ct - 1
+### Out of Stock Items
+- **Daisy** Sour Cream - 0
+
+## Order #m2 - September 1, 2025
+### Produce
+- Limes - 1
+### Dairy & Eggs
+- **Daisy** Sour Cream - 1
+
+## Order #m1 - July 1, 2025
+### Produce
+- Limes - 1
+- Lime Juice - 1
+### Dairy & Eggs
+- **Daisy** Sour Cream - 1
+";
+
+const MODEL: &str = r#"
+family:
+  members: []
+stores: {}
+giant_recurring:
+  - item: "Limes"
+    category: produce
+    frequency: "4/18"
+  - item: "Daisy Sour Cream"
+    category: dairy
+    frequency: "14/18"
+  - item: "Seedless Cucumber"
+    category: produce
+    frequency: "1/18"
+  - item: "Dragon Fruit"
+    category: produce
+    frequency: "9/18"
+"#;
+
+fn orders() -> Vec<Order> {
+    parse_order_history(ORDERS, &Store::Giant).orders
+}
+
+#[test]
+fn test_window_last_orders_and_weeks() -> TestResult {
+    let orders = orders();
+    let refs: Vec<&Order> = orders.iter().rev().collect();
+
+    let ids = |selected: Vec<&Order>| selected.iter().map(|o| o.id.clone()).collect::<Vec<_>>();
+    assert_eq!(ids(HistoryWindow::All.select(&refs)), vec!["m3", "m2", "m1"]);
+    assert_eq!(ids(HistoryWindow::LastOrders(2).select(&refs)), vec!["m3", "m2"]);
+    // Weeks are counted back from the newest order, not today
+    assert_eq!(ids(HistoryWindow::LastWeeks(4).select(&refs)), vec!["m3", "m2"]);
+    assert_eq!(ids(HistoryWindow::LastWeeks(1).select(&refs)), vec!["m3"]);
+    assert!(HistoryWindow::LastWeeks(4).select(&[]).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_refresh_counts_appearances_per_order() -> TestResult {
+    let mut model = HouseholdModel::from_yaml(MODEL)?;
+    let orders = orders();
+    let refs: Vec<&Order> = orders.iter().collect();
+
+    let report = refresh_frequencies(&mut model, &Store::Giant, &refs, &DecayModel::default());
+    assert_eq!(report.orders_counted, 3);
+    assert_eq!(report.updated, 3);
+
+    // "Limes" and "Lime Juice" in one order count once
+    let limes = &model.recurring[0];
+    assert_eq!(limes.frequency.as_deref(), Some("3/3"));
+    assert_eq!(limes.propensity, Some(1.0));
+    assert_eq!(limes.tier(), FrequencyTier::EveryOrder);
+
+    // Out-of-stock lines still count as wanted, and are tallied
+    let sour_cream = &model.recurring[1];
+    assert_eq!(sour_cream.frequency.as_deref(), Some("3/3"));
+    assert_eq!(sour_cream.oos_count, Some(1));
+
+    assert_eq!(model.recurring[2].frequency.as_deref(), Some("1/3"));
+    Ok(())
+}
+
+#[test]
+fn test_refresh_reports_tier_moves_and_unmatched() -> TestResult {
+    let mut model = HouseholdModel::from_yaml(MODEL)?;
+    let orders = orders();
+    let refs: Vec<&Order> = orders.iter().collect();
+
+    let report = refresh_frequencies(&mut model, &Store::Giant, &refs, &DecayModel::default());
+
+    let moved: Vec<&str> = report.changes.iter().map(|c| c.item.as_str()).collect();
+    assert_eq!(moved, vec!["Limes", "Seedless Cucumber"]);
+    let limes = &report.changes[0];
+    assert_eq!(limes.old_frequency.as_deref(), Some("4/18"));
+    assert_eq!(limes.old_tier, FrequencyTier::Occasional);
+    assert_eq!(limes.new_tier, FrequencyTier::EveryOrder);
+
+    // Items never seen keep their hand-entered frequency
+    assert_eq!(report.unmatched, vec!["Dragon Fruit"]);
+    assert_eq!(model.recurring[3].frequency.as_deref(), Some("9/18"));
+    Ok(())
+}
+
+#[test]
+fn test_refresh_with_no_orders_changes_nothing() -> TestResult {
+    let mut model = HouseholdModel::from_yaml(MODEL)?;
+    let report = refresh_frequencies(&mut model, &Store::Giant, &[], &DecayModel::default());
+    assert_eq!(report.updated, 0);
+    assert!(report.changes.is_empty());
+    assert_eq!(model.recurring[0].frequency.as_deref(), Some("4/18"));
+    Ok(())
+}
+
+#[test]
+fn test_decay_weight_halves_each_half_life() -> TestResult {
+    let decay = DecayModel::from_weeks(2.0);
+    let newest = NaiveDate::from_ymd_opt(2025, 9, 29).ok_or("bad date")?;
+    let older = NaiveDate::from_ymd_opt(2025, 9, 15).ok_or("bad date")?;
+    let oldest = NaiveDate::from_ymd_opt(2025, 9, 1).ok_or("bad date")?;
+    assert!((decay.weight(newest, newest) - 1.0).abs() < 1e-9);
+    assert!((decay.weight(older, newest) - 0.5).abs() < 1e-9);
+    assert!((decay.weight(oldest, newest) - 0.25).abs() < 1e-9);
+    Ok(())
+}
+
+#[test]
+fn test_decay_demotes_items_no_longer_bought() -> TestResult {
+    // Bought in 8 of 12 weekly orders, but none of the last 4
+    let mut md = String::new();
+    for week in 0..12u32 {
+        let date = NaiveDate::from_ymd_opt(2025, 1, 6).ok_or("bad date")?
+            + Duration::weeks(i64::from(week));
+        md.push_str(&format!("## Order #w{week} - {}\n### Produce\n", date.format("%B %d, %Y")));
+        md.push_str("- Bananas - 1\n");
+        if week < 8 {
+            md.push_str("- Limes - 1\n");
+        }
+    }
+    let orders = parse_order_history(&md, &Store::Giant).orders;
+    let refs: Vec<&Order> = orders.iter().collect();
+
+    // Flat counting still calls it a staple
+    assert_eq!(FrequencyTier::from_frequency(8, 12), FrequencyTier::EveryOrder);
+
+    let decayed = DecayModel::from_weeks(2.0).evaluate("Limes", &refs);
+    assert!(decayed.propensity < 0.25, "propensity {}", decayed.propensity);
+    assert_eq!(decayed.tier, FrequencyTier::Occasional);
+
+    let bananas = DecayModel::from_weeks(2.0).evaluate("Bananas", &refs);
+    assert!((bananas.propensity - 1.0).abs() < 1e-9);
+    assert_eq!(bananas.tier, FrequencyTier::EveryOrder);
+
+    // A very long half-life approaches flat counting
+    let flat = DecayModel::from_weeks(10_000.0).evaluate("Limes", &refs);
+    assert!((flat.propensity - 8.0 / 12.0).abs() < 0.01);
+    Ok(())
+}
+
+#[test]
+fn test_refreshed_propensity_drives_staples() -> TestResult {
+    let mut model = HouseholdModel::from_yaml(MODEL)?;
+    let orders = orders();
+    let refs: Vec<&Order> = orders.iter().collect();
+
+    // Seedless Cucumber only appears in the newest order
+    refresh_frequencies(&mut model, &Store::Giant, &refs, &DecayModel::from_weeks(1.0));
+    let cucumber = &model.recurring[2];
+    assert_eq!(cucumber.frequency.as_deref(), Some("1/3"));
+    assert!(cucumber.propensity.ok_or("propensity not set")? > 0.67);
+
+    let staples: Vec<&str> = model.staples().iter().map(|i| i.item.as_str()).collect();
+    assert_eq!(staples, vec!["Limes", "Daisy Sour Cream", "Seedless Cucumber"]);
+    Ok(())
+}
+
+#[test]
+fn test_evaluate_without_orders_is_rare() -> TestResult {
+    let decayed = DecayModel::default().evaluate("Limes", &[]);
+    assert_eq!(decayed.propensity, 0.0);
+    assert_eq!(decayed.tier, FrequencyTier::Rare);
+    Ok(())
+}
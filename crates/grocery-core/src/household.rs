@@ -1,23 +1,38 @@
+use std::collections::BTreeMap;
 use std::path::Path;
 
-use serde::{Deserialize, Serialize};
+use chrono::{NaiveDate, Weekday};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_yaml::Mapping;
 
-use crate::error::{GroceryError, GroceryResult};
+use crate::atomic::write_atomic;
+use crate::dates::{self, CycleDays};
+use crate::diet::{Allergen, SpiceLevel};
+use crate::error::{yaml_location, GroceryError, GroceryResult};
+use crate::item::RecurringItem;
+use crate::layout::StoreLayout;
+use crate::routing::{Route, StoreRouting};
 use crate::types::{Category, FrequencyTier, Store};
 
 /// Top-level household model parsed from household-model.yaml.
+///
+/// Older files keep recurring items in per-store lists (`giant_recurring`,
+/// `amazon_recurring`, `acme_recurring`); those load into `recurring` with
+/// the store taken from the list, and are written back in the unified form.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "HouseholdModelFile")]
 pub struct HouseholdModel {
     pub family: FamilyProfile,
     pub stores: Stores,
     #[serde(default)]
-    pub amazon_recurring: Vec<AmazonItem>,
-    #[serde(default)]
-    pub giant_recurring: Vec<GiantItem>,
-    #[serde(default)]
-    pub acme_recurring: Vec<serde_yaml::Value>,
-    #[serde(default)]
+    pub recurring: Vec<RecurringItem>,
+    #[serde(default, skip_serializing_if = "StoreRouting::is_default")]
+    pub routing: StoreRouting,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub meal_plan_source: Option<String>,
+    /// Keys this version doesn't model, written back as they were.
+    #[serde(flatten)]
+    pub extra: Mapping,
 }
 
 impl HouseholdModel {
@@ -29,20 +44,140 @@ impl HouseholdModel {
 
     /// Parse from a YAML string.
     pub fn from_yaml(yaml: &str) -> GroceryResult<Self> {
-        serde_yaml::from_str(yaml).map_err(|e| GroceryError::HouseholdParse(e.to_string()))
+        serde_yaml::from_str(yaml).map_err(|e| GroceryError::HouseholdParse {
+            location: yaml_location(&e),
+            message: e.to_string(),
+        })
+    }
+
+    /// Parse, also returning where each recurring item came from in the
+    /// file as `(list key, index)`, e.g. `("giant_recurring", 3)`.
+    pub(crate) fn from_yaml_with_sources(yaml: &str) -> GroceryResult<(Self, Vec<(&'static str, usize)>)> {
+        let file: HouseholdModelFile =
+            serde_yaml::from_str(yaml).map_err(|e| GroceryError::HouseholdParse {
+                location: yaml_location(&e),
+                message: e.to_string(),
+            })?;
+        Ok(file.into_model())
+    }
+
+    /// Write back to a YAML file, keeping the previous version as `<file>.bak`.
+    /// The file is replaced atomically, so a crash can't leave it truncated.
+    ///
+    /// Keys the model doesn't know are kept; comments are not.
+    pub fn save(&self, path: &Path) -> GroceryResult<()> {
+        let yaml = serde_yaml::to_string(self)?;
+        if path.exists() {
+            std::fs::copy(path, path.with_extension("yaml.bak"))?;
+        }
+        write_atomic(path, &yaml)
+    }
+
+    /// Recurring items bought at one store.
+    pub fn items_for_store(&self, store: &Store) -> Vec<&RecurringItem> {
+        self.recurring.iter().filter(|item| &item.store == store).collect()
+    }
+
+    /// Mutable access to one store's recurring items.
+    pub fn items_for_store_mut(&mut self, store: &Store) -> Vec<&mut RecurringItem> {
+        self.recurring
+            .iter_mut()
+            .filter(|item| &item.store == store)
+            .collect()
+    }
+
+    /// Get one store's recurring items at a given tier.
+    pub fn items_by_tier(&self, store: &Store, tier: FrequencyTier) -> Vec<&RecurringItem> {
+        self.recurring
+            .iter()
+            .filter(|item| &item.store == store && item.tier() == tier)
+            .collect()
     }
 
     /// Get all Giant recurring items at a given tier.
-    pub fn giant_items_by_tier(&self, tier: FrequencyTier) -> Vec<&GiantItem> {
-        self.giant_recurring
+    pub fn giant_items_by_tier(&self, tier: FrequencyTier) -> Vec<&RecurringItem> {
+        self.items_by_tier(&Store::Giant, tier)
+    }
+
+    /// Stores that have at least one recurring item, in first-seen order.
+    pub fn recurring_stores(&self) -> Vec<&Store> {
+        let mut stores: Vec<&Store> = Vec::new();
+        for item in &self.recurring {
+            if !stores.contains(&&item.store) {
+                stores.push(&item.store);
+            }
+        }
+        stores
+    }
+
+    /// Which store to buy an item at; see [`StoreRouting::route`].
+    pub fn route(&self, item: &str) -> Route {
+        self.routing.route(item, &self.recurring)
+    }
+
+    /// Get all tier 1 (every order) items, across stores.
+    pub fn staples(&self) -> Vec<&RecurringItem> {
+        self.recurring
             .iter()
-            .filter(|item| item.tier() == tier)
+            .filter(|item| item.tier() == FrequencyTier::EveryOrder)
             .collect()
     }
+}
 
-    /// Get all tier 1 (every order) Giant items.
-    pub fn staples(&self) -> Vec<&GiantItem> {
-        self.giant_items_by_tier(FrequencyTier::EveryOrder)
+/// On-disk layout, accepting both the unified `recurring` list and the
+/// older per-store lists.
+#[derive(Deserialize)]
+struct HouseholdModelFile {
+    family: FamilyProfile,
+    stores: Stores,
+    #[serde(default)]
+    recurring: Vec<RecurringItem>,
+    #[serde(default)]
+    giant_recurring: Vec<LegacyItem>,
+    #[serde(default)]
+    amazon_recurring: Vec<LegacyItem>,
+    #[serde(default)]
+    acme_recurring: Vec<LegacyItem>,
+    #[serde(default)]
+    routing: StoreRouting,
+    #[serde(default)]
+    meal_plan_source: Option<String>,
+    #[serde(flatten)]
+    extra: Mapping,
+}
+
+impl HouseholdModelFile {
+    fn into_model(self) -> (HouseholdModel, Vec<(&'static str, usize)>) {
+        let mut sources: Vec<(&'static str, usize)> =
+            (0..self.recurring.len()).map(|i| ("recurring", i)).collect();
+        let mut recurring = self.recurring;
+
+        for (key, store, items) in [
+            ("giant_recurring", Store::Giant, self.giant_recurring),
+            ("amazon_recurring", Store::Amazon, self.amazon_recurring),
+            ("acme_recurring", Store::Acme, self.acme_recurring),
+        ] {
+            for (i, item) in items.into_iter().enumerate() {
+                sources.push((key, i));
+                recurring.push(item.into_recurring(store.clone()));
+            }
+        }
+
+        let model = HouseholdModel {
+            family: self.family,
+            stores: self.stores,
+            recurring,
+            routing: self.routing,
+            meal_plan_source: self.meal_plan_source,
+            extra: self.extra,
+        };
+        (model, sources)
+    }
+}
+
+impl From<HouseholdModelFile> for HouseholdModel {
+    fn from(file: HouseholdModelFile) -> Self {
+        file.into_model().0
     }
 }
 
@@ -50,103 +185,206 @@ impl HouseholdModel {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FamilyProfile {
     pub members: Vec<FamilyMember>,
+    /// Unmodeled keys, kept on save.
+    #[serde(flatten)]
+    pub extra: Mapping,
+}
+
+impl FamilyProfile {
+    /// Members who eat the family's meals.
+    pub fn eaters(&self) -> impl Iterator<Item = &FamilyMember> {
+        self.members.iter().filter(|m| m.eats)
+    }
+
+    /// Servings the family eats at a meal: each eating member's portion for
+    /// their age stage (adult if no age is given), rounded up. `None` if
+    /// nobody eats.
+    pub fn servings(&self) -> Option<f64> {
+        let total: f64 = self.eaters().map(|m| m.stage().portion()).sum();
+        (total > 0.0).then(|| total.ceil())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FamilyMember {
     pub name: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub age: Option<u8>,
+    /// Whether this member eats the family's meals; `false` leaves them out
+    /// of servings and recipe fit (a baby on formula, a lodger).
+    #[serde(default = "default_true", skip_serializing_if = "is_true")]
+    pub eats: bool,
+    /// Allergies and intolerances, e.g. `[peanut, tree_nut]`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allergens: Vec<Allergen>,
+    /// Days this member eats vegetarian, e.g. `[Fri]` or `[friday]`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub vegetarian_days: Vec<Weekday>,
+    /// Ingredients this member won't eat, matched as words.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dislikes: Vec<String>,
+    /// Ingredients this member enjoys, matched as words.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub likes: Vec<String>,
+    /// Proteins (`primary_protein` names) this member enjoys.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub liked_proteins: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disliked_proteins: Vec<String>,
+    /// Cuisines (recipe tags) this member enjoys.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub liked_cuisines: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disliked_cuisines: Vec<String>,
+    /// Hottest food this member is happy with; `None` if they'll eat anything.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spice_tolerance: Option<SpiceLevel>,
+    /// Unmodeled keys, kept on save.
+    #[serde(flatten)]
+    pub extra: Mapping,
 }
 
 /// Store configurations.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Stores {
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub giant: Option<StoreConfig>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub acme: Option<StoreConfig>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub amazon: Option<StoreConfig>,
+    /// Any other store, keyed by name (`trader_joes`, `H Mart`).
+    #[serde(flatten)]
+    pub other: BTreeMap<String, StoreConfig>,
+}
+
+impl Stores {
+    /// Configuration for one store, if there is any.
+    pub fn get(&self, store: &Store) -> Option<&StoreConfig> {
+        match store {
+            Store::Giant => self.giant.as_ref(),
+            Store::Acme => self.acme.as_ref(),
+            Store::Amazon => self.amazon.as_ref(),
+            _ => self.other.iter().find_map(|(name, config)| {
+                let Ok(parsed) = name.parse::<Store>();
+                (&parsed == store).then_some(config)
+            }),
+        }
+    }
+
+    /// The layout configured for one store.
+    pub fn layout(&self, store: &Store) -> Option<&StoreLayout> {
+        self.get(store).and_then(|config| config.layout.as_ref())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StoreConfig {
     #[serde(rename = "type")]
     pub store_type: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub order_method: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub frequency: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub account: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub store: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub avg_order_total: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub total_annual_spend: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub avg_items_per_order: Option<u32>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_sources: Option<Vec<String>>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_source: Option<String>,
+    /// Walking order for shopping lists at this store.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub layout: Option<StoreLayout>,
+    /// Unmodeled keys, kept on save.
+    #[serde(flatten)]
+    pub extra: Mapping,
 }
 
-/// Amazon recurring item from household model.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct AmazonItem {
-    pub item: String,
-    pub category: Category,
+/// A recurring item from one of the old per-store lists, where the store
+/// comes from the list the item is in unless the item names one itself.
+#[derive(Deserialize)]
+struct LegacyItem {
+    item: String,
+    category: Category,
     #[serde(default)]
-    pub cycle_days: Option<String>,
+    store: Option<Store>,
     #[serde(default)]
-    pub last_seen: Option<String>,
+    tier: Option<FrequencyTier>,
     #[serde(default)]
-    pub note: Option<String>,
-}
-
-/// Giant recurring item from household model.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct GiantItem {
-    pub item: String,
-    pub category: Category,
-    #[serde(default)]
-    pub frequency: Option<String>,
+    frequency: Option<String>,
     #[serde(default)]
-    pub typical_qty: Option<String>,
+    propensity: Option<f64>,
     #[serde(default)]
-    pub price: Option<String>,
+    typical_qty: Option<String>,
     #[serde(default)]
-    pub store: Option<Store>,
+    price: Option<String>,
     #[serde(default)]
-    pub oos_count: Option<u32>,
+    oos_count: Option<u32>,
     #[serde(default)]
-    pub note: Option<String>,
+    note: Option<String>,
+    #[serde(default, deserialize_with = "cycle_days")]
+    cycle_days: Option<CycleDays>,
+    #[serde(default, deserialize_with = "last_seen")]
+    last_seen: Option<NaiveDate>,
+    #[serde(flatten)]
+    extra: Mapping,
 }
 
-impl GiantItem {
-    /// Parse frequency string like "14/18" into a tier.
-    pub fn tier(&self) -> FrequencyTier {
-        let Some(freq) = &self.frequency else {
-            return FrequencyTier::Rare;
-        };
-        parse_frequency_tier(freq)
+impl LegacyItem {
+    fn into_recurring(self, list_store: Store) -> RecurringItem {
+        RecurringItem {
+            item: self.item,
+            category: self.category,
+            store: self.store.unwrap_or(list_store),
+            tier: self.tier,
+            frequency: self.frequency,
+            propensity: self.propensity,
+            typical_qty: self.typical_qty,
+            price: self.price,
+            oos_count: self.oos_count,
+            note: self.note,
+            cycle_days: self.cycle_days,
+            last_seen: self.last_seen,
+            extra: self.extra,
+        }
     }
 }
 
 /// Parse "14/18" style frequency strings into a tier.
-fn parse_frequency_tier(freq: &str) -> FrequencyTier {
+pub(crate) fn parse_frequency_tier(freq: &str) -> FrequencyTier {
     let parts: Vec<&str> = freq.split('/').collect();
     if parts.len() != 2 {
         return FrequencyTier::Rare;
     }
-    let appearances = parts[0].trim().parse::<u8>().unwrap_or(0);
-    let total = parts[1].trim().parse::<u8>().unwrap_or(0);
+    let appearances = parts[0].trim().parse::<u32>().unwrap_or(0);
+    let total = parts[1].trim().parse::<u32>().unwrap_or(0);
     FrequencyTier::from_frequency(appearances, total)
 }
 
+pub(crate) fn cycle_days<'de, D: Deserializer<'de>>(d: D) -> Result<Option<CycleDays>, D::Error> {
+    dates::deserialize_opt_cycle(d, "cycle_days")
+}
+
+pub(crate) fn last_seen<'de, D: Deserializer<'de>>(d: D) -> Result<Option<NaiveDate>, D::Error> {
+    dates::deserialize_opt_date(d, "last_seen")
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn is_true(value: &bool) -> bool {
+    *value
+}
+
 #[cfg(test)]
 #[path = "household_tests.rs"]
 mod tests;
@@ -23,10 +23,10 @@ fn test_parse_household_model_from_real_yaml() -> TestResult {
     assert_eq!(model.family.members[1].name, "Christine");
 
     // Should have Giant recurring items
-    assert!(!model.giant_recurring.is_empty());
+    assert!(!model.items_for_store(&Store::Giant).is_empty());
 
     // Should have Amazon recurring items
-    assert!(!model.amazon_recurring.is_empty());
+    assert!(!model.items_for_store(&Store::Amazon).is_empty());
 
     // Giant store config should exist
     let giant = model.stores.giant.as_ref().ok_or("missing giant store config")?;
@@ -60,7 +60,7 @@ giant_recurring:
     frequency: "1/18"
 "#;
     let model = HouseholdModel::from_yaml(yaml)?;
-    assert_eq!(model.giant_recurring.len(), 4);
+    assert_eq!(model.recurring.len(), 4);
 
     let staples = model.staples();
     assert_eq!(staples.len(), 1);
@@ -102,6 +102,234 @@ giant_recurring:
     category: dairy
 "#;
     let model = HouseholdModel::from_yaml(yaml)?;
-    assert_eq!(model.giant_recurring[0].tier(), FrequencyTier::Rare);
+    assert_eq!(model.recurring[0].tier(), FrequencyTier::Rare);
+    Ok(())
+}
+
+#[test]
+fn test_propensity_overrides_frequency_string() -> TestResult {
+    let yaml = r#"
+family:
+  members: []
+stores: {}
+giant_recurring:
+  - item: "Lapsed Staple"
+    category: dairy
+    frequency: "14/18"
+    propensity: 0.1
+"#;
+    let model = HouseholdModel::from_yaml(yaml)?;
+    assert_eq!(model.recurring[0].tier(), FrequencyTier::Rare);
+    assert!(model.staples().is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_amazon_item_dates_are_typed() -> TestResult {
+    let yaml = r#"
+family:
+  members: []
+stores: {}
+amazon_recurring:
+  - item: "Diapers"
+    category: baby
+    cycle_days: "~30 days"
+    last_seen: "Aug 28, 2025"
+  - item: "Dog Food"
+    category: pet
+    cycle_days: 45
+    last_seen: 2025-08-01
+"#;
+    let model = HouseholdModel::from_yaml(yaml)?;
+    let diapers = &model.recurring[0];
+    assert_eq!(diapers.cycle_days, Some(CycleDays::exactly(30)));
+    assert_eq!(diapers.last_seen, NaiveDate::from_ymd_opt(2025, 8, 28));
+    let dog_food = &model.recurring[1];
+    assert_eq!(dog_food.cycle_days, Some(CycleDays::exactly(45)));
+    assert_eq!(dog_food.last_seen, NaiveDate::from_ymd_opt(2025, 8, 1));
+
+    // Round-trips through YAML
+    let reloaded = HouseholdModel::from_yaml(&serde_yaml::to_string(&model)?)?;
+    assert_eq!(reloaded.recurring[0].cycle_days, diapers.cycle_days);
+    assert_eq!(reloaded.recurring[0].last_seen, diapers.last_seen);
+    Ok(())
+}
+
+#[test]
+fn test_bad_amazon_dates_name_the_field() -> TestResult {
+    let yaml = r#"
+family:
+  members: []
+stores: {}
+amazon_recurring:
+  - item: "Diapers"
+    category: baby
+    cycle_days: "whenever"
+"#;
+    let err = HouseholdModel::from_yaml(yaml).err().ok_or("expected an error")?;
+    assert!(err.to_string().contains("invalid cycle_days \"whenever\""), "{err}");
+
+    let yaml = yaml.replace("cycle_days: \"whenever\"", "last_seen: \"28/08/2025\"");
+    let err = HouseholdModel::from_yaml(&yaml).err().ok_or("expected an error")?;
+    assert!(err.to_string().contains("invalid last_seen \"28/08/2025\""), "{err}");
+    Ok(())
+}
+
+#[test]
+fn test_legacy_store_lists_merge_into_recurring() -> TestResult {
+    let yaml = r#"
+family:
+  members: []
+stores: {}
+recurring:
+  - item: "Everything Bagel Seasoning"
+    category: condiments
+    store: trader_joes
+    frequency: "5/6"
+giant_recurring:
+  - item: "Bananas"
+    category: produce
+    frequency: "16/18"
+amazon_recurring:
+  - item: "Diapers"
+    category: baby
+    cycle_days: 30
+    tier: Occasional
+acme_recurring:
+  - item: "Rotisserie Chicken"
+    category: deli
+    store: giant
+"#;
+    let model = HouseholdModel::from_yaml(yaml)?;
+    let stores: Vec<(&str, &Store)> = model
+        .recurring
+        .iter()
+        .map(|i| (i.item.as_str(), &i.store))
+        .collect();
+    assert_eq!(
+        stores,
+        vec![
+            ("Everything Bagel Seasoning", &Store::TraderJoes),
+            ("Bananas", &Store::Giant),
+            ("Diapers", &Store::Amazon),
+            // An item's own store wins over the list it sits in
+            ("Rotisserie Chicken", &Store::Giant),
+        ]
+    );
+
+    // Written back as one list with explicit stores
+    let yaml = serde_yaml::to_string(&model)?;
+    assert!(!yaml.contains("giant_recurring"), "{yaml}");
+    let reloaded = HouseholdModel::from_yaml(&yaml)?;
+    assert_eq!(reloaded.recurring.len(), 4);
+    assert_eq!(reloaded.recurring[2].store, Store::Amazon);
+    assert_eq!(reloaded.recurring[2].cycle_days, Some(CycleDays::exactly(30)));
+    // A manual tier survives the move instead of resetting
+    assert_eq!(reloaded.recurring[2].tier, Some(FrequencyTier::Occasional));
+    assert_eq!(reloaded.recurring[1].tier, None);
+    Ok(())
+}
+
+#[test]
+fn test_tiers_are_per_store() -> TestResult {
+    let yaml = r#"
+family:
+  members: []
+stores: {}
+recurring:
+  - item: "Bananas"
+    category: produce
+    store: giant
+    frequency: "16/18"
+  - item: "Dark Chocolate Peanut Butter Cups"
+    category: snacks
+    store: trader_joes
+    frequency: "5/6"
+  - item: "Paper Towels"
+    category: household
+    store: amazon
+    tier: Occasional
+"#;
+    let model = HouseholdModel::from_yaml(yaml)?;
+    assert_eq!(model.recurring_stores(), vec![&Store::Giant, &Store::TraderJoes, &Store::Amazon]);
+
+    let tj = model.items_by_tier(&Store::TraderJoes, FrequencyTier::EveryOrder);
+    assert_eq!(tj.len(), 1);
+    assert_eq!(tj[0].item, "Dark Chocolate Peanut Butter Cups");
+    assert_eq!(model.giant_items_by_tier(FrequencyTier::EveryOrder).len(), 1);
+    assert_eq!(model.staples().len(), 2);
+
+    // A hand-set tier is used when there's no purchase history
+    assert_eq!(model.items_by_tier(&Store::Amazon, FrequencyTier::Occasional).len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_store_configs_for_any_store() -> TestResult {
+    let yaml = r#"
+family:
+  members: []
+stores:
+  giant:
+    type: grocery
+    layout:
+      order: [produce, dairy]
+  trader_joes:
+    type: grocery
+    layout:
+      order: [frozen]
+  H Mart:
+    type: grocery
+"#;
+    let model = HouseholdModel::from_yaml(yaml)?;
+    let giant = model.stores.layout(&Store::Giant).ok_or("missing Giant layout")?;
+    assert_eq!(giant.order, vec!["produce", "dairy"]);
+    let tj = model.stores.layout(&Store::TraderJoes).ok_or("missing Trader Joe's layout")?;
+    assert_eq!(tj.order, vec!["frozen"]);
+    assert!(model.stores.get(&Store::Other("H Mart".to_string())).is_some());
+    assert!(model.stores.layout(&Store::Other("H Mart".to_string())).is_none());
+    assert!(model.stores.get(&Store::Acme).is_none());
+    Ok(())
+}
+
+#[test]
+fn test_save_keeps_unmodeled_keys() -> TestResult {
+    let yaml = r#"
+last_reviewed: "2025-09-01"
+family:
+  members:
+    - name: "Nora"
+      age: 4
+      nickname: "Bug"
+  pediatrician: "Dr. Lee"
+stores:
+  giant:
+    type: grocery
+    loyalty_card: "1234"
+recurring:
+  - item: "Bananas"
+    category: produce
+    store: giant
+    frequency: "16/18"
+    brand: "Chiquita"
+"#;
+    let mut model = HouseholdModel::from_yaml(yaml)?;
+    model.recurring[0].frequency = Some("17/18".to_string());
+
+    let dir = std::env::temp_dir().join(format!("grocery-household-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join("household-model.yaml");
+    model.save(&path)?;
+    let saved = std::fs::read_to_string(&path)?;
+    std::fs::remove_dir_all(&dir)?;
+
+    for key in ["last_reviewed", "nickname: Bug", "pediatrician", "loyalty_card", "brand: Chiquita"] {
+        assert!(saved.contains(key), "lost {key}:\n{saved}");
+    }
+    let reloaded = HouseholdModel::from_yaml(&saved)?;
+    assert_eq!(reloaded.recurring[0].frequency.as_deref(), Some("17/18"));
+    assert_eq!(reloaded.extra, model.extra);
+    assert_eq!(reloaded.recurring[0].extra, model.recurring[0].extra);
+    assert_eq!(reloaded.family.members[0].extra, model.family.members[0].extra);
     Ok(())
 }
@@ -0,0 +1,442 @@
+use serde::{Deserialize, Serialize};
+
+use crate::quantity::{Quantity, Unit};
+
+/// A recipe ingredient line broken into quantity, unit, name and prep notes.
+///
+/// Produced by [`parse_ingredient`] from strings like
+/// "1 1/2 cups jasmine rice, rinsed" or "1 (14-oz.) can coconut milk".
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParsedIngredient {
+    /// The original, unparsed ingredient text.
+    pub raw: String,
+    /// Leading amount (lower bound for ranges like "2–3").
+    #[serde(default)]
+    pub quantity: Option<f64>,
+    /// Upper bound of a range, e.g. 3.0 for "2–3 cloves".
+    #[serde(default)]
+    pub quantity_max: Option<f64>,
+    /// Canonical unit name, e.g. "tbsp", "cup", "can".
+    #[serde(default)]
+    pub unit: Option<String>,
+    /// Ingredient name with quantity, unit and prep notes removed.
+    pub name: String,
+    /// Trailing preparation, e.g. "thinly sliced".
+    #[serde(default)]
+    pub preparation: Option<String>,
+    /// Parenthetical or size text, e.g. "14-oz." from "1 (14-oz.) can".
+    #[serde(default)]
+    pub note: Option<String>,
+    /// Whether the recipe marks this ingredient as optional.
+    #[serde(default)]
+    pub optional: bool,
+}
+
+impl ParsedIngredient {
+    /// The amount to shop for: the top of a range, or the single quantity.
+    pub fn shopping_quantity(&self) -> Option<f64> {
+        self.quantity_max.or(self.quantity)
+    }
+
+    /// The same ingredient with its amounts multiplied by `factor`, for
+    /// feeding more or fewer people than the recipe serves.
+    pub fn scaled(mut self, factor: f64) -> Self {
+        self.quantity = self.quantity.map(|q| q * factor);
+        self.quantity_max = self.quantity_max.map(|q| q * factor);
+        self
+    }
+
+    /// The shopping amount with its unit, or `None` when no quantity was given.
+    pub fn as_quantity(&self) -> Option<Quantity> {
+        let amount = self.shopping_quantity()?;
+        let unit = self.unit.as_deref().map_or(Unit::Each, Unit::parse);
+        Some(Quantity::new(amount, unit))
+    }
+}
+
+/// Known units and their spellings, longest spellings first within each unit.
+///
+/// Matched case-insensitively at a word boundary, with an optional trailing period.
+const UNITS: &[(&str, &[&str])] = &[
+    ("tbsp", &["tablespoons", "tablespoon", "tbsps", "tbsp", "tbs"]),
+    ("tsp", &["teaspoons", "teaspoon", "tsps", "tsp"]),
+    ("cup", &["cups", "cup"]),
+    ("fl oz", &["fluid ounces", "fluid ounce", "fl. oz", "fl oz"]),
+    ("oz", &["ounces", "ounce", "oz"]),
+    ("lb", &["pounds", "pound", "lbs", "lb"]),
+    ("kg", &["kilograms", "kilogram", "kg"]),
+    ("g", &["grams", "gram", "g"]),
+    ("ml", &["milliliters", "millilitres", "milliliter", "millilitre", "ml"]),
+    ("l", &["liters", "litres", "liter", "litre", "l"]),
+    ("quart", &["quarts", "quart", "qt"]),
+    ("pint", &["pints", "pint", "pt"]),
+    ("gallon", &["gallons", "gallon", "gal"]),
+    ("inch", &["inches", "inch", "\""]),
+    ("can", &["cans", "can"]),
+    ("jar", &["jars", "jar"]),
+    ("bottle", &["bottles", "bottle"]),
+    ("package", &["packages", "package", "pkg", "packs", "pack"]),
+    ("bag", &["bags", "bag"]),
+    ("box", &["boxes", "box"]),
+    ("block", &["blocks", "block"]),
+    ("stick", &["sticks", "stick"]),
+    ("bunch", &["bunches", "bunch"]),
+    ("clove", &["cloves", "clove"]),
+    ("head", &["heads", "head"]),
+    ("stalk", &["stalks", "stalk"]),
+    ("sprig", &["sprigs", "sprig"]),
+    ("slice", &["slices", "slice"]),
+    ("piece", &["pieces", "piece"]),
+    ("pinch", &["pinches", "pinch"]),
+    ("dash", &["dashes", "dash"]),
+    ("handful", &["handfuls", "handful"]),
+];
+
+/// Count units also recognised after the noun ("4 garlic cloves"). Units
+/// that commonly name the product itself ("fish sticks", "apple slices")
+/// are left out.
+const TRAILING_UNITS: &[&str] = &[
+    "can", "jar", "bottle", "package", "bag", "block", "bunch", "clove", "head", "stalk", "sprig",
+];
+
+/// Parse a free-form recipe ingredient line.
+///
+/// Handles whole numbers, decimals, fractions ("1/2"), mixed fractions
+/// ("1 1/2"), unicode fractions ("½", "1½"), ranges ("2–3", "2 to 3"),
+/// units after the noun ("4 garlic cloves"), parentheticals
+/// ("1 (14-oz.) can"), trailing prep after a comma
+/// ("thinly sliced") and "optional" markers.
+///
+/// Never fails: text that doesn't fit the pattern ends up in `name`.
+pub fn parse_ingredient(raw: &str) -> ParsedIngredient {
+    let trimmed = raw.trim();
+    let mut optional = false;
+    let mut notes: Vec<String> = Vec::new();
+
+    // 1. Pull out parentheticals: "(optional)" sets the flag, everything else is a note.
+    let (text, parentheticals) = extract_parentheticals(trimmed);
+    for inner in parentheticals {
+        if is_optional_marker(&inner) {
+            optional = true;
+        } else if !inner.is_empty() {
+            notes.push(inner);
+        }
+    }
+
+    // 2. Split trailing prep at the first comma.
+    let (head, mut preparation) = match text.split_once(',') {
+        Some((head, tail)) => {
+            let parts: Vec<&str> = tail
+                .split(',')
+                .map(str::trim)
+                .filter(|p| !p.is_empty())
+                .filter(|p| {
+                    if is_optional_marker(p) {
+                        optional = true;
+                        false
+                    } else {
+                        true
+                    }
+                })
+                .collect();
+            let prep = (!parts.is_empty()).then(|| parts.join(", "));
+            (head.trim().to_string(), prep)
+        }
+        None => (text.trim().to_string(), None),
+    };
+
+    // "salt and pepper to taste" has no comma but the same intent.
+    let mut head = head;
+    if preparation.is_none() {
+        let suffix = " to taste";
+        let cut = head.len().saturating_sub(suffix.len());
+        if head.is_char_boundary(cut) && head[cut..].eq_ignore_ascii_case(suffix) {
+            head.truncate(cut);
+            preparation = Some("to taste".to_string());
+        }
+    }
+
+    // 3. Leading quantity (possibly a range).
+    let mut rest = head.as_str();
+    let mut quantity = None;
+    let mut quantity_max = None;
+    if let Some((value, after)) = parse_number(rest) {
+        quantity = Some(value);
+        rest = after;
+        if let Some((upper, after)) = parse_range_upper(rest) {
+            quantity_max = Some(upper);
+            rest = after;
+        }
+    }
+
+    // 4. Unit, only meaningful after a quantity.
+    let mut unit = None;
+    if quantity.is_some() {
+        // Hyphenated sizes: "1-inch", "14-oz."
+        if quantity_max.is_none() {
+            rest = rest.strip_prefix('-').unwrap_or(rest);
+        }
+        rest = rest.trim_start();
+        // A size before a count unit is a note: `1 2" piece`, `1 14.5-oz can`.
+        if let Some((size, after)) = parse_number(rest) {
+            let after = after.strip_prefix('-').unwrap_or(after).trim_start();
+            if let Some((size_unit, after_size)) = match_unit(after) {
+                let after_size = after_size.trim_start();
+                if match_unit(after_size).is_some_and(|(u, _)| is_count_unit(u)) {
+                    notes.push(format!("{}-{}", format_number(size), size_unit));
+                    rest = after_size;
+                }
+            }
+        }
+        if let Some((canonical, after)) = match_unit(rest) {
+            let after = after.trim_start();
+            match match_unit(after) {
+                // "1-inch piece ginger": the first unit sizes the second.
+                Some((count, after_count)) if is_count_unit(count) && !is_count_unit(canonical) => {
+                    if let Some(q) = quantity {
+                        notes.push(format!("{}-{}", format_number(q), canonical));
+                    }
+                    unit = Some(count.to_string());
+                    rest = after_count.trim_start();
+                }
+                _ => {
+                    unit = Some(canonical.to_string());
+                    rest = after;
+                }
+            }
+            rest = strip_prefix_ignore_case(rest, "of ").unwrap_or(rest);
+        }
+    }
+
+    let mut name = collapse_whitespace(rest);
+    name = name
+        .trim_matches(|c: char| c == ',' || c == ';' || c == '-' || c.is_whitespace())
+        .to_string();
+    // 5. A count unit after the noun: "4 garlic cloves", "2 tomato cans".
+    if quantity.is_some() && unit.is_none() {
+        if let Some((noun, last)) = name.rsplit_once(' ') {
+            if let Some((canonical, "")) = match_unit(last).filter(|(u, _)| TRAILING_UNITS.contains(u)) {
+                unit = Some(canonical.to_string());
+                name = noun.trim_end().to_string();
+            }
+        }
+    }
+    if name.is_empty() {
+        name = collapse_whitespace(&head);
+    }
+
+    ParsedIngredient {
+        raw: trimmed.to_string(),
+        quantity,
+        quantity_max,
+        unit,
+        name,
+        preparation,
+        note: (!notes.is_empty()).then(|| notes.join("; ")),
+        optional,
+    }
+}
+
+/// Remove all top-level "(...)" groups, returning the remaining text and the group contents.
+fn extract_parentheticals(s: &str) -> (String, Vec<String>) {
+    let mut text = String::with_capacity(s.len());
+    let mut groups = Vec::new();
+    let mut depth = 0usize;
+    let mut current = String::new();
+
+    for c in s.chars() {
+        match c {
+            '(' => {
+                if depth > 0 {
+                    current.push(c);
+                }
+                depth += 1;
+            }
+            ')' if depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    groups.push(current.trim().to_string());
+                    current.clear();
+                    text.push(' ');
+                } else {
+                    current.push(c);
+                }
+            }
+            _ if depth > 0 => current.push(c),
+            _ => text.push(c),
+        }
+    }
+
+    // Unbalanced "(" — keep the text rather than silently dropping it.
+    if depth > 0 {
+        text.push('(');
+        text.push_str(&current);
+    }
+
+    (collapse_whitespace(&text), groups)
+}
+
+fn is_optional_marker(s: &str) -> bool {
+    let lower = s.trim().to_lowercase();
+    lower == "optional" || lower.starts_with("optional ") || lower.starts_with("optional:")
+}
+
+/// Value of a unicode vulgar fraction character.
+fn unicode_fraction(c: char) -> Option<f64> {
+    let value = match c {
+        '½' => 1.0 / 2.0,
+        '⅓' => 1.0 / 3.0,
+        '⅔' => 2.0 / 3.0,
+        '¼' => 1.0 / 4.0,
+        '¾' => 3.0 / 4.0,
+        '⅕' => 1.0 / 5.0,
+        '⅖' => 2.0 / 5.0,
+        '⅗' => 3.0 / 5.0,
+        '⅘' => 4.0 / 5.0,
+        '⅙' => 1.0 / 6.0,
+        '⅚' => 5.0 / 6.0,
+        '⅛' => 1.0 / 8.0,
+        '⅜' => 3.0 / 8.0,
+        '⅝' => 5.0 / 8.0,
+        '⅞' => 7.0 / 8.0,
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// Parse a run of ASCII digits with an optional decimal part.
+fn parse_decimal(s: &str) -> Option<(f64, &str)> {
+    let int_end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    if int_end == 0 {
+        return None;
+    }
+    let mut end = int_end;
+    let after = &s[int_end..];
+    if let Some(frac) = after.strip_prefix('.') {
+        let frac_len = frac.find(|c: char| !c.is_ascii_digit()).unwrap_or(frac.len());
+        if frac_len > 0 {
+            end = int_end + 1 + frac_len;
+        }
+    }
+    s[..end].parse().ok().map(|v| (v, &s[end..]))
+}
+
+/// Parse "N/D" (ASCII slash or fraction slash) at the start of `s`.
+fn parse_slash_fraction(s: &str) -> Option<(f64, &str)> {
+    let (numerator, after) = parse_decimal(s)?;
+    let after = after
+        .strip_prefix('/')
+        .or_else(|| after.strip_prefix('⁄'))?;
+    let (denominator, after) = parse_decimal(after)?;
+    if denominator == 0.0 {
+        return None;
+    }
+    Some((numerator / denominator, after))
+}
+
+/// Parse a leading number: "2", "1.5", "1/2", "1 1/2", "½", "1½", "1 ½".
+fn parse_number(s: &str) -> Option<(f64, &str)> {
+    let s = s.trim_start();
+
+    if let Some(first) = s.chars().next() {
+        if let Some(value) = unicode_fraction(first) {
+            return Some((value, &s[first.len_utf8()..]));
+        }
+    }
+
+    if let Some(result) = parse_slash_fraction(s) {
+        return Some(result);
+    }
+
+    let (whole, after) = parse_decimal(s)?;
+
+    // "1½"
+    if let Some(c) = after.chars().next() {
+        if let Some(frac) = unicode_fraction(c) {
+            return Some((whole + frac, &after[c.len_utf8()..]));
+        }
+    }
+
+    // "1 1/2" or "1 ½"
+    let spaced = after.trim_start();
+    if spaced.len() < after.len() {
+        if let Some(c) = spaced.chars().next() {
+            if let Some(frac) = unicode_fraction(c) {
+                return Some((whole + frac, &spaced[c.len_utf8()..]));
+            }
+        }
+        if let Some((frac, rest)) = parse_slash_fraction(spaced) {
+            if frac < 1.0 {
+                return Some((whole + frac, rest));
+            }
+        }
+    }
+
+    Some((whole, after))
+}
+
+/// Parse the upper bound of a range: "-3", "–3", "— 3", " to 3".
+fn parse_range_upper(s: &str) -> Option<(f64, &str)> {
+    let trimmed = s.trim_start();
+    let after = trimmed
+        .strip_prefix('-')
+        .or_else(|| trimmed.strip_prefix('–'))
+        .or_else(|| trimmed.strip_prefix('—'))
+        .or_else(|| strip_prefix_ignore_case(trimmed, "to "))?;
+    parse_number(after)
+}
+
+/// Match a unit at the start of `s`, returning its canonical name and the remaining text.
+fn match_unit(s: &str) -> Option<(&'static str, &str)> {
+    let lower = s.to_lowercase();
+    for (canonical, spellings) in UNITS {
+        for spelling in *spellings {
+            if !lower.starts_with(spelling) || !s.is_char_boundary(spelling.len()) {
+                continue;
+            }
+            let after = &s[spelling.len()..];
+            let boundary = after.is_empty()
+                || after.starts_with(|c: char| c.is_whitespace() || c == '.' || c == ',')
+                || *spelling == "\"";
+            if boundary {
+                let after = after.strip_prefix('.').unwrap_or(after);
+                return Some((canonical, after));
+            }
+        }
+    }
+    None
+}
+
+/// Units that count discrete things (as opposed to measuring volume, mass or length).
+fn is_count_unit(unit: &str) -> bool {
+    matches!(
+        unit,
+        "can" | "jar" | "bottle" | "package" | "bag" | "box" | "block" | "stick"
+            | "bunch" | "clove" | "head" | "stalk" | "sprig" | "slice" | "piece"
+    )
+}
+
+fn strip_prefix_ignore_case<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = s.get(..prefix.len())?;
+    head.eq_ignore_ascii_case(prefix).then(|| &s[prefix.len()..])
+}
+
+fn collapse_whitespace(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Format a quantity without a trailing ".0" for whole numbers.
+pub fn format_number(value: f64) -> String {
+    // Round to the two decimals shown first, so 2.996 prints as "3"
+    // (adding 0.0 turns -0.0 into 0.0)
+    let rounded = (value * 100.0).round() / 100.0 + 0.0;
+    if rounded.fract() == 0.0 {
+        format!("{rounded}")
+    } else {
+        format!("{rounded:.2}").trim_end_matches('0').to_string()
+    }
+}
+
+#[cfg(test)]
+#[path = "ingredient_tests.rs"]
+mod tests;
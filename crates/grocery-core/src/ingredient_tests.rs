@@ -0,0 +1,180 @@
+use super::*;
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+fn approx(actual: Option<f64>, expected: f64) -> bool {
+    actual.is_some_and(|v| (v - expected).abs() < 1e-9)
+}
+
+#[test]
+fn test_parse_simple_quantity_and_unit() -> TestResult {
+    let p = parse_ingredient("2 Tbsp. extra-virgin olive oil");
+    assert!(approx(p.quantity, 2.0));
+    assert_eq!(p.unit.as_deref(), Some("tbsp"));
+    assert_eq!(p.name, "extra-virgin olive oil");
+    assert_eq!(p.preparation, None);
+    assert!(!p.optional);
+    Ok(())
+}
+
+#[test]
+fn test_parse_mixed_fraction() -> TestResult {
+    let p = parse_ingredient("1 1/2 cups jasmine rice");
+    assert!(approx(p.quantity, 1.5));
+    assert_eq!(p.unit.as_deref(), Some("cup"));
+    assert_eq!(p.name, "jasmine rice");
+    Ok(())
+}
+
+#[test]
+fn test_parse_unicode_fractions() -> TestResult {
+    let p = parse_ingredient("½ cup coconut milk");
+    assert!(approx(p.quantity, 0.5));
+    assert_eq!(p.name, "coconut milk");
+
+    let p = parse_ingredient("1½ tsp. kosher salt");
+    assert!(approx(p.quantity, 1.5));
+    assert_eq!(p.unit.as_deref(), Some("tsp"));
+
+    let p = parse_ingredient("1 ¼ cups water");
+    assert!(approx(p.quantity, 1.25));
+    Ok(())
+}
+
+#[test]
+fn test_parse_ranges() -> TestResult {
+    let p = parse_ingredient("2–3 cloves garlic");
+    assert!(approx(p.quantity, 2.0));
+    assert!(approx(p.quantity_max, 3.0));
+    assert_eq!(p.unit.as_deref(), Some("clove"));
+    assert_eq!(p.name, "garlic");
+    assert!(approx(p.shopping_quantity(), 3.0));
+    assert_eq!(
+        p.as_quantity(),
+        Some(Quantity::new(3.0, Unit::Piece("clove".to_string())))
+    );
+
+    let p = parse_ingredient("2 to 3 limes");
+    assert!(approx(p.quantity_max, 3.0));
+    assert_eq!(p.name, "limes");
+
+    let p = parse_ingredient("1-2 Tbsp sambal oelek");
+    assert!(approx(p.quantity, 1.0));
+    assert!(approx(p.quantity_max, 2.0));
+    assert_eq!(p.name, "sambal oelek");
+    Ok(())
+}
+
+#[test]
+fn test_parse_parenthetical_package_size() -> TestResult {
+    let p = parse_ingredient("1 (14-oz.) can unsweetened coconut milk");
+    assert!(approx(p.quantity, 1.0));
+    assert_eq!(p.unit.as_deref(), Some("can"));
+    assert_eq!(p.name, "unsweetened coconut milk");
+    assert_eq!(p.note.as_deref(), Some("14-oz."));
+    Ok(())
+}
+
+#[test]
+fn test_parse_trailing_preparation() -> TestResult {
+    let p = parse_ingredient("8 garlic cloves, thinly sliced");
+    assert!(approx(p.quantity, 8.0));
+    assert_eq!(p.unit.as_deref(), Some("clove"));
+    assert_eq!(p.name, "garlic");
+    assert_eq!(p.preparation.as_deref(), Some("thinly sliced"));
+
+    let p = parse_ingredient("Kosher salt and pepper to taste");
+    assert_eq!(p.quantity, None);
+    assert_eq!(p.name, "Kosher salt and pepper");
+    assert_eq!(p.preparation.as_deref(), Some("to taste"));
+    Ok(())
+}
+
+#[test]
+fn test_parse_unit_after_the_noun() -> TestResult {
+    for line in ["4 garlic cloves", "4 cloves garlic", "4 cloves of garlic"] {
+        let p = parse_ingredient(line);
+        assert!(approx(p.quantity, 4.0), "{line}");
+        assert_eq!(p.unit.as_deref(), Some("clove"), "{line}");
+        assert_eq!(p.name, "garlic", "{line}");
+    }
+    let p = parse_ingredient("2 thyme sprigs");
+    assert_eq!((p.unit.as_deref(), p.name.as_str()), (Some("sprig"), "thyme"));
+
+    // Only count units, and only after an amount
+    assert_eq!(parse_ingredient("3 fish sticks").unit, None);
+    assert_eq!(parse_ingredient("2 cups chicken stock").name, "chicken stock");
+    assert_eq!(parse_ingredient("garlic cloves").name, "garlic cloves");
+    Ok(())
+}
+
+#[test]
+fn test_parse_optional_markers() -> TestResult {
+    let p = parse_ingredient("1 tsp. fish sauce (optional)");
+    assert!(p.optional);
+    assert_eq!(p.name, "fish sauce");
+    assert_eq!(p.note, None);
+
+    let p = parse_ingredient("Toasted sesame seeds, for serving, optional");
+    assert!(p.optional);
+    assert_eq!(p.preparation.as_deref(), Some("for serving"));
+    Ok(())
+}
+
+#[test]
+fn test_parse_size_before_count_unit() -> TestResult {
+    let p = parse_ingredient("1 2\" piece fresh ginger");
+    assert!(approx(p.quantity, 1.0));
+    assert_eq!(p.unit.as_deref(), Some("piece"));
+    assert_eq!(p.name, "fresh ginger");
+    assert_eq!(p.note.as_deref(), Some("2-inch"));
+
+    let p = parse_ingredient("1-inch piece ginger, peeled");
+    assert_eq!(p.unit.as_deref(), Some("piece"));
+    assert_eq!(p.name, "ginger");
+    assert_eq!(p.note.as_deref(), Some("1-inch"));
+
+    let p = parse_ingredient("1 14.5-oz can diced tomatoes");
+    assert_eq!(p.unit.as_deref(), Some("can"));
+    assert_eq!(p.name, "diced tomatoes");
+    assert_eq!(p.note.as_deref(), Some("14.5-oz"));
+    Ok(())
+}
+
+#[test]
+fn test_parse_no_quantity() -> TestResult {
+    let p = parse_ingredient("fresh cilantro");
+    assert_eq!(p.quantity, None);
+    assert_eq!(p.unit, None);
+    assert_eq!(p.name, "fresh cilantro");
+    Ok(())
+}
+
+#[test]
+fn test_parse_unit_requires_word_boundary() -> TestResult {
+    // "g" must not be stripped from "garlic", nor "l" from "lemon".
+    assert_eq!(parse_ingredient("2 garlic bulbs").name, "garlic bulbs");
+    assert_eq!(parse_ingredient("1 lemon").name, "lemon");
+    assert_eq!(parse_ingredient("1 lemon").unit, None);
+    Ok(())
+}
+
+#[test]
+fn test_parse_empty_string() -> TestResult {
+    let p = parse_ingredient("   ");
+    assert_eq!(p.name, "");
+    assert_eq!(p.quantity, None);
+    Ok(())
+}
+
+#[test]
+fn test_format_number() -> TestResult {
+    assert_eq!(format_number(2.0), "2");
+    assert_eq!(format_number(0.5), "0.5");
+    assert_eq!(format_number(1.25), "1.25");
+    assert_eq!(format_number(2.996), "3");
+    assert_eq!(format_number(1.004), "1");
+    assert_eq!(format_number(0.333), "0.33");
+    assert_eq!(format_number(-0.001), "0");
+    Ok(())
+}
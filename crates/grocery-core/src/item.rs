@@ -1,5 +1,11 @@
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
+use serde_yaml::Mapping;
 
+use crate::dates::CycleDays;
+use crate::household::parse_frequency_tier;
+use crate::package::PackagePlan;
+use crate::quantity::Quantity;
 use crate::types::{Category, FrequencyTier, Store};
 
 /// A recurring grocery item from the household model.
@@ -8,32 +14,70 @@ pub struct RecurringItem {
     pub item: String,
     pub category: Category,
     pub store: Store,
-    pub tier: FrequencyTier,
-    #[serde(default)]
+    /// Manual tier for items without order history; `frequency` and
+    /// `propensity` take precedence.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tier: Option<FrequencyTier>,
+    /// Appearances in this store's orders, e.g. "14/18".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub frequency: Option<String>,
-    #[serde(default)]
+    /// Time-decayed purchase propensity (0.0–1.0) written by `model refresh`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub propensity: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub typical_qty: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub price: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub oos_count: Option<u32>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub note: Option<String>,
-    #[serde(default)]
-    pub cycle_days: Option<String>,
-    #[serde(default)]
-    pub last_seen: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "crate::household::cycle_days",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub cycle_days: Option<CycleDays>,
+    #[serde(
+        default,
+        deserialize_with = "crate::household::last_seen",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_seen: Option<NaiveDate>,
+    /// Unmodeled keys, kept on save.
+    #[serde(flatten)]
+    pub extra: Mapping,
+}
+
+impl RecurringItem {
+    /// Tier within this item's store: from the decayed propensity if
+    /// refreshed, then a frequency string like "14/18", then the manual tier.
+    pub fn tier(&self) -> FrequencyTier {
+        if let Some(propensity) = self.propensity {
+            return FrequencyTier::from_ratio(propensity);
+        }
+        if let Some(freq) = &self.frequency {
+            return parse_frequency_tier(freq);
+        }
+        self.tier.unwrap_or(FrequencyTier::Rare)
+    }
 }
 
 /// An item on a shopping list (generated, not from the model).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ShoppingItem {
     pub name: String,
-    pub quantity: u32,
+    pub quantity: Quantity,
     pub category: Category,
     pub source: ItemSource,
     #[serde(default)]
     pub note: Option<String>,
+    /// Whole packages to buy, when the store's package size is known.
+    #[serde(default)]
+    pub packages: Option<PackagePlan>,
+    /// Where to buy it; `None` until the list is routed to stores.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub store: Option<Store>,
 }
 
 /// Where a shopping list item came from.
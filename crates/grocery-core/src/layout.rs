@@ -0,0 +1,76 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::Category;
+
+/// How a store is laid out, from a store's `layout` in household-model.yaml.
+///
+/// ```yaml
+/// stores:
+///   giant:
+///     layout:
+///       order: [produce, deli, bread, meat, Aisle 7 International, dairy, frozen]
+///       items:
+///         Gochujang: Aisle 7 International
+///         Tortillas: deli
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StoreLayout {
+    /// Sections in walking order: category names or aisle names.
+    #[serde(default)]
+    pub order: Vec<String>,
+    /// Items shelved away from their category, item name → section.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub items: BTreeMap<String, String>,
+}
+
+impl StoreLayout {
+    /// The section an item is shelved in: its override if it has one,
+    /// otherwise its category. An override naming a category ("deli") puts
+    /// the item in that category's section.
+    pub fn section_for(&self, item: &str, category: &Category) -> Section {
+        let Some((_, aisle)) = self
+            .items
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(item))
+        else {
+            return Section::Category(category.clone());
+        };
+        match Category::from_name(aisle) {
+            known if known.is_known() => Section::Category(known),
+            _ => Section::Aisle(aisle.trim().to_string()),
+        }
+    }
+
+    /// Walking-order position of a section, or `None` if the layout
+    /// doesn't list it. Names match case-insensitively.
+    pub fn position(&self, section: &Section) -> Option<usize> {
+        let name = section.key();
+        self.order
+            .iter()
+            .position(|entry| entry.trim().eq_ignore_ascii_case(name))
+    }
+}
+
+/// Where an item sits in a store.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Section {
+    Category(Category),
+    /// A named aisle from a per-item override.
+    Aisle(String),
+}
+
+impl Section {
+    /// The name layouts refer to this section by.
+    pub fn key(&self) -> &str {
+        match self {
+            Self::Category(category) => category.as_str(),
+            Self::Aisle(name) => name,
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "layout_tests.rs"]
+mod tests;
@@ -0,0 +1,42 @@
+use super::*;
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+fn giant() -> Result<StoreLayout, Box<dyn std::error::Error>> {
+    let yaml = r#"
+order: [produce, Deli, bread, meat, Aisle 7 International, dairy]
+items:
+  Gochujang: Aisle 7 International
+  tortillas: deli
+"#;
+    Ok(serde_yaml::from_str(yaml)?)
+}
+
+#[test]
+fn test_item_overrides_beat_category() -> TestResult {
+    let layout = giant()?;
+    assert_eq!(
+        layout.section_for("gochujang", &Category::Condiments),
+        Section::Aisle("Aisle 7 International".to_string())
+    );
+    assert_eq!(
+        layout.section_for("Tortillas", &Category::Bread),
+        Section::Category(Category::Deli)
+    );
+    assert_eq!(
+        layout.section_for("Bananas", &Category::Produce),
+        Section::Category(Category::Produce)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_positions_follow_walking_order() -> TestResult {
+    let layout = giant()?;
+    assert_eq!(layout.position(&Section::Category(Category::Produce)), Some(0));
+    assert_eq!(layout.position(&Section::Category(Category::Deli)), Some(1));
+    assert_eq!(layout.position(&Section::Aisle("deli".to_string())), Some(1));
+    assert_eq!(layout.position(&Section::Aisle("aisle 7 international".to_string())), Some(4));
+    assert_eq!(layout.position(&Section::Category(Category::Frozen)), None);
+    Ok(())
+}
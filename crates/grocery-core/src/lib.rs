@@ -1,6 +1,22 @@
+pub mod atomic;
+pub mod categorize;
+pub mod check;
+pub mod cooking;
+pub mod dates;
+pub mod diet;
+pub mod diagnostics;
 pub mod error;
+pub mod frequency;
 pub mod household;
+pub mod ingredient;
 pub mod item;
+pub mod layout;
+pub mod order;
+pub mod package;
+pub mod quantity;
 pub mod recipe;
+pub mod replenish;
+pub mod routing;
 pub mod scoring;
+pub mod stage;
 pub mod types;
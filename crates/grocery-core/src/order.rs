@@ -0,0 +1,338 @@
+use std::fmt;
+use std::path::Path;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::error::GroceryResult;
+use crate::types::{Category, Store};
+
+/// A past grocery order (one "## Order #..." block in grocery-orders-history.md).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Order {
+    pub id: String,
+    pub date: NaiveDate,
+    pub store: Store,
+    /// Order total in dollars.
+    #[serde(default)]
+    pub total: Option<f64>,
+    #[serde(default)]
+    pub delivered_count: Option<u32>,
+    #[serde(default)]
+    pub out_of_stock_count: Option<u32>,
+    #[serde(default)]
+    pub lines: Vec<OrderLine>,
+}
+
+impl Order {
+    /// Lines that were actually delivered (excludes out-of-stock items).
+    pub fn delivered(&self) -> impl Iterator<Item = &OrderLine> {
+        self.lines.iter().filter(|l| !l.out_of_stock)
+    }
+}
+
+/// One product line in an order: `- **Brand** Product - qty`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrderLine {
+    #[serde(default)]
+    pub brand: Option<String>,
+    pub product: String,
+    pub quantity: u32,
+    /// Section heading the line appeared under, e.g. "Dairy & Eggs".
+    pub section: String,
+    pub category: Category,
+    #[serde(default)]
+    pub out_of_stock: bool,
+}
+
+impl OrderLine {
+    /// Brand and product as one display name, e.g. "Daisy Sour Cream".
+    pub fn full_name(&self) -> String {
+        match &self.brand {
+            Some(brand) => format!("{brand} {}", self.product),
+            None => self.product.clone(),
+        }
+    }
+
+    /// Whether this line is a purchase of a household-model item name.
+    ///
+    /// Every word of `item` must appear in the brand + product name, ignoring
+    /// case, punctuation and plural "s" ("Limes" matches "Lime").
+    pub fn matches_item(&self, item: &str) -> bool {
+        let wanted = name_tokens(item);
+        if wanted.is_empty() {
+            return false;
+        }
+        let have = name_tokens(&self.full_name());
+        wanted.iter().all(|w| have.contains(w))
+    }
+}
+
+/// Lowercased alphanumeric words with a trailing plural "s" dropped.
+fn name_tokens(name: &str) -> Vec<String> {
+    name.split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(|t| {
+            let lower = t.to_lowercase();
+            match lower.strip_suffix('s') {
+                Some(stem) if stem.len() >= 3 => stem.to_string(),
+                _ => lower,
+            }
+        })
+        .collect()
+}
+
+/// All imported orders, persisted as JSON so other commands can query them.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OrderHistory {
+    pub orders: Vec<Order>,
+}
+
+impl OrderHistory {
+    /// Load from a JSON file path.
+    pub fn from_file(path: &Path) -> GroceryResult<Self> {
+        let content = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// Write as pretty-printed JSON.
+    pub fn save(&self, path: &Path) -> GroceryResult<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json)?;
+        Ok(())
+    }
+
+    /// Add or replace orders by ID, keeping newest first.
+    ///
+    /// Returns how many orders were new.
+    pub fn merge(&mut self, orders: Vec<Order>) -> usize {
+        let mut added = 0;
+        for order in orders {
+            if let Some(existing) = self.orders.iter_mut().find(|o| o.id == order.id) {
+                *existing = order;
+            } else {
+                self.orders.push(order);
+                added += 1;
+            }
+        }
+        self.orders.sort_by(|a, b| b.date.cmp(&a.date).then(a.id.cmp(&b.id)));
+        added
+    }
+
+    /// Orders from one store, newest first.
+    pub fn for_store(&self, store: &Store) -> Vec<&Order> {
+        self.orders.iter().filter(|o| &o.store == store).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.orders.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.orders.is_empty()
+    }
+}
+
+/// A problem on a specific line of the order history markdown.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineError {
+    /// 1-based line number.
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for LineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+/// Result of parsing an order history file: everything that parsed, plus per-line errors.
+#[derive(Debug, Clone, Default)]
+pub struct ParsedOrderHistory {
+    pub orders: Vec<Order>,
+    pub errors: Vec<LineError>,
+}
+
+/// Parse the grocery-orders-history.md format.
+///
+/// Recognizes `## Order #<id> - <Month D, YYYY>` headers, a `**Total:**` line,
+/// `### <Section>` headings and `- **Brand** Product - qty` item lines.
+/// Headings that aren't orders (e.g. "Order Totals Summary") end the current
+/// order and their content is ignored. Malformed lines are reported and skipped.
+pub fn parse_order_history(markdown: &str, store: &Store) -> ParsedOrderHistory {
+    let mut result = ParsedOrderHistory::default();
+    let mut current: Option<Order> = None;
+    let mut section: Option<String> = None;
+
+    for (index, raw_line) in markdown.lines().enumerate() {
+        let line_no = index + 1;
+        let line = raw_line.trim();
+
+        if let Some(heading) = line.strip_prefix("## ") {
+            if let Some(order) = current.take() {
+                result.orders.push(order);
+            }
+            section = None;
+            if heading.starts_with("Order #") {
+                match parse_order_header(heading, store) {
+                    Ok(order) => current = Some(order),
+                    Err(message) => result.errors.push(LineError { line: line_no, message }),
+                }
+            }
+            continue;
+        }
+
+        let Some(order) = current.as_mut() else {
+            continue;
+        };
+
+        if let Some(heading) = line.strip_prefix("### ") {
+            section = Some(heading.trim().to_string());
+        } else if let Some(rest) = line.strip_prefix("**Total:**") {
+            match parse_total(rest) {
+                Ok((total, delivered, oos)) => {
+                    order.total = Some(total);
+                    order.delivered_count = delivered;
+                    order.out_of_stock_count = oos;
+                }
+                Err(message) => result.errors.push(LineError { line: line_no, message }),
+            }
+        } else if let Some(item) = line.strip_prefix("- ") {
+            let Some(section_name) = section.as_deref() else {
+                result.errors.push(LineError {
+                    line: line_no,
+                    message: "item line before any ### section heading".to_string(),
+                });
+                continue;
+            };
+            match parse_order_line(item, section_name) {
+                Ok(order_line) => order.lines.push(order_line),
+                Err(message) => result.errors.push(LineError { line: line_no, message }),
+            }
+        }
+    }
+
+    if let Some(order) = current.take() {
+        result.orders.push(order);
+    }
+
+    result
+}
+
+/// Parse "Order #m704241626 - August 28, 2025".
+fn parse_order_header(heading: &str, store: &Store) -> Result<Order, String> {
+    let rest = heading.trim_start_matches("Order #");
+    let (id, date) = rest
+        .split_once(" - ")
+        .ok_or_else(|| format!("order header missing \" - <date>\": {heading:?}"))?;
+    let id = id.trim();
+    if id.is_empty() {
+        return Err("order header has an empty order ID".to_string());
+    }
+    let date = NaiveDate::parse_from_str(date.trim(), "%B %d, %Y")
+        .map_err(|e| format!("invalid order date {:?}: {e}", date.trim()))?;
+    Ok(Order {
+        id: id.to_string(),
+        date,
+        store: store.clone(),
+        total: None,
+        delivered_count: None,
+        out_of_stock_count: None,
+        lines: Vec::new(),
+    })
+}
+
+/// Parse " $242.74 (45 delivered, 3 out of stock)".
+fn parse_total(rest: &str) -> Result<(f64, Option<u32>, Option<u32>), String> {
+    let rest = rest.trim();
+    let (amount, counts) = match rest.split_once('(') {
+        Some((amount, counts)) => (amount.trim(), Some(counts.trim_end_matches(')'))),
+        None => (rest, None),
+    };
+    let total = amount
+        .trim_start_matches('$')
+        .replace(',', "")
+        .parse::<f64>()
+        .map_err(|_| format!("invalid order total {amount:?}"))?;
+
+    let mut delivered = None;
+    let mut oos = None;
+    for part in counts.into_iter().flat_map(|c| c.split(',')) {
+        let part = part.trim();
+        let (number, label) = part.split_once(' ').unwrap_or((part, ""));
+        let Ok(n) = number.parse::<u32>() else {
+            continue;
+        };
+        if label.starts_with("delivered") {
+            delivered = Some(n);
+        } else if label.starts_with("out of stock") {
+            oos = Some(n);
+        }
+    }
+    Ok((total, delivered, oos))
+}
+
+/// Parse "**Brand** Product Name - 2" (the brand is optional).
+fn parse_order_line(item: &str, section: &str) -> Result<OrderLine, String> {
+    let (name, qty) = item
+        .rsplit_once(" - ")
+        .ok_or_else(|| format!("item line missing \" - <qty>\": {item:?}"))?;
+    let quantity = qty
+        .trim()
+        .parse::<u32>()
+        .map_err(|_| format!("invalid quantity {:?} for {:?}", qty.trim(), name.trim()))?;
+
+    let name = name.trim();
+    let (brand, product) = match name.strip_prefix("**") {
+        Some(after) => {
+            let (brand, product) = after
+                .split_once("**")
+                .ok_or_else(|| format!("unclosed ** around brand: {name:?}"))?;
+            (Some(brand.trim().to_string()), product.trim().to_string())
+        }
+        None => (None, name.to_string()),
+    };
+    if product.is_empty() {
+        return Err(format!("item line has no product name: {item:?}"));
+    }
+
+    let out_of_stock = section.eq_ignore_ascii_case("Out of Stock Items");
+    Ok(OrderLine {
+        brand,
+        product,
+        quantity,
+        section: section.to_string(),
+        category: section_category(section),
+        out_of_stock,
+    })
+}
+
+/// Map a Giant order section heading to a product category.
+pub fn section_category(section: &str) -> Category {
+    match section.trim().to_lowercase().as_str() {
+        "produce" => Category::Produce,
+        "meat" | "seafood" | "meat & seafood" => Category::Meat,
+        "deli & prepared food" | "deli" => Category::Deli,
+        "dairy & eggs" | "dairy" => Category::Dairy,
+        "frozen" => Category::Frozen,
+        "beverages" => Category::Beverages,
+        "bread & bakery" | "bread" => Category::Bread,
+        "breakfast" => Category::Breakfast,
+        "snacks" | "candy & chocolate" => Category::Snacks,
+        "rice, pasta & beans" | "pasta" => Category::Pasta,
+        "soups & canned goods" | "canned" => Category::Canned,
+        "condiments & sauces" | "condiments" => Category::Condiments,
+        "baking & cooking" | "baking" => Category::Baking,
+        "baby" => Category::Baby,
+        "laundry, paper & cleaning" | "household" => Category::Household,
+        "health & beauty" | "health" => Category::Health,
+        "personal care" => Category::Personal,
+        "pet" | "pet supplies" => Category::Pet,
+        _ => Category::Other(section.trim().to_string()),
+    }
+}
+
+#[cfg(test)]
+#[path = "order_tests.rs"]
+mod tests;
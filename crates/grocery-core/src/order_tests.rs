@@ -0,0 +1,182 @@
+use super::*;
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+const SAMPLE: &str = r#"# Giant Food Stores Order History
+
+## Order Summary
+Last 2 orders
+
+---
+
+## Order #m704241626 - August 28, 2025
+**Total:** $242.74 (45 delivered, 3 out of stock)
+
+### Out of Stock Items
+- **Healthy Choice** Power Bowls Frozen Meal - 0
+
+### Produce
+- Seedless Cucumbers - 3 ct - 1
+- **Dole** American Salad Blend - 2
+
+### Dairy & Eggs
+- **Icelandic Provisions** Vanilla Skyr Yogurt Cup - 4
+
+---
+
+## Order #m698870041 - August 4, 2025
+**Total:** $1,164.74
+
+### Produce
+- Limes - 1
+
+---
+
+## Order Totals Summary
+- **August 28, 2025:** $242.74
+"#;
+
+#[test]
+fn test_parse_orders_and_totals() -> TestResult {
+    let parsed = parse_order_history(SAMPLE, &Store::Giant);
+    assert!(parsed.errors.is_empty(), "unexpected errors: {:?}", parsed.errors);
+    assert_eq!(parsed.orders.len(), 2);
+
+    let first = &parsed.orders[0];
+    assert_eq!(first.id, "m704241626");
+    assert_eq!(first.date, NaiveDate::from_ymd_opt(2025, 8, 28).ok_or("bad date")?);
+    assert_eq!(first.store, Store::Giant);
+    assert_eq!(first.total, Some(242.74));
+    assert_eq!(first.delivered_count, Some(45));
+    assert_eq!(first.out_of_stock_count, Some(3));
+    assert_eq!(first.lines.len(), 4);
+
+    let second = &parsed.orders[1];
+    assert_eq!(second.total, Some(1164.74));
+    assert_eq!(second.delivered_count, None);
+    Ok(())
+}
+
+#[test]
+fn test_parse_order_lines() -> TestResult {
+    let parsed = parse_order_history(SAMPLE, &Store::Giant);
+    let lines = &parsed.orders[0].lines;
+
+    let oos = &lines[0];
+    assert!(oos.out_of_stock);
+    assert_eq!(oos.brand.as_deref(), Some("Healthy Choice"));
+    assert_eq!(oos.quantity, 0);
+
+    // Product names may themselves contain " - "
+    let cucumbers = &lines[1];
+    assert_eq!(cucumbers.brand, None);
+    assert_eq!(cucumbers.product, "Seedless Cucumbers - 3 ct");
+    assert_eq!(cucumbers.quantity, 1);
+    assert_eq!(cucumbers.category, Category::Produce);
+
+    let yogurt = &lines[3];
+    assert_eq!(yogurt.full_name(), "Icelandic Provisions Vanilla Skyr Yogurt Cup");
+    assert_eq!(yogurt.section, "Dairy & Eggs");
+    assert_eq!(yogurt.category, Category::Dairy);
+
+    assert_eq!(parsed.orders[0].delivered().count(), 3);
+    Ok(())
+}
+
+#[test]
+fn test_order_line_matches_item() -> TestResult {
+    let parsed = parse_order_history(SAMPLE, &Store::Giant);
+    let lines = &parsed.orders[0].lines;
+    assert!(lines[1].matches_item("Seedless Cucumber"));
+    assert!(lines[2].matches_item("dole american salad"));
+    assert!(!lines[2].matches_item("Caesar Salad Kit"));
+    assert!(!lines[2].matches_item(""));
+    Ok(())
+}
+
+#[test]
+fn test_summary_sections_are_ignored() -> TestResult {
+    let parsed = parse_order_history(SAMPLE, &Store::Giant);
+    // "Order Totals Summary" bullets must not be attached to the last order
+    assert_eq!(parsed.orders[1].lines.len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_parse_reports_line_errors() -> TestResult {
+    let md = "\
+## Order #m1 - Augst 28, 2025
+### Produce
+- Limes - 1
+
+## Order #m2 - May 12, 2025
+**Total:** about $20
+- Orphan Item - 1
+### Produce
+- Limes - many
+- **Dole Salad Blend - 1
+- No quantity here
+- Cilantro Fresh - 2
+";
+    let parsed = parse_order_history(md, &Store::Giant);
+
+    let lines: Vec<usize> = parsed.errors.iter().map(|e| e.line).collect();
+    assert_eq!(lines, vec![1, 6, 7, 9, 10, 11]);
+    assert!(parsed.errors[0].to_string().starts_with("line 1: invalid order date"));
+
+    // The valid order and its good line still come through
+    assert_eq!(parsed.orders.len(), 1);
+    assert_eq!(parsed.orders[0].id, "m2");
+    assert_eq!(parsed.orders[0].lines.len(), 1);
+    assert_eq!(parsed.orders[0].lines[0].product, "Cilantro Fresh");
+    Ok(())
+}
+
+#[test]
+fn test_history_merge_replaces_by_id_and_sorts() -> TestResult {
+    let parsed = parse_order_history(SAMPLE, &Store::Giant);
+    let mut history = OrderHistory::default();
+
+    let mut reversed = parsed.orders.clone();
+    reversed.reverse();
+    assert_eq!(history.merge(reversed), 2);
+    assert_eq!(history.orders[0].id, "m704241626");
+
+    // Re-importing the same orders adds nothing
+    assert_eq!(history.merge(parsed.orders), 0);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.for_store(&Store::Giant).len(), 2);
+    assert!(history.for_store(&Store::Amazon).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_history_json_roundtrip() -> TestResult {
+    let parsed = parse_order_history(SAMPLE, &Store::Giant);
+    let history = OrderHistory { orders: parsed.orders };
+    let json = serde_json::to_string(&history)?;
+    let loaded: OrderHistory = serde_json::from_str(&json)?;
+    assert_eq!(loaded.orders, history.orders);
+    Ok(())
+}
+
+#[test]
+fn test_parse_real_order_history() -> TestResult {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .and_then(|p| p.parent())
+        .map(|p| p.join("grocery-orders-history.md"))
+        .ok_or("could not resolve grocery-orders-history.md path")?;
+
+    if !path.exists() {
+        // Skip if running outside the workspace root
+        return Ok(());
+    }
+
+    let markdown = std::fs::read_to_string(&path)?;
+    let parsed = parse_order_history(&markdown, &Store::Giant);
+    assert!(parsed.errors.is_empty(), "unexpected errors: {:?}", parsed.errors);
+    assert_eq!(parsed.orders.len(), 6);
+    assert!(parsed.orders.iter().all(|o| !o.lines.is_empty()));
+    Ok(())
+}
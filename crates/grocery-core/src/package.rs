@@ -0,0 +1,73 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::quantity::Quantity;
+
+/// How a store sells an item, e.g. ground chicken in ~1 lb packs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PackageSize {
+    /// Contents of one package.
+    #[serde(flatten)]
+    pub size: Quantity,
+    /// Display name for one package, e.g. "1 lb pack". Defaults to the size.
+    #[serde(default)]
+    pub label: Option<String>,
+}
+
+impl PackageSize {
+    /// Round a need up to whole packages.
+    ///
+    /// Returns `None` when the need can't be expressed in the package's unit
+    /// (e.g. a count of garlic cloves against a 1 lb bag).
+    pub fn plan_for(&self, need: &Quantity) -> Option<PackagePlan> {
+        if self.size.amount <= 0.0 {
+            return None;
+        }
+        let need_in_pack_unit = need.convert_to(&self.size.unit)?;
+        let exact = need_in_pack_unit.amount / self.size.amount;
+        // Tolerate float noise so 2.0000001 packs doesn't become 3.
+        let count = (exact - 1e-6).ceil().max(1.0) as u32;
+        let bought = f64::from(count) * self.size.amount;
+        let leftover = Quantity::new(
+            (bought - need_in_pack_unit.amount).max(0.0),
+            self.size.unit.clone(),
+        );
+        Some(PackagePlan {
+            count,
+            package: self.clone(),
+            leftover,
+        })
+    }
+
+    /// Label for one package: the configured label or the size itself.
+    pub fn display_label(&self) -> String {
+        self.label.clone().unwrap_or_else(|| self.size.to_string())
+    }
+}
+
+/// Whole packages to buy for a need, and what will be left over.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PackagePlan {
+    pub count: u32,
+    pub package: PackageSize,
+    /// Expected spare amount after the recipes use what they need.
+    pub leftover: Quantity,
+}
+
+impl PackagePlan {
+    /// Whether there's a meaningful amount left over.
+    pub fn has_leftover(&self) -> bool {
+        self.leftover.amount > 1e-6
+    }
+}
+
+impl fmt::Display for PackagePlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} × {}", self.count, self.package.display_label())
+    }
+}
+
+#[cfg(test)]
+#[path = "package_tests.rs"]
+mod tests;
@@ -0,0 +1,63 @@
+use super::*;
+use crate::quantity::Unit;
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+fn pound_pack() -> PackageSize {
+    PackageSize {
+        size: Quantity::new(1.0, Unit::Pound),
+        label: Some("1 lb pack".to_string()),
+    }
+}
+
+#[test]
+fn test_plan_rounds_up_to_whole_packages() -> TestResult {
+    let plan = pound_pack()
+        .plan_for(&Quantity::new(1.5, Unit::Pound))
+        .ok_or("should plan")?;
+    assert_eq!(plan.count, 2);
+    assert!((plan.leftover.amount - 0.5).abs() < 1e-6);
+    assert_eq!(plan.leftover.unit, Unit::Pound);
+    assert_eq!(plan.to_string(), "2 × 1 lb pack");
+    Ok(())
+}
+
+#[test]
+fn test_plan_exact_fit_has_no_leftover() -> TestResult {
+    let plan = pound_pack()
+        .plan_for(&Quantity::new(32.0, Unit::Ounce))
+        .ok_or("should plan")?;
+    assert_eq!(plan.count, 2);
+    assert!(!plan.has_leftover());
+    Ok(())
+}
+
+#[test]
+fn test_plan_small_need_buys_one_package() -> TestResult {
+    let plan = pound_pack()
+        .plan_for(&Quantity::new(4.0, Unit::Ounce))
+        .ok_or("should plan")?;
+    assert_eq!(plan.count, 1);
+    assert!((plan.leftover.amount - 0.75).abs() < 1e-6);
+    Ok(())
+}
+
+#[test]
+fn test_plan_incompatible_need_returns_none() -> TestResult {
+    assert!(pound_pack().plan_for(&Quantity::new(2.0, Unit::Cup)).is_none());
+    assert!(pound_pack().plan_for(&Quantity::count(2.0)).is_none());
+    Ok(())
+}
+
+#[test]
+fn test_package_size_parses_flattened_yaml() -> TestResult {
+    let yaml = "amount: 6\nunit: each\nlabel: bag of 6\n";
+    let pkg: PackageSize = serde_yaml::from_str(yaml)?;
+    assert_eq!(pkg.size, Quantity::count(6.0));
+    assert_eq!(pkg.display_label(), "bag of 6");
+
+    let pkg: PackageSize = serde_yaml::from_str("amount: 13.5\nunit: fl oz\n")?;
+    assert_eq!(pkg.size.unit, Unit::FluidOunce);
+    assert_eq!(pkg.display_label(), "13.5 fl oz");
+    Ok(())
+}
@@ -0,0 +1,226 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ingredient::format_number;
+
+/// Physical dimension of a unit. Only quantities in the same dimension convert.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Dimension {
+    Volume,
+    Mass,
+    Count,
+}
+
+/// Unit of measure for a shopping quantity.
+///
+/// Serialized as its canonical short name ("tbsp", "lb", "can"); a bare count is "each".
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum Unit {
+    Teaspoon,
+    Tablespoon,
+    FluidOunce,
+    Cup,
+    Pint,
+    Quart,
+    Gallon,
+    Milliliter,
+    Liter,
+    Gram,
+    Kilogram,
+    Ounce,
+    Pound,
+    /// A plain count of items ("3 limes").
+    Each,
+    /// A count of some container or piece ("2 cans", "4 cloves").
+    /// Only convertible to the same piece kind.
+    Piece(String),
+}
+
+impl Unit {
+    /// Parse a canonical unit name as produced by the ingredient parser.
+    pub fn parse(name: &str) -> Self {
+        match name.trim().to_lowercase().as_str() {
+            "tsp" => Self::Teaspoon,
+            "tbsp" => Self::Tablespoon,
+            "fl oz" => Self::FluidOunce,
+            "cup" => Self::Cup,
+            "pint" => Self::Pint,
+            "quart" => Self::Quart,
+            "gallon" => Self::Gallon,
+            "ml" => Self::Milliliter,
+            "l" => Self::Liter,
+            "g" => Self::Gram,
+            "kg" => Self::Kilogram,
+            "oz" => Self::Ounce,
+            "lb" => Self::Pound,
+            "" | "each" | "ct" => Self::Each,
+            other => Self::Piece(other.to_string()),
+        }
+    }
+
+    /// Canonical short name.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Teaspoon => "tsp",
+            Self::Tablespoon => "tbsp",
+            Self::FluidOunce => "fl oz",
+            Self::Cup => "cup",
+            Self::Pint => "pint",
+            Self::Quart => "quart",
+            Self::Gallon => "gallon",
+            Self::Milliliter => "ml",
+            Self::Liter => "l",
+            Self::Gram => "g",
+            Self::Kilogram => "kg",
+            Self::Ounce => "oz",
+            Self::Pound => "lb",
+            Self::Each => "each",
+            Self::Piece(name) => name,
+        }
+    }
+
+    pub fn dimension(&self) -> Dimension {
+        match self {
+            Self::Teaspoon
+            | Self::Tablespoon
+            | Self::FluidOunce
+            | Self::Cup
+            | Self::Pint
+            | Self::Quart
+            | Self::Gallon
+            | Self::Milliliter
+            | Self::Liter => Dimension::Volume,
+            Self::Gram | Self::Kilogram | Self::Ounce | Self::Pound => Dimension::Mass,
+            Self::Each | Self::Piece(_) => Dimension::Count,
+        }
+    }
+
+    /// Size of one unit in its dimension's base unit (ml, g, or one item).
+    fn base_factor(&self) -> f64 {
+        match self {
+            Self::Teaspoon => 4.928_921_593_75,
+            Self::Tablespoon => 14.786_764_781_25,
+            Self::FluidOunce => 29.573_529_562_5,
+            Self::Cup => 236.588_236_5,
+            Self::Pint => 473.176_473,
+            Self::Quart => 946.352_946,
+            Self::Gallon => 3_785.411_784,
+            Self::Milliliter => 1.0,
+            Self::Liter => 1_000.0,
+            Self::Gram => 1.0,
+            Self::Kilogram => 1_000.0,
+            Self::Ounce => 28.349_523_125,
+            Self::Pound => 453.592_37,
+            Self::Each | Self::Piece(_) => 1.0,
+        }
+    }
+
+    /// Whether quantities in these two units can be converted into each other.
+    pub fn is_compatible(&self, other: &Unit) -> bool {
+        match (self, other) {
+            (Self::Piece(a), Self::Piece(b)) => a == b,
+            (Self::Piece(_), _) | (_, Self::Piece(_)) => false,
+            _ => self.dimension() == other.dimension(),
+        }
+    }
+}
+
+impl From<String> for Unit {
+    fn from(s: String) -> Self {
+        Self::parse(&s)
+    }
+}
+
+impl From<Unit> for String {
+    fn from(unit: Unit) -> Self {
+        unit.as_str().to_string()
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Display for Dimension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Volume => "volume",
+            Self::Mass => "weight",
+            Self::Count => "count",
+        };
+        f.write_str(name)
+    }
+}
+
+/// An amount with a unit, e.g. 2 tbsp or 3 (each).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Quantity {
+    pub amount: f64,
+    pub unit: Unit,
+}
+
+impl Quantity {
+    pub fn new(amount: f64, unit: Unit) -> Self {
+        Self { amount, unit }
+    }
+
+    /// A plain count of items.
+    pub fn count(amount: f64) -> Self {
+        Self::new(amount, Unit::Each)
+    }
+
+    pub fn dimension(&self) -> Dimension {
+        self.unit.dimension()
+    }
+
+    /// Whether this quantity can be summed with `other`.
+    pub fn is_compatible(&self, other: &Quantity) -> bool {
+        self.unit.is_compatible(&other.unit)
+    }
+
+    /// Convert to another unit in the same dimension.
+    /// Returns `None` for incompatible units (e.g. cups to pounds).
+    pub fn convert_to(&self, unit: &Unit) -> Option<Quantity> {
+        if !self.unit.is_compatible(unit) {
+            return None;
+        }
+        let base = self.amount * self.unit.base_factor();
+        Some(Quantity::new(base / unit.base_factor(), unit.clone()))
+    }
+
+    /// Sum two compatible quantities.
+    ///
+    /// The result is expressed in the larger of the two units, unless that
+    /// would be less than one (2 tbsp + ¼ cup = 6 tbsp, not 0.375 cup).
+    pub fn checked_add(&self, other: &Quantity) -> Option<Quantity> {
+        let (larger, smaller) = if self.unit.base_factor() >= other.unit.base_factor() {
+            (&self.unit, &other.unit)
+        } else {
+            (&other.unit, &self.unit)
+        };
+        let in_larger = self.convert_to(larger)?.amount + other.convert_to(larger)?.amount;
+        if in_larger >= 1.0 || larger == smaller {
+            return Some(Quantity::new(in_larger, larger.clone()));
+        }
+        let in_smaller = self.convert_to(smaller)?.amount + other.convert_to(smaller)?.amount;
+        Some(Quantity::new(in_smaller, smaller.clone()))
+    }
+}
+
+impl fmt::Display for Quantity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.unit {
+            Unit::Each => write!(f, "{}", format_number(self.amount)),
+            _ => write!(f, "{} {}", format_number(self.amount), self.unit),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "quantity_tests.rs"]
+mod tests;
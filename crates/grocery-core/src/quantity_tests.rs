@@ -0,0 +1,97 @@
+use super::*;
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-6
+}
+
+#[test]
+fn test_unit_parse_and_dimension() -> TestResult {
+    assert_eq!(Unit::parse("tbsp"), Unit::Tablespoon);
+    assert_eq!(Unit::parse("lb").dimension(), Dimension::Mass);
+    assert_eq!(Unit::parse("cup").dimension(), Dimension::Volume);
+    assert_eq!(Unit::parse("can"), Unit::Piece("can".to_string()));
+    assert_eq!(Unit::parse("can").dimension(), Dimension::Count);
+    assert_eq!(Unit::parse("each"), Unit::Each);
+    Ok(())
+}
+
+#[test]
+fn test_convert_volume() -> TestResult {
+    let q = Quantity::new(0.25, Unit::Cup);
+    let tbsp = q.convert_to(&Unit::Tablespoon).ok_or("should convert")?;
+    assert!(close(tbsp.amount, 4.0), "got {}", tbsp.amount);
+
+    let tsp = Quantity::new(1.0, Unit::Tablespoon)
+        .convert_to(&Unit::Teaspoon)
+        .ok_or("should convert")?;
+    assert!(close(tsp.amount, 3.0));
+    Ok(())
+}
+
+#[test]
+fn test_convert_mass() -> TestResult {
+    let q = Quantity::new(16.0, Unit::Ounce);
+    let lb = q.convert_to(&Unit::Pound).ok_or("should convert")?;
+    assert!(close(lb.amount, 1.0));
+    Ok(())
+}
+
+#[test]
+fn test_convert_across_dimensions_fails() -> TestResult {
+    assert!(Quantity::new(1.0, Unit::Cup).convert_to(&Unit::Pound).is_none());
+    assert!(Quantity::count(2.0).convert_to(&Unit::Cup).is_none());
+    assert!(Quantity::new(1.0, Unit::Piece("can".to_string()))
+        .convert_to(&Unit::Piece("clove".to_string()))
+        .is_none());
+    Ok(())
+}
+
+#[test]
+fn test_checked_add_prefers_readable_unit() -> TestResult {
+    // 2 tbsp + 1/4 cup = 6 tbsp (0.375 cup would be awkward)
+    let sum = Quantity::new(2.0, Unit::Tablespoon)
+        .checked_add(&Quantity::new(0.25, Unit::Cup))
+        .ok_or("should add")?;
+    assert_eq!(sum.unit, Unit::Tablespoon);
+    assert!(close(sum.amount, 6.0));
+
+    // 1 cup + 8 tbsp = 1.5 cups
+    let sum = Quantity::new(1.0, Unit::Cup)
+        .checked_add(&Quantity::new(8.0, Unit::Tablespoon))
+        .ok_or("should add")?;
+    assert_eq!(sum.unit, Unit::Cup);
+    assert!(close(sum.amount, 1.5));
+    Ok(())
+}
+
+#[test]
+fn test_checked_add_counts() -> TestResult {
+    let sum = Quantity::count(2.0)
+        .checked_add(&Quantity::count(3.0))
+        .ok_or("should add")?;
+    assert_eq!(sum, Quantity::count(5.0));
+
+    let cans = Quantity::new(1.0, Unit::Piece("can".to_string()));
+    assert!(cans.checked_add(&Quantity::count(1.0)).is_none());
+    Ok(())
+}
+
+#[test]
+fn test_quantity_display() -> TestResult {
+    assert_eq!(Quantity::count(3.0).to_string(), "3");
+    assert_eq!(Quantity::new(2.0, Unit::Tablespoon).to_string(), "2 tbsp");
+    assert_eq!(Quantity::new(0.5, Unit::Piece("can".to_string())).to_string(), "0.5 can");
+    Ok(())
+}
+
+#[test]
+fn test_quantity_serde_roundtrip() -> TestResult {
+    let q = Quantity::new(1.5, Unit::Cup);
+    let json = serde_json::to_string(&q)?;
+    assert_eq!(json, r#"{"amount":1.5,"unit":"cup"}"#);
+    let parsed: Quantity = serde_json::from_str(&json)?;
+    assert_eq!(parsed, q);
+    Ok(())
+}
@@ -1,33 +1,56 @@
-use serde::{Deserialize, Serialize};
+use chrono::NaiveDate;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+
+use crate::dates;
+
+use crate::ingredient::{parse_ingredient, ParsedIngredient};
 
 /// A recipe from the collection (recipe-links.json schema).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Recipe {
     pub name: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub rating: Option<u8>,
     #[serde(default)]
     pub needs_fixing: bool,
-    #[serde(default)]
-    pub last_made: Option<String>,
+    #[serde(default, deserialize_with = "last_made", skip_serializing_if = "Option::is_none")]
+    pub last_made: Option<NaiveDate>,
     #[serde(default)]
     pub times_made: u32,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub feedback: Vec<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub ingredients: Vec<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cook_time: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub servings: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub primary_protein: Option<String>,
+    /// Keys this version doesn't model, written back as they were.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A whole, decimal or fractional number ("2", "1.5", "1/2").
+fn parse_amount(word: &str) -> Option<f64> {
+    if let Ok(n) = word.parse::<f64>() {
+        return Some(n);
+    }
+    let (num, den) = word.split_once('/')?;
+    let (num, den) = (num.parse::<f64>().ok()?, den.parse::<f64>().ok()?);
+    (den > 0.0).then(|| num / den)
+}
+
+fn last_made<'de, D: Deserializer<'de>>(d: D) -> Result<Option<NaiveDate>, D::Error> {
+    dates::deserialize_opt_date(d, "last_made")
 }
 
 impl Recipe {
@@ -35,6 +58,117 @@ impl Recipe {
     pub fn has_ingredients(&self) -> bool {
         !self.ingredients.is_empty()
     }
+
+    /// Parse every ingredient line into its structured form.
+    pub fn parsed_ingredients(&self) -> Vec<ParsedIngredient> {
+        self.ingredients.iter().map(|i| parse_ingredient(i)).collect()
+    }
+
+    /// Total cook time in minutes, parsed from `cook_time` ("25 minutes",
+    /// "1 hour 15 min", "1 1/2 hours", "1hr 45min"). Ranges ("25–30 min",
+    /// "2 to 3 hours") count at their longest. `None` if blank or
+    /// unreadable.
+    pub fn cook_minutes(&self) -> Option<u32> {
+        // Split glued numbers and units ("45min" → "45 min")
+        let mut text = String::new();
+        let mut prev: Option<char> = None;
+        for c in self.cook_time.as_deref()?.to_lowercase().chars() {
+            let boundary = prev.is_some_and(|p| {
+                (p.is_ascii_digit() && c.is_alphabetic()) || (p.is_alphabetic() && c.is_ascii_digit())
+            });
+            if boundary {
+                text.push(' ');
+            }
+            text.push(c);
+            prev = Some(c);
+        }
+        let mut minutes = 0.0;
+        let mut amount: Option<f64> = None;
+        // Set after "-" or "to": the next number replaces the low end, so
+        // ranges count at their longest
+        let mut range = false;
+        let mut found = false;
+        for word in text.split(|c: char| c.is_whitespace() || c == ',') {
+            let word = word.trim_end_matches('.');
+            if let Some(n) = parse_amount(word) {
+                amount = Some(if range { n } else { amount.unwrap_or(0.0) + n });
+                range = false;
+            } else if matches!(word, "-" | "–" | "—" | "to") {
+                range = true;
+            } else if let Some((low, high)) = word
+                .split_once(['-', '–', '—'])
+                .filter(|(low, _)| low.is_empty() || parse_amount(low).is_some())
+            {
+                if let Some(low) = parse_amount(low) {
+                    amount = Some(if range { low } else { amount.unwrap_or(0.0) + low });
+                }
+                match parse_amount(high) {
+                    Some(high) => {
+                        amount = Some(high);
+                        range = false;
+                    }
+                    None => range = true,
+                }
+            } else if matches!(word, "h" | "hr" | "hrs" | "hour" | "hours") {
+                if let Some(n) = amount.take() {
+                    minutes += n * 60.0;
+                    found = true;
+                }
+            } else if matches!(word, "m" | "min" | "mins" | "minute" | "minutes") {
+                if let Some(n) = amount.take() {
+                    minutes += n;
+                    found = true;
+                }
+            }
+        }
+        found.then(|| minutes.round() as u32)
+    }
+
+    /// How many the recipe serves, parsed from `servings`.
+    pub fn servings_range(&self) -> Option<Servings> {
+        self.servings.as_deref().and_then(Servings::parse)
+    }
+
+    /// Factor to multiply ingredient quantities by to feed `target`
+    /// servings, or `None` if the recipe doesn't say how many it serves.
+    pub fn scale_factor(&self, target: f64) -> Option<f64> {
+        self.servings_range().map(|s| target / f64::from(s.min))
+    }
+}
+
+/// How many a recipe serves, e.g. 6 to 8 for "6–8 servings".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Servings {
+    pub min: u32,
+    /// Same as `min` unless the recipe gives a range.
+    pub max: u32,
+}
+
+impl Servings {
+    /// Parse free-form servings text: "4 servings", "Serves 6–8",
+    /// "2 to 3 servings", "Makes 12". Returns `None` without a positive
+    /// number.
+    pub fn parse(text: &str) -> Option<Self> {
+        let (min, rest) = leading_number(text)?;
+        let rest = rest.trim_start();
+        let after_separator = ["-", "–", "—", "to ", "or "]
+            .iter()
+            .find_map(|sep| rest.strip_prefix(sep));
+        let max = after_separator
+            .and_then(|r| r.trim_start().split(|c: char| !c.is_ascii_digit()).next())
+            .and_then(|digits| digits.parse::<u32>().ok())
+            .filter(|&max| max >= min)
+            .unwrap_or(min);
+        (min > 0).then_some(Self { min, max })
+    }
+}
+
+/// The first run of digits in `text` and whatever follows it.
+fn leading_number(text: &str) -> Option<(u32, &str)> {
+    let start = text.find(|c: char| c.is_ascii_digit())?;
+    let digits = &text[start..];
+    let end = digits.find(|c: char| !c.is_ascii_digit()).unwrap_or(digits.len());
+    Some((digits[..end].parse().ok()?, &digits[end..]))
 }
 
 #[cfg(test)]
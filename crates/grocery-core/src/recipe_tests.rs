@@ -58,3 +58,95 @@ fn test_parse_recipe_collection() -> TestResult {
     assert!(recipes[1].has_ingredients());
     Ok(())
 }
+
+#[test]
+fn test_parsed_ingredients() -> TestResult {
+    let json = r#"{
+        "name": "Test",
+        "ingredients": ["2 Tbsp. soy sauce", "8 garlic cloves, thinly sliced"]
+    }"#;
+    let recipe: Recipe = serde_json::from_str(json)?;
+    let parsed = recipe.parsed_ingredients();
+    assert_eq!(parsed.len(), 2);
+    assert_eq!(parsed[0].name, "soy sauce");
+    assert_eq!(parsed[0].unit.as_deref(), Some("tbsp"));
+    assert_eq!(parsed[1].name, "garlic");
+    assert_eq!(parsed[1].preparation.as_deref(), Some("thinly sliced"));
+    Ok(())
+}
+
+#[test]
+fn test_recipe_last_made_accepts_our_date_formats() -> TestResult {
+    let expected = chrono::NaiveDate::from_ymd_opt(2025, 8, 28);
+    for raw in ["2025-08-28", "Aug 28, 2025"] {
+        let json = format!(r#"{{"name": "R", "last_made": "{raw}"}}"#);
+        let recipe: Recipe = serde_json::from_str(&json)?;
+        assert_eq!(recipe.last_made, expected, "{raw}");
+    }
+
+    let recipe: Recipe = serde_json::from_str(r#"{"name": "R", "last_made": ""}"#)?;
+    assert_eq!(recipe.last_made, None);
+
+    // Serializes back in ISO form
+    let recipe: Recipe = serde_json::from_str(r#"{"name": "R", "last_made": "Aug 28, 2025"}"#)?;
+    assert!(serde_json::to_string(&recipe)?.contains(r#""last_made":"2025-08-28""#));
+    Ok(())
+}
+
+#[test]
+fn test_recipe_last_made_rejects_garbage_naming_field() -> TestResult {
+    let err = serde_json::from_str::<Recipe>(r#"{"name": "R", "last_made": "last Tuesday"}"#)
+        .err()
+        .ok_or("expected an error")?;
+    assert!(err.to_string().contains("invalid last_made \"last Tuesday\""), "{err}");
+    Ok(())
+}
+
+#[test]
+fn test_parse_servings() -> TestResult {
+    assert_eq!(Servings::parse("4 servings"), Some(Servings { min: 4, max: 4 }));
+    assert_eq!(Servings::parse("6–8 servings"), Some(Servings { min: 6, max: 8 }));
+    assert_eq!(Servings::parse("Serves 2 to 3"), Some(Servings { min: 2, max: 3 }));
+    assert_eq!(Servings::parse("Makes 12 (about 4 servings)"), Some(Servings { min: 12, max: 12 }));
+    assert_eq!(Servings::parse("4 or 5"), Some(Servings { min: 4, max: 5 }));
+    assert_eq!(Servings::parse("a crowd"), None);
+    assert_eq!(Servings::parse("0 servings"), None);
+    Ok(())
+}
+
+#[test]
+fn test_scale_factor_feeds_the_low_end() -> TestResult {
+    let recipe: Recipe = serde_json::from_str(r#"{"name": "T", "servings": "6-8 servings"}"#)?;
+    assert_eq!(recipe.scale_factor(3.0), Some(0.5));
+    let unknown: Recipe = serde_json::from_str(r#"{"name": "T"}"#)?;
+    assert_eq!(unknown.scale_factor(3.0), None);
+    Ok(())
+}
+
+#[test]
+fn test_cook_minutes() -> TestResult {
+    let minutes = |text: &str| -> Result<Option<u32>, Box<dyn std::error::Error>> {
+        let json = format!(r#"{{"name": "T", "cook_time": {text:?}}}"#);
+        Ok(serde_json::from_str::<Recipe>(&json)?.cook_minutes())
+    };
+    assert_eq!(minutes("25 minutes")?, Some(25));
+    assert_eq!(minutes("20 min")?, Some(20));
+    assert_eq!(minutes("1 hour 15 minutes")?, Some(75));
+    assert_eq!(minutes("1 1/2 hours")?, Some(90));
+    assert_eq!(minutes("3 hrs")?, Some(180));
+    assert_eq!(minutes("25–30 minutes")?, Some(30));
+    assert_eq!(minutes("1hr")?, Some(60));
+    assert_eq!(minutes("45min")?, Some(45));
+    assert_eq!(minutes("1hr 15mins")?, Some(75));
+    assert_eq!(minutes("25–30min")?, Some(30));
+    assert_eq!(minutes("25 - 30 min")?, Some(30));
+    assert_eq!(minutes("25- 30 min")?, Some(30));
+    assert_eq!(minutes("2 to 3 hours")?, Some(180));
+    assert_eq!(minutes("1 to 1 1/2 hours")?, Some(90));
+    // Only hour words count as hours
+    assert_eq!(minutes("10 hands-on minutes")?, Some(10));
+    assert_eq!(minutes("4 hrs on high")?, Some(240));
+    assert_eq!(minutes("")?, None);
+    assert_eq!(minutes("overnight")?, None);
+    Ok(())
+}
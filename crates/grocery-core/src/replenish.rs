@@ -0,0 +1,122 @@
+use chrono::{Days, NaiveDate};
+
+use crate::dates::CycleDays;
+use crate::household::HouseholdModel;
+use crate::order::{Order, OrderHistory};
+use crate::types::{Category, FrequencyTier, Store};
+
+/// How far ahead to look for due items: one weekly order.
+pub const DEFAULT_LOOKAHEAD_DAYS: i64 = 7;
+
+/// A cycle item and when it next needs buying.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Replenishment {
+    pub item: String,
+    pub category: Category,
+    pub store: Store,
+    pub cycle: CycleDays,
+    pub last_seen: NaiveDate,
+    /// Earliest expected run-out: last purchase plus the shortest cycle.
+    pub due: NaiveDate,
+}
+
+impl Replenishment {
+    /// `None` if the due date is past the end of the calendar.
+    fn new(item: &str, category: &Category, store: Store, cycle: CycleDays, last_seen: NaiveDate) -> Option<Self> {
+        Some(Self {
+            item: item.to_string(),
+            category: category.clone(),
+            store,
+            cycle,
+            last_seen,
+            due: last_seen.checked_add_days(Days::new(u64::from(cycle.min)))?,
+        })
+    }
+
+    /// Days from `today` until due; negative when overdue.
+    pub fn days_until_due(&self, today: NaiveDate) -> i64 {
+        (self.due - today).num_days()
+    }
+
+    /// Whether the item runs out within `lookahead_days` of `today`.
+    pub fn is_due(&self, today: NaiveDate, lookahead_days: i64) -> bool {
+        self.days_until_due(today) <= lookahead_days
+    }
+
+    /// Short explanation for list notes, e.g. "every 30 days, last bought Aug 28".
+    pub fn describe(&self) -> String {
+        format!(
+            "every {}, last bought {}",
+            self.cycle,
+            self.last_seen.format("%b %-d")
+        )
+    }
+}
+
+/// Predict next due dates for every cycle item we can date.
+///
+/// - Items with a `cycle_days` use it with their `last_seen`, moved forward
+///   if their store's order history shows a later delivery.
+/// - Other items below the every-order tier get their cycle from the
+///   median gap between deliveries in their store's order history (staples
+///   are bought every order anyway).
+///
+/// Items without a cycle or purchase date are skipped, as are cycles too
+/// long to give a due date. Results are sorted by due date.
+pub fn predict_replenishments(
+    model: &HouseholdModel,
+    history: Option<&OrderHistory>,
+) -> Vec<Replenishment> {
+    let mut predictions = Vec::new();
+
+    for item in &model.recurring {
+        let orders = history.map(|h| h.for_store(&item.store)).unwrap_or_default();
+        let dates = delivery_dates(&item.item, &orders);
+
+        let (cycle, last_seen) = match item.cycle_days {
+            Some(cycle) => (Some(cycle), item.last_seen.max(dates.last().copied())),
+            None if item.tier() == FrequencyTier::EveryOrder => continue,
+            None => (median_gap(&dates), dates.last().copied()),
+        };
+        let (Some(cycle), Some(last_seen)) = (cycle, last_seen) else {
+            continue;
+        };
+        predictions.extend(Replenishment::new(
+            &item.item,
+            &item.category,
+            item.store.clone(),
+            cycle,
+            last_seen,
+        ));
+    }
+
+    predictions.sort_by(|a, b| a.due.cmp(&b.due).then(a.item.cmp(&b.item)));
+    predictions
+}
+
+/// Distinct dates an item was delivered, oldest first.
+fn delivery_dates(item: &str, orders: &[&Order]) -> Vec<NaiveDate> {
+    let mut dates: Vec<NaiveDate> = orders
+        .iter()
+        .filter(|o| o.delivered().any(|l| l.matches_item(item)))
+        .map(|o| o.date)
+        .collect();
+    dates.sort();
+    dates.dedup();
+    dates
+}
+
+/// Median days between consecutive purchases; needs at least two.
+fn median_gap(dates: &[NaiveDate]) -> Option<CycleDays> {
+    let mut gaps: Vec<i64> = dates.windows(2).map(|w| (w[1] - w[0]).num_days()).collect();
+    if gaps.is_empty() {
+        return None;
+    }
+    gaps.sort_unstable();
+    let median = gaps[gaps.len() / 2];
+    u32::try_from(median).ok().filter(|d| *d > 0).map(CycleDays::exactly)
+}
+
+#[cfg(test)]
+#[path = "replenish_tests.rs"]
+mod tests;
@@ -0,0 +1,152 @@
+use super::*;
+use crate::order::parse_order_history;
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+const MODEL: &str = r#"
+family:
+  members: []
+stores: {}
+amazon_recurring:
+  - item: "Pampers Diapers Size 4"
+    category: baby
+    cycle_days: "~30 days"
+    last_seen: "Aug 28, 2025"
+  - item: "Blue Buffalo Dog Food"
+    category: pet
+    cycle_days: "45-60 days"
+    last_seen: "2025-08-01"
+  - item: "Undated Cycle"
+    category: household
+    last_seen: "2025-08-01"
+  - item: "Undated Item"
+    category: household
+    cycle_days: "30"
+giant_recurring:
+  - item: "Bounty Paper Towels"
+    category: household
+    frequency: "4/18"
+  - item: "Limes"
+    category: produce
+    frequency: "16/18"
+"#;
+
+const ORDERS: &str = "\
+## Order #m4 - September 15, 2025
+### Laundry, Paper & Cleaning
+- **Bounty** Select-A-Size Paper Towels - 1
+### Produce
+- Limes - 1
+
+## Order #m3 - August 18, 2025
+### Out of Stock Items
+- **Bounty** Select-A-Size Paper Towels - 0
+
+## Order #m2 - August 11, 2025
+### Laundry, Paper & Cleaning
+- **Bounty** Select-A-Size Paper Towels - 1
+
+## Order #m1 - July 14, 2025
+### Laundry, Paper & Cleaning
+- **Bounty** Select-A-Size Paper Towels - 1
+### Produce
+- Limes - 1
+";
+
+fn date(y: i32, m: u32, d: u32) -> Result<NaiveDate, Box<dyn std::error::Error>> {
+    Ok(NaiveDate::from_ymd_opt(y, m, d).ok_or("bad date")?)
+}
+
+fn history() -> OrderHistory {
+    OrderHistory {
+        orders: parse_order_history(ORDERS, &Store::Giant).orders,
+    }
+}
+
+#[test]
+fn test_amazon_items_use_cycle_and_last_seen() -> TestResult {
+    let model = HouseholdModel::from_yaml(MODEL)?;
+    let predictions = predict_replenishments(&model, None);
+
+    // Items missing a cycle or a date are skipped; sorted by due date
+    let names: Vec<&str> = predictions.iter().map(|p| p.item.as_str()).collect();
+    assert_eq!(names, vec!["Blue Buffalo Dog Food", "Pampers Diapers Size 4"]);
+
+    let dog_food = &predictions[0];
+    assert_eq!(dog_food.store, Store::Amazon);
+    assert_eq!(dog_food.cycle, CycleDays::new(45, 60));
+    assert_eq!(dog_food.due, date(2025, 9, 15)?);
+
+    let diapers = &predictions[1];
+    assert_eq!(diapers.due, date(2025, 9, 27)?);
+    assert_eq!(diapers.describe(), "every 30 days, last bought Aug 28");
+    Ok(())
+}
+
+#[test]
+fn test_due_window() -> TestResult {
+    let model = HouseholdModel::from_yaml(MODEL)?;
+    let predictions = predict_replenishments(&model, None);
+    let diapers = predictions
+        .iter()
+        .find(|p| p.item.starts_with("Pampers"))
+        .ok_or("missing diapers")?;
+
+    let today = date(2025, 9, 22)?;
+    assert_eq!(diapers.days_until_due(today), 5);
+    assert!(diapers.is_due(today, DEFAULT_LOOKAHEAD_DAYS));
+    assert!(!diapers.is_due(today, 3));
+    assert!(diapers.is_due(date(2025, 10, 5)?, 0));
+    Ok(())
+}
+
+#[test]
+fn test_giant_cycle_inferred_from_deliveries() -> TestResult {
+    let model = HouseholdModel::from_yaml(MODEL)?;
+    let history = history();
+    let predictions = predict_replenishments(&model, Some(&history));
+
+    // Limes is a staple, so only paper towels get a Giant prediction
+    let giant: Vec<&Replenishment> = predictions.iter().filter(|p| p.store == Store::Giant).collect();
+    assert_eq!(giant.len(), 1);
+
+    // Delivered Jul 14, Aug 11, Sep 15 (the Aug 18 out-of-stock doesn't count):
+    // gaps of 28 and 35 days
+    let towels = giant[0];
+    assert_eq!(towels.item, "Bounty Paper Towels");
+    assert_eq!(towels.last_seen, date(2025, 9, 15)?);
+    assert_eq!(towels.cycle, CycleDays::exactly(35));
+    assert_eq!(towels.due, date(2025, 10, 20)?);
+    Ok(())
+}
+
+#[test]
+fn test_history_moves_amazon_last_seen_forward() -> TestResult {
+    let model = HouseholdModel::from_yaml(MODEL)?;
+    let md = "\
+## Order #a1 - September 20, 2025
+### Baby
+- **Pampers** Cruisers Diapers Size 4 - 1
+";
+    let history = OrderHistory {
+        orders: parse_order_history(md, &Store::Amazon).orders,
+    };
+    let predictions = predict_replenishments(&model, Some(&history));
+    let diapers = predictions
+        .iter()
+        .find(|p| p.item.starts_with("Pampers"))
+        .ok_or("missing diapers")?;
+    assert_eq!(diapers.last_seen, date(2025, 9, 20)?);
+    assert_eq!(diapers.due, date(2025, 10, 20)?);
+    Ok(())
+}
+
+#[test]
+fn test_cycle_past_the_calendar_is_skipped() -> TestResult {
+    let model = HouseholdModel::from_yaml(&format!(
+        "{MODEL}  - item: \"Forever Stamps\"\n    category: household\n    cycle_days: \"900000000 days\"\n    last_seen: \"2025-08-01\"\n"
+    ))?;
+    let names: Vec<String> = predict_replenishments(&model, None).into_iter().map(|p| p.item).collect();
+    assert_eq!(names, vec!["Blue Buffalo Dog Food", "Pampers Diapers Size 4"]);
+    Ok(())
+}
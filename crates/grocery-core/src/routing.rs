@@ -0,0 +1,150 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::item::RecurringItem;
+use crate::types::Store;
+
+/// Which store to buy things at, from the `routing` section of
+/// household-model.yaml.
+///
+/// ```yaml
+/// routing:
+///   default_store: giant
+///   availability:
+///     gochujang:
+///       not_at: [giant]
+///       buy_at: [H Mart, amazon]
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StoreRouting {
+    /// Store for items nothing else routes.
+    #[serde(default = "default_store")]
+    pub default_store: Store,
+    /// Items some stores don't carry, keyed by name. A key matches any item
+    /// whose name contains it (case-insensitive); the longest key wins.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub availability: BTreeMap<String, Availability>,
+}
+
+fn default_store() -> Store {
+    Store::Giant
+}
+
+impl Default for StoreRouting {
+    fn default() -> Self {
+        Self {
+            default_store: default_store(),
+            availability: BTreeMap::new(),
+        }
+    }
+}
+
+/// Where one item can and can't be bought.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Availability {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub not_at: Vec<Store>,
+    /// Stores that carry it, most preferred first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub buy_at: Vec<Store>,
+}
+
+/// Why an item was sent to its store.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RouteSource {
+    /// It's a recurring item bought at that store.
+    Recurring,
+    /// The availability map named it.
+    Availability,
+    /// Nothing else applied.
+    Default,
+}
+
+/// The store chosen for one item.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Route {
+    pub store: Store,
+    pub source: RouteSource,
+    /// Shown on the list when routing needs explaining, e.g. "not at Giant".
+    pub note: Option<String>,
+}
+
+impl StoreRouting {
+    /// Whether this is the default routing, so it can be left out of saved files.
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// The availability entry matching an item name, if any.
+    pub fn availability_for(&self, name: &str) -> Option<&Availability> {
+        let lower = name.to_lowercase();
+        self.availability
+            .iter()
+            .filter(|(key, _)| lower.contains(&key.to_lowercase()))
+            .max_by_key(|(key, _)| key.len())
+            .map(|(_, availability)| availability)
+    }
+
+    /// Pick a store for an item.
+    ///
+    /// A recurring item goes where it's usually bought. Otherwise the
+    /// availability map is consulted: the first `buy_at` store that isn't
+    /// also in `not_at`, else the default store if it carries the item. An
+    /// item no known store carries stays at the default store with a note.
+    pub fn route(&self, name: &str, recurring: &[RecurringItem]) -> Route {
+        if let Some(item) = recurring.iter().find(|r| r.item.eq_ignore_ascii_case(name)) {
+            return Route {
+                store: item.store.clone(),
+                source: RouteSource::Recurring,
+                note: None,
+            };
+        }
+
+        let Some(availability) = self.availability_for(name) else {
+            return Route {
+                store: self.default_store.clone(),
+                source: RouteSource::Default,
+                note: None,
+            };
+        };
+
+        let mut carriers = availability
+            .buy_at
+            .iter()
+            .chain(std::iter::once(&self.default_store))
+            .filter(|store| !availability.not_at.contains(store));
+        let store = carriers.next();
+        let alternatives: Vec<String> = carriers
+            .filter(|s| Some(*s) != store && availability.buy_at.contains(s))
+            .map(Store::to_string)
+            .collect();
+
+        let not_at: Vec<String> = availability.not_at.iter().map(Store::to_string).collect();
+        let mut note = (!not_at.is_empty()).then(|| format!("not at {}", not_at.join(" or ")));
+        if !alternatives.is_empty() {
+            let also = format!("also at {}", alternatives.join(", "));
+            note = Some(match note {
+                Some(n) => format!("{n}; {also}"),
+                None => also,
+            });
+        }
+
+        match store {
+            Some(store) => Route {
+                store: store.clone(),
+                source: RouteSource::Availability,
+                note,
+            },
+            None => Route {
+                store: self.default_store.clone(),
+                source: RouteSource::Availability,
+                note: Some(format!("{}; no known store carries it", note.unwrap_or_default())),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "routing_tests.rs"]
+mod tests;
@@ -0,0 +1,97 @@
+use super::*;
+use crate::household::HouseholdModel;
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+const MODEL: &str = r#"
+family:
+  members: []
+stores: {}
+recurring:
+  - item: "Diapers"
+    category: baby
+    store: amazon
+  - item: "Everything Bagel Seasoning"
+    category: condiments
+    store: trader_joes
+routing:
+  default_store: giant
+  availability:
+    gochujang:
+      not_at: [giant]
+      buy_at: [H Mart, amazon]
+    fish sauce:
+      buy_at: [H Mart]
+    "red boat fish sauce":
+      not_at: [giant, H Mart]
+    durian:
+      not_at: [giant]
+"#;
+
+#[test]
+fn test_recurring_items_go_to_their_store() -> TestResult {
+    let model = HouseholdModel::from_yaml(MODEL)?;
+    let route = model.route("diapers");
+    assert_eq!(route.store, Store::Amazon);
+    assert_eq!(route.source, RouteSource::Recurring);
+    assert_eq!(model.route("Everything Bagel Seasoning").store, Store::TraderJoes);
+    Ok(())
+}
+
+#[test]
+fn test_unlisted_items_go_to_default_store() -> TestResult {
+    let model = HouseholdModel::from_yaml(MODEL)?;
+    let route = model.route("Bananas");
+    assert_eq!(route.store, Store::Giant);
+    assert_eq!(route.source, RouteSource::Default);
+    assert_eq!(route.note, None);
+
+    // No routing section at all
+    let model = HouseholdModel::from_yaml("family:\n  members: []\nstores: {}\n")?;
+    assert_eq!(model.route("Bananas").store, Store::Giant);
+    Ok(())
+}
+
+#[test]
+fn test_availability_routes_to_first_carrier() -> TestResult {
+    let model = HouseholdModel::from_yaml(MODEL)?;
+    let route = model.route("gochujang paste");
+    assert_eq!(route.store, Store::Other("H Mart".to_string()));
+    assert_eq!(route.source, RouteSource::Availability);
+    assert_eq!(route.note.as_deref(), Some("not at Giant; also at Amazon"));
+    Ok(())
+}
+
+#[test]
+fn test_longest_availability_key_wins() -> TestResult {
+    let model = HouseholdModel::from_yaml(MODEL)?;
+    assert_eq!(model.route("fish sauce").store, Store::Other("H Mart".to_string()));
+
+    // Neither the default store nor H Mart carries this brand
+    let route = model.route("Red Boat Fish Sauce");
+    assert_eq!(route.store, Store::Giant);
+    assert_eq!(route.note.as_deref(), Some("not at Giant or H Mart; no known store carries it"));
+    Ok(())
+}
+
+#[test]
+fn test_not_carried_anywhere_known_stays_at_default() -> TestResult {
+    let model = HouseholdModel::from_yaml(MODEL)?;
+    let route = model.route("durian");
+    assert_eq!(route.store, Store::Giant);
+    assert_eq!(route.note.as_deref(), Some("not at Giant; no known store carries it"));
+    Ok(())
+}
+
+#[test]
+fn test_routing_round_trips_and_default_is_omitted() -> TestResult {
+    let model = HouseholdModel::from_yaml(MODEL)?;
+    let yaml = serde_yaml::to_string(&model)?;
+    assert!(yaml.contains("H Mart"), "{yaml}");
+    let reloaded = HouseholdModel::from_yaml(&yaml)?;
+    assert_eq!(reloaded.routing, model.routing);
+
+    let bare = HouseholdModel::from_yaml("family:\n  members: []\nstores: {}\n")?;
+    assert!(!serde_yaml::to_string(&bare)?.contains("routing"));
+    Ok(())
+}
@@ -1,18 +1,52 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 
 use serde::{Deserialize, Serialize};
 
-use crate::error::{GroceryError, GroceryResult};
+use crate::categorize::{category_from_keywords, contains_word};
+use crate::error::{yaml_location, GroceryError, GroceryResult};
+use crate::package::PackageSize;
+use crate::types::Category;
 
-/// Recipe scoring weights — must sum to 1.0.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ScoringWeights {
-    pub ingredient_overlap: f64,
-    pub protein_alignment: f64,
-    pub cuisine_affinity: f64,
-    pub practical_friction: f64,
-    pub family_fit: f64,
+/// Recipe scoring weights, keyed by dimension name (`family_fit`,
+/// `recency`, ...) — must sum to 1.0. Dimensions added after a config was
+/// written get a share from [`DEFAULT_WEIGHTS`] (see
+/// [`ScoringConfig::weight`]), and every weight the config does write is
+/// scaled down by that share, so leaving one out changes the ranking;
+/// `grocery check` warns about each. Any other dimension without a weight
+/// still shows in the breakdown but counts for nothing.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ScoringWeights(BTreeMap<String, f64>);
+
+/// Weights for dimensions a config may predate. When the config leaves one
+/// out it gets this share, and the written weights shrink to make room.
+pub const DEFAULT_WEIGHTS: &[(&str, f64)] = &[("recency", 0.10), ("track_record", 0.10)];
+
+impl ScoringWeights {
+    /// The weight the config gives a dimension, 0 if it doesn't give one.
+    pub fn get(&self, key: &str) -> f64 {
+        self.0.get(key).copied().unwrap_or(0.0)
+    }
+
+    /// Whether the config gives a weight for `key` (even 0).
+    pub fn contains(&self, key: &str) -> bool {
+        self.0.contains_key(key)
+    }
+
+    pub fn set(&mut self, key: &str, weight: f64) {
+        self.0.insert(key.to_string(), weight);
+    }
+
+    /// Each weight with its config key.
+    pub fn named(&self) -> impl Iterator<Item = (&str, f64)> {
+        self.0.iter().map(|(key, weight)| (key.as_str(), *weight))
+    }
+
+    /// Sum of all weights; should be 1.0.
+    pub fn total(&self) -> f64 {
+        self.0.values().sum()
+    }
 }
 
 /// Ingredient mapping entry from the scoring config.
@@ -23,8 +57,37 @@ pub struct IngredientMapping {
     pub tier: u8,
     #[serde(default)]
     pub aliases: Vec<String>,
+    /// Store category for list output; inferred when absent.
+    #[serde(default)]
+    pub category: Option<Category>,
     #[serde(default)]
     pub note: Option<String>,
+    /// Whether a partly used package spoils before it's used up; inferred
+    /// when absent (see [`is_perishable`](Self::is_perishable)).
+    #[serde(default)]
+    pub perishable: Option<bool>,
+}
+
+/// Produce that keeps for weeks, so leftovers aren't a worry.
+const LONG_KEEPING: &[&str] = &["garlic", "onion", "potato", "shallot", "ginger", "winter squash"];
+
+/// Fresh forms of long-keeping produce.
+const FRESH_FORMS: &[&str] = &["green onion", "spring onion", "green garlic"];
+
+impl IngredientMapping {
+    /// The explicit `perishable` flag, or else whether the mapping (keyed
+    /// `key` in `ingredient_map`) is produce or dairy that doesn't keep:
+    /// fresh herbs, citrus and green onions yes; garlic and onions no.
+    pub fn is_perishable(&self, key: &str) -> bool {
+        if let Some(perishable) = self.perishable {
+            return perishable;
+        }
+        let name = self.model_item.as_deref().unwrap_or(key).to_lowercase().replace('_', " ");
+        let category = self.category.clone().or_else(|| category_from_keywords(&name));
+        let keeps = LONG_KEEPING.iter().any(|word| contains_word(&name, word))
+            && !FRESH_FORMS.iter().any(|word| contains_word(&name, word));
+        matches!(category, Some(Category::Produce | Category::Dairy)) && !keeps
+    }
 }
 
 /// Flavor booster keywords grouped by impact level.
@@ -48,6 +111,131 @@ pub struct UntappedOpportunity {
     pub note: Option<String>,
 }
 
+/// How the Recency dimension treats recently and long-ago cooked recipes.
+///
+/// ```yaml
+/// recency:
+///   window_days: 21
+///   revive_after_days: 90
+///   revive_min_rating: 4
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecencyConfig {
+    /// Recipes made within this many days are penalised, most when made
+    /// most recently.
+    #[serde(default = "default_window_days")]
+    pub window_days: u32,
+    /// Recipes rated at least `revive_min_rating` and not made for this
+    /// many days score full marks; omit to turn the boost off.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revive_after_days: Option<u32>,
+    #[serde(default = "default_revive_min_rating")]
+    pub revive_min_rating: u8,
+    /// Score (0-100) for recipes never made, or made before the window
+    /// and not revived.
+    #[serde(default = "default_baseline_score")]
+    pub baseline_score: u32,
+}
+
+fn default_window_days() -> u32 {
+    21
+}
+
+fn default_revive_min_rating() -> u8 {
+    4
+}
+
+fn default_baseline_score() -> u32 {
+    80
+}
+
+impl Default for RecencyConfig {
+    fn default() -> Self {
+        Self {
+            window_days: default_window_days(),
+            revive_after_days: None,
+            revive_min_rating: default_revive_min_rating(),
+            baseline_score: default_baseline_score(),
+        }
+    }
+}
+
+/// Words and phrases in recipe feedback that count for or against it in
+/// the Track Record dimension. Either list replaces its default.
+///
+/// ```yaml
+/// feedback_lexicon:
+///   positive: ["loved", "make again"]
+///   negative: ["bland", "not great"]
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeedbackLexicon {
+    #[serde(default = "default_positive_cues")]
+    pub positive: Vec<String>,
+    #[serde(default = "default_negative_cues")]
+    pub negative: Vec<String>,
+}
+
+fn default_positive_cues() -> Vec<String> {
+    [
+        "loved", "love", "favorite", "favourite", "delicious", "great", "hit", "yum", "make again",
+        "seconds", "cleaned their plate",
+    ]
+    .map(String::from)
+    .to_vec()
+}
+
+fn default_negative_cues() -> Vec<String> {
+    [
+        "bland", "dry", "soggy", "salty", "meh", "skip", "never again", "too much work", "not great",
+        "not good", "didn't like", "didn't love", "nobody ate", "wouldn't eat",
+    ]
+    .map(String::from)
+    .to_vec()
+}
+
+impl Default for FeedbackLexicon {
+    fn default() -> Self {
+        Self {
+            positive: default_positive_cues(),
+            negative: default_negative_cues(),
+        }
+    }
+}
+
+/// The lexicon entries found in a recipe's feedback.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FeedbackCues<'a> {
+    pub positive: Vec<&'a str>,
+    pub negative: Vec<&'a str>,
+}
+
+impl FeedbackLexicon {
+    /// Cues found in each feedback note, as whole words and ignoring case.
+    ///
+    /// A positive cue inside a matched negative phrase doesn't count, so
+    /// "didn't love it" is only negative.
+    pub fn cues<'a>(&'a self, feedback: &[String]) -> FeedbackCues<'a> {
+        let mut cues = FeedbackCues::default();
+        for note in feedback {
+            let note = note.to_lowercase().replace('\u{2019}', "'");
+            let negative: Vec<&str> = self
+                .negative
+                .iter()
+                .map(String::as_str)
+                .filter(|cue| contains_word(&note, &cue.to_lowercase()))
+                .collect();
+            let positive = self.positive.iter().map(String::as_str).filter(|cue| {
+                let cue = cue.to_lowercase();
+                contains_word(&note, &cue) && !negative.iter().any(|n| contains_word(&n.to_lowercase(), &cue))
+            });
+            cues.positive.extend(positive);
+            cues.negative.extend(negative);
+        }
+        cues
+    }
+}
+
 /// Recipe source configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RecipeSource {
@@ -82,6 +270,16 @@ pub struct ScoringConfig {
     pub untapped: Vec<UntappedOpportunity>,
     #[serde(default)]
     pub sources: Vec<RecipeSource>,
+    /// How the store sells each item, keyed by `IngredientMapping::model_item`.
+    #[serde(default)]
+    pub package_sizes: HashMap<String, PackageSize>,
+    #[serde(default)]
+    pub recency: RecencyConfig,
+    #[serde(default)]
+    pub feedback_lexicon: FeedbackLexicon,
+    /// Dimensions to leave out of scoring entirely, by weight key.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disabled_dimensions: Vec<String>,
 }
 
 impl ScoringConfig {
@@ -93,8 +291,36 @@ impl ScoringConfig {
 
     /// Parse from a YAML string.
     pub fn from_yaml(yaml: &str) -> GroceryResult<Self> {
-        serde_yaml::from_str(yaml)
-            .map_err(|e| GroceryError::ScoringConfigParse(e.to_string()))
+        serde_yaml::from_str(yaml).map_err(|e| GroceryError::ScoringConfigParse {
+            location: yaml_location(&e),
+            message: e.to_string(),
+        })
+    }
+
+    /// Whether a dimension is scored, i.e. not in `disabled_dimensions`.
+    pub fn is_enabled(&self, key: &str) -> bool {
+        !self.disabled_dimensions.iter().any(|d| d == key)
+    }
+
+    /// The weight a dimension is scored with.
+    ///
+    /// Enabled dimensions in [`DEFAULT_WEIGHTS`] that the config leaves out
+    /// get their default, and the written weights are scaled down by the
+    /// same total so everything still sums to 1.0. Write `recency: 0` to
+    /// turn a defaulted dimension off.
+    pub fn weight(&self, key: &str) -> f64 {
+        let filled = |k: &str| !self.weights.contains(k) && self.is_enabled(k);
+        if let Some((_, default)) = DEFAULT_WEIGHTS.iter().find(|(k, _)| *k == key) {
+            if filled(key) {
+                return *default;
+            }
+        }
+        let defaulted: f64 = DEFAULT_WEIGHTS
+            .iter()
+            .filter(|(k, _)| filled(k))
+            .map(|(_, default)| default)
+            .sum();
+        self.weights.get(key) * (1.0 - defaulted)
     }
 
     /// Look up the protein score (0-100) for a protein key.
@@ -129,6 +355,37 @@ impl ScoringConfig {
         }
         None
     }
+
+    /// The `ingredient_map` entry whose alias appears in a parsed
+    /// ingredient name, as `(key, mapping)`. The longest alias wins, so
+    /// "english cucumber" beats "cucumber"; ties go to the first key.
+    pub fn mapping_for(&self, name: &str) -> Option<(&str, &IngredientMapping)> {
+        let lower = name.to_lowercase();
+        self.ingredient_map
+            .iter()
+            .filter_map(|(key, mapping)| {
+                let longest = mapping
+                    .aliases
+                    .iter()
+                    .map(|a| a.to_lowercase())
+                    .filter(|a| lower.contains(a.as_str()))
+                    .map(|a| a.len())
+                    .max()?;
+                Some((longest, key.as_str(), mapping))
+            })
+            .max_by(|a, b| a.0.cmp(&b.0).then_with(|| b.1.cmp(a.1)))
+            .map(|(_, key, mapping)| (key, mapping))
+    }
+
+    /// Look up the package size for a canonical model item (case-insensitive).
+    pub fn package_size(&self, model_item: &str) -> Option<&PackageSize> {
+        self.package_sizes.get(model_item).or_else(|| {
+            self.package_sizes
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(model_item))
+                .map(|(_, size)| size)
+        })
+    }
 }
 
 #[cfg(test)]
@@ -18,11 +18,7 @@ fn test_parse_scoring_config_from_real_yaml() -> TestResult {
     let config = ScoringConfig::from_file(&path)?;
 
     // Weights should sum to ~1.0
-    let sum = config.weights.ingredient_overlap
-        + config.weights.protein_alignment
-        + config.weights.cuisine_affinity
-        + config.weights.practical_friction
-        + config.weights.family_fit;
+    let sum = config.weights.total();
     assert!((sum - 1.0).abs() < 0.01, "weights sum to {sum}, expected 1.0");
 
     // Protein scores should include ground_chicken at 100
@@ -48,8 +44,8 @@ weights:
   family_fit: 0.10
 "#;
     let config = ScoringConfig::from_yaml(yaml)?;
-    assert!((config.weights.ingredient_overlap - 0.40).abs() < f64::EPSILON);
-    assert!((config.weights.protein_alignment - 0.20).abs() < f64::EPSILON);
+    assert!((config.weights.get("ingredient_overlap") - 0.40).abs() < f64::EPSILON);
+    assert!((config.weights.get("protein_alignment") - 0.20).abs() < f64::EPSILON);
     Ok(())
 }
 
@@ -117,3 +113,152 @@ ingredient_map:
     assert_eq!(config.ingredient_tier("truffle oil"), None);
     Ok(())
 }
+
+#[test]
+fn test_package_size_lookup() -> TestResult {
+    let yaml = r#"
+weights:
+  ingredient_overlap: 0.40
+  protein_alignment: 0.20
+  cuisine_affinity: 0.15
+  practical_friction: 0.15
+  family_fit: 0.10
+package_sizes:
+  "Ground Chicken":
+    amount: 1
+    unit: lb
+    label: "1 lb pack"
+"#;
+    let config = ScoringConfig::from_yaml(yaml)?;
+    let pkg = config.package_size("ground chicken").ok_or("missing package size")?;
+    assert_eq!(pkg.label.as_deref(), Some("1 lb pack"));
+    assert!(config.package_size("Tofu").is_none());
+    Ok(())
+}
+
+#[test]
+fn test_mapping_for_and_perishables() -> TestResult {
+    let yaml = r#"
+weights:
+  ingredient_overlap: 0.40
+  protein_alignment: 0.20
+  cuisine_affinity: 0.15
+  practical_friction: 0.15
+  family_fit: 0.10
+ingredient_map:
+  cucumber:
+    tier: 1
+    aliases: ["cucumber"]
+  english_cucumber:
+    tier: 1
+    aliases: ["english cucumber"]
+  green_onions:
+    tier: 1
+    aliases: ["green onion", "scallion"]
+  garlic:
+    tier: 3
+    aliases: ["garlic"]
+  sour_cream:
+    tier: 2
+    aliases: ["sour cream"]
+  fish_sauce:
+    tier: 2
+    aliases: ["fish sauce"]
+  kale:
+    tier: 2
+    aliases: ["kale"]
+    perishable: false
+"#;
+    let config = ScoringConfig::from_yaml(yaml)?;
+    let key = |name: &str| config.mapping_for(name).map(|(key, _)| key);
+    assert_eq!(key("English cucumbers"), Some("english_cucumber"));
+    assert_eq!(key("scallions"), Some("green_onions"));
+    assert_eq!(key("truffle oil"), None);
+
+    let perishable = |name: &str| -> Result<bool, Box<dyn std::error::Error>> {
+        let mapping = config.ingredient_map.get(name).ok_or("missing mapping")?;
+        Ok(mapping.is_perishable(name))
+    };
+    assert!(perishable("green_onions")?);
+    assert!(perishable("sour_cream")?);
+    assert!(!perishable("garlic")?);
+    assert!(!perishable("fish_sauce")?);
+    assert!(!perishable("kale")?);
+    Ok(())
+}
+
+#[test]
+fn test_recency_config_defaults_and_overrides() -> TestResult {
+    let yaml = r#"
+weights:
+  ingredient_overlap: 0.40
+  protein_alignment: 0.20
+  cuisine_affinity: 0.15
+  practical_friction: 0.15
+  family_fit: 0.10
+"#;
+    let config = ScoringConfig::from_yaml(yaml)?;
+    assert!(config.weights.get("recency").abs() < f64::EPSILON);
+    assert_eq!(config.recency, RecencyConfig::default());
+    // Unwritten, recency and track_record take their default shares from the others
+    assert!((config.weight("recency") - 0.10).abs() < f64::EPSILON);
+    assert!((config.weight("track_record") - 0.10).abs() < f64::EPSILON);
+    assert!((config.weight("ingredient_overlap") - 0.32).abs() < 1e-9);
+
+    let config = ScoringConfig::from_yaml(&format!(
+        "{yaml}  recency: 0.10\nrecency:\n  window_days: 14\n  revive_after_days: 90\n"
+    ))?;
+    assert!((config.weights.get("recency") - 0.10).abs() < f64::EPSILON);
+    assert!((config.weight("ingredient_overlap") - 0.36).abs() < 1e-9);
+    assert_eq!(config.recency.window_days, 14);
+    assert_eq!(config.recency.revive_after_days, Some(90));
+    assert_eq!(config.recency.revive_min_rating, 4);
+    Ok(())
+}
+
+#[test]
+fn test_written_defaulted_keys_keep_weights_exact() -> TestResult {
+    let yaml = r#"
+weights:
+  ingredient_overlap: 0.30
+  protein_alignment: 0.20
+  cuisine_affinity: 0.15
+  practical_friction: 0.15
+  family_fit: 0.05
+  recency: 0.10
+  track_record: 0.05
+"#;
+    let config = ScoringConfig::from_yaml(yaml)?;
+    for (key, weight) in config.weights.named() {
+        assert_eq!(config.weight(key), weight, "{key}");
+    }
+    Ok(())
+}
+
+#[test]
+fn test_feedback_lexicon_cues() -> TestResult {
+    let lexicon = FeedbackLexicon::default();
+    let feedback = vec![
+        "Loved it, make again!".to_string(),
+        "A bit dry; the kids didn\u{2019}t love the sauce".to_string(),
+    ];
+    let cues = lexicon.cues(&feedback);
+    assert_eq!(cues.positive, vec!["loved", "make again"]);
+    assert_eq!(cues.negative, vec!["dry", "didn't love"]);
+
+    let yaml = r#"
+weights:
+  ingredient_overlap: 0.40
+  protein_alignment: 0.20
+  cuisine_affinity: 0.15
+  practical_friction: 0.15
+  family_fit: 0.10
+feedback_lexicon:
+  negative: ["too sweet"]
+"#;
+    let config = ScoringConfig::from_yaml(yaml)?;
+    assert_eq!(config.feedback_lexicon.positive, FeedbackLexicon::default().positive);
+    let cues = config.feedback_lexicon.cues(&["Too sweet and bland".to_string()]);
+    assert_eq!(cues.negative, vec!["too sweet"]);
+    Ok(())
+}
@@ -0,0 +1,259 @@
+use std::fmt;
+
+use crate::categorize::contains_word;
+use crate::diet::{spice_level, SpiceLevel};
+use crate::household::FamilyMember;
+use crate::recipe::Recipe;
+
+/// Points lost per step of heat beyond what a diner tolerates.
+pub const SPICE_STEP_POINTS: f64 = -15.0;
+const CHOKING_POINTS: f64 = -20.0;
+const TEXTURE_POINTS: f64 = -10.0;
+const UNSAFE_POINTS: f64 = -40.0;
+
+/// Eating stage, derived from a member's age.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AgeStage {
+    /// Under 1.
+    Infant,
+    /// 1 to 3.
+    Toddler,
+    /// 4 to 12.
+    SchoolAge,
+    /// 13 and up, or age unknown.
+    Adult,
+}
+
+impl AgeStage {
+    pub fn from_age(age: Option<u8>) -> Self {
+        match age {
+            Some(0) => Self::Infant,
+            Some(1..=3) => Self::Toddler,
+            Some(4..=12) => Self::SchoolAge,
+            _ => Self::Adult,
+        }
+    }
+
+    /// Heat this stage handles when the member doesn't set a tolerance.
+    pub fn spice_tolerance(self) -> SpiceLevel {
+        match self {
+            Self::Infant => SpiceLevel::None,
+            Self::Toddler => SpiceLevel::Mild,
+            Self::SchoolAge => SpiceLevel::Medium,
+            Self::Adult => SpiceLevel::Hot,
+        }
+    }
+
+    /// Share of an adult serving someone at this stage eats.
+    pub fn portion(self) -> f64 {
+        match self {
+            Self::Infant => 0.25,
+            Self::Toddler => 0.5,
+            Self::SchoolAge => 0.75,
+            Self::Adult => 1.0,
+        }
+    }
+
+    /// Whether whole nuts, grapes and tough textures are a concern.
+    pub fn is_little(self) -> bool {
+        self <= Self::Toddler
+    }
+}
+
+impl fmt::Display for AgeStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Infant => "infant",
+            Self::Toddler => "toddler",
+            Self::SchoolAge => "school-age",
+            Self::Adult => "adult",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FamilyMember {
+    pub fn stage(&self) -> AgeStage {
+        AgeStage::from_age(self.age)
+    }
+
+    /// The member's own spice tolerance, or their stage's default.
+    pub fn effective_spice_tolerance(&self) -> SpiceLevel {
+        self.spice_tolerance.unwrap_or_else(|| self.stage().spice_tolerance())
+    }
+}
+
+/// Choking hazards for infants and toddlers, with how to serve them safely.
+const CHOKING_HAZARDS: &[(&str, &str)] = &[
+    ("almond", "chop finely or leave theirs out"),
+    ("cashew", "chop finely or leave theirs out"),
+    ("cherry tomato", "quarter them"),
+    ("grape", "quarter lengthwise"),
+    ("grape tomato", "quarter them"),
+    ("hazelnut", "chop finely or leave theirs out"),
+    ("hot dog", "cut into thin strips, not coins"),
+    ("macadamia", "chop finely or leave theirs out"),
+    ("marshmallow", "leave theirs out"),
+    ("olive", "pit and quarter"),
+    ("peanut", "chop finely or leave theirs out"),
+    ("pecan", "chop finely or leave theirs out"),
+    ("pine nut", "chop finely or leave theirs out"),
+    ("pistachio", "chop finely or leave theirs out"),
+    ("popcorn", "leave theirs out"),
+    ("sausage", "cut into thin strips, not coins"),
+    ("walnut", "chop finely or leave theirs out"),
+];
+
+/// Ingredient forms that aren't a choking risk ("peanut butter", "olive oil").
+const CHOKING_EXCEPTIONS: &[&str] = &[
+    "butter", "flour", "milk", "oil", "sauce", "paste", "ground", "crumbled", "juice", "jelly",
+    "vinegar", "extract",
+];
+
+/// Textures that are hard for small children, with how to adapt them.
+const HARD_TEXTURES: &[(&str, &str)] = &[
+    ("charred", "serve theirs soft"),
+    ("chewy", "slice thin or shred"),
+    ("crispy", "serve theirs soft"),
+    ("crunchy", "serve theirs soft"),
+    ("jerky", "leave theirs out"),
+    ("rare", "cook theirs through"),
+    ("raw", "cook or grate theirs"),
+    ("skewer", "slide theirs off the skewer"),
+    ("steak", "slice thin against the grain"),
+];
+
+/// What makes a recipe hard for a particular diner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConcernKind {
+    /// Hotter than the diner tolerates.
+    Spice { heat: SpiceLevel, tolerance: SpiceLevel },
+    Choking,
+    Texture,
+    /// Not safe at this stage at all (honey under 1).
+    Unsafe,
+}
+
+/// One problem a recipe poses for a diner's stage.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StageConcern {
+    pub kind: ConcernKind,
+    pub stage: AgeStage,
+    /// The ingredient line (or "spicy" tag) that raised it.
+    pub ingredient: String,
+    /// How to serve it to them anyway, if there's a way.
+    pub adaptation: Option<String>,
+    pub points: f64,
+}
+
+impl fmt::Display for StageConcern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ConcernKind::Spice { heat, tolerance } => {
+                write!(f, "too spicy ({heat} heat, tolerates {tolerance})")?
+            }
+            ConcernKind::Choking => write!(f, "choking hazard for {}", with_article(self.stage))?,
+            ConcernKind::Texture => write!(f, "hard to eat for {}", with_article(self.stage))?,
+            ConcernKind::Unsafe => write!(f, "not safe for {}", with_article(self.stage))?,
+        }
+        write!(f, ": {:?}", self.ingredient)
+    }
+}
+
+fn with_article(stage: AgeStage) -> String {
+    match stage {
+        AgeStage::Infant => format!("an {stage}"),
+        _ => format!("a {stage}"),
+    }
+}
+
+/// How well a recipe suits one diner's stage.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suitability {
+    pub stage: AgeStage,
+    /// 100 less the concerns' points, floored at 0.
+    pub score: f64,
+    pub concerns: Vec<StageConcern>,
+}
+
+impl Suitability {
+    /// Suitability for a member, using their age and spice tolerance.
+    pub fn for_member(recipe: &Recipe, member: &FamilyMember) -> Self {
+        Self::for_stage(recipe, member.stage(), member.effective_spice_tolerance())
+    }
+
+    pub fn for_stage(recipe: &Recipe, stage: AgeStage, tolerance: SpiceLevel) -> Self {
+        let mut concerns = Vec::new();
+
+        let (heat, source) = spice_level(recipe);
+        if heat > tolerance {
+            // A "spicy" tag doesn't say where the heat comes from
+            let adaptation = source
+                .as_ref()
+                .filter(|s| recipe.ingredients.contains(s))
+                .map(|_| "set theirs aside before adding it".to_string());
+            concerns.push(StageConcern {
+                kind: ConcernKind::Spice { heat, tolerance },
+                stage,
+                ingredient: source.unwrap_or_else(|| "spicy".to_string()),
+                adaptation,
+                points: SPICE_STEP_POINTS * f64::from(heat.steps() - tolerance.steps()),
+            });
+        }
+
+        if stage.is_little() {
+            for line in &recipe.ingredients {
+                let lower = line.to_lowercase();
+                if stage == AgeStage::Infant && contains_word(&lower, "honey") {
+                    concerns.push(StageConcern {
+                        kind: ConcernKind::Unsafe,
+                        stage,
+                        ingredient: line.clone(),
+                        adaptation: None,
+                        points: UNSAFE_POINTS,
+                    });
+                }
+                let choking = !CHOKING_EXCEPTIONS.iter().any(|e| contains_word(&lower, e));
+                if let Some((_, fix)) = CHOKING_HAZARDS
+                    .iter()
+                    .filter(|_| choking)
+                    .filter(|(word, _)| contains_word(&lower, word))
+                    .max_by_key(|(word, _)| word.len())
+                {
+                    concerns.push(StageConcern {
+                        kind: ConcernKind::Choking,
+                        stage,
+                        ingredient: line.clone(),
+                        adaptation: Some((*fix).to_string()),
+                        points: CHOKING_POINTS,
+                    });
+                }
+                if let Some((_, fix)) = HARD_TEXTURES.iter().find(|(word, _)| contains_word(&lower, word)) {
+                    concerns.push(StageConcern {
+                        kind: ConcernKind::Texture,
+                        stage,
+                        ingredient: line.clone(),
+                        adaptation: Some((*fix).to_string()),
+                        points: TEXTURE_POINTS,
+                    });
+                }
+            }
+        }
+
+        let lost: f64 = concerns.iter().map(|c| c.points).sum();
+        Self {
+            stage,
+            score: (100.0 + lost).clamp(0.0, 100.0),
+            concerns,
+        }
+    }
+
+    /// Has concerns, but every one of them can be worked around.
+    pub fn is_adaptable(&self) -> bool {
+        !self.concerns.is_empty() && self.concerns.iter().all(|c| c.adaptation.is_some())
+    }
+}
+
+#[cfg(test)]
+#[path = "stage_tests.rs"]
+mod tests;
@@ -0,0 +1,158 @@
+use super::*;
+use crate::diet::Diners;
+use crate::household::HouseholdModel;
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+fn recipe(ingredients: &[&str]) -> Recipe {
+    Recipe {
+        name: "Test Dish".to_string(),
+        url: None,
+        tags: vec![],
+        rating: None,
+        needs_fixing: false,
+        last_made: None,
+        times_made: 0,
+        feedback: vec![],
+        source: None,
+        ingredients: ingredients.iter().map(|i| i.to_string()).collect(),
+        cook_time: None,
+        servings: None,
+        primary_protein: None,
+        extra: Default::default(),
+    }
+}
+
+#[test]
+fn test_stages_from_age() -> TestResult {
+    assert_eq!(AgeStage::from_age(Some(0)), AgeStage::Infant);
+    assert_eq!(AgeStage::from_age(Some(2)), AgeStage::Toddler);
+    assert_eq!(AgeStage::from_age(Some(4)), AgeStage::SchoolAge);
+    assert_eq!(AgeStage::from_age(Some(12)), AgeStage::SchoolAge);
+    assert_eq!(AgeStage::from_age(Some(13)), AgeStage::Adult);
+    assert_eq!(AgeStage::from_age(None), AgeStage::Adult);
+    Ok(())
+}
+
+#[test]
+fn test_toddler_concerns_are_adaptable() -> TestResult {
+    let dish = recipe(&[
+        "1 cup red grapes",
+        "1/2 cup chopped walnuts",
+        "2 Tbsp peanut butter",
+        "1 pint cherry tomatoes",
+        "2 Tbsp olive oil",
+        "1 tsp sriracha",
+    ]);
+    let fit = Suitability::for_stage(&dish, AgeStage::Toddler, AgeStage::Toddler.spice_tolerance());
+
+    let rendered: Vec<String> = fit.concerns.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        rendered,
+        vec![
+            "too spicy (medium heat, tolerates mild): \"1 tsp sriracha\"",
+            "choking hazard for a toddler: \"1 cup red grapes\"",
+            "choking hazard for a toddler: \"1/2 cup chopped walnuts\"",
+            "choking hazard for a toddler: \"1 pint cherry tomatoes\"",
+        ]
+    );
+    assert_eq!(fit.concerns[3].adaptation.as_deref(), Some("quarter them"));
+    assert!(fit.is_adaptable());
+    // 100 - 15 - 3 × 20
+    assert!((fit.score - 25.0).abs() < f64::EPSILON);
+    Ok(())
+}
+
+#[test]
+fn test_older_stages_skip_texture_and_choking() -> TestResult {
+    let dish = recipe(&["1 lb flank steak", "1 cup grapes", "1 tsp chili powder"]);
+    let school = Suitability::for_stage(&dish, AgeStage::SchoolAge, AgeStage::SchoolAge.spice_tolerance());
+    assert!(school.concerns.is_empty());
+    assert!(!school.is_adaptable());
+
+    let toddler = Suitability::for_stage(&dish, AgeStage::Toddler, SpiceLevel::Mild);
+    let kinds: Vec<ConcernKind> = toddler.concerns.iter().map(|c| c.kind).collect();
+    assert_eq!(kinds, vec![ConcernKind::Texture, ConcernKind::Choking]);
+    Ok(())
+}
+
+#[test]
+fn test_honey_is_not_adaptable_for_infants() -> TestResult {
+    let dish = recipe(&["2 Tbsp honey", "1 sweet potato"]);
+    let fit = Suitability::for_stage(&dish, AgeStage::Infant, SpiceLevel::None);
+    assert_eq!(fit.concerns.len(), 1);
+    assert_eq!(fit.concerns[0].to_string(), "not safe for an infant: \"2 Tbsp honey\"");
+    assert!(!fit.is_adaptable());
+    Ok(())
+}
+
+#[test]
+fn test_member_tolerance_overrides_stage_default() -> TestResult {
+    let yaml = r#"
+family:
+  members:
+    - name: Son
+      age: 3
+      spice_tolerance: medium
+    - name: Daughter
+      age: 2
+stores: {}
+"#;
+    let model = HouseholdModel::from_yaml(yaml)?;
+    let dish = recipe(&["2 Tbsp gochujang"]);
+    let son = Suitability::for_member(&dish, &model.family.members[0]);
+    let daughter = Suitability::for_member(&dish, &model.family.members[1]);
+    assert!(son.concerns.is_empty());
+    assert_eq!(daughter.concerns.len(), 1);
+    assert_eq!(daughter.stage, AgeStage::Toddler);
+    Ok(())
+}
+
+#[test]
+fn test_family_servings_weigh_portions_by_stage() -> TestResult {
+    let yaml = r#"
+family:
+  members:
+    - name: David
+    - name: Christine
+      age: 38
+    - name: Daughter
+      age: 2
+stores: {}
+"#;
+    let model = HouseholdModel::from_yaml(yaml)?;
+    // 1 + 1 + 0.5, rounded up
+    assert_eq!(model.family.servings(), Some(3.0));
+    let empty = HouseholdModel::from_yaml("family:\n  members: []\nstores: {}")?;
+    assert_eq!(empty.family.servings(), None);
+    Ok(())
+}
+
+#[test]
+fn test_family_servings_skip_members_who_dont_eat() -> TestResult {
+    let yaml = r#"
+family:
+  members:
+    - name: Christine
+      age: 38
+    - name: Baby
+      age: 0
+      eats: false
+      allergens: [peanut]
+    - name: Grandpa
+      eats: false
+stores: {}
+"#;
+    let model = HouseholdModel::from_yaml(yaml)?;
+    assert_eq!(model.family.servings(), Some(1.0));
+    assert_eq!(Diners::everyone(&model.family).members.len(), 1);
+
+    let nobody = HouseholdModel::from_yaml("family:\n  members:\n    - name: Grandpa\n      eats: false\nstores: {}")?;
+    assert_eq!(nobody.family.servings(), None);
+
+    // Written back only when it's off
+    let yaml = serde_yaml::to_string(&model.family)?;
+    assert_eq!(yaml.matches("eats: false").count(), 2, "{yaml}");
+    assert!(!yaml.contains("eats: true"), "{yaml}");
+    Ok(())
+}
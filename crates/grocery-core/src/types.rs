@@ -1,8 +1,11 @@
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// Grocery store identifier.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
+///
+/// Serialized as its snake_case name, or the store's own name for `Other`
+/// (`h_mart` would read back as `Other("h_mart")`, so write "H Mart").
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Store {
     Giant,
     Acme,
@@ -11,9 +14,87 @@ pub enum Store {
     Other(String),
 }
 
+impl std::str::FromStr for Store {
+    type Err = std::convert::Infallible;
+
+    /// Parse a store name as typed on the command line ("giant", "Trader Joe's").
+    /// Unknown names become `Store::Other`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized: String = s
+            .trim()
+            .to_lowercase()
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .collect();
+        Ok(match normalized.as_str() {
+            "giant" => Self::Giant,
+            "acme" => Self::Acme,
+            "amazon" => Self::Amazon,
+            "traderjoes" => Self::TraderJoes,
+            _ => Self::Other(s.trim().to_string()),
+        })
+    }
+}
+
+impl Store {
+    /// The snake_case name used in data files.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Giant => "giant",
+            Self::Acme => "acme",
+            Self::Amazon => "amazon",
+            Self::TraderJoes => "trader_joes",
+            Self::Other(name) => name,
+        }
+    }
+}
+
+impl std::fmt::Display for Store {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Giant => write!(f, "Giant"),
+            Self::Acme => write!(f, "Acme"),
+            Self::Amazon => write!(f, "Amazon"),
+            Self::TraderJoes => write!(f, "Trader Joe's"),
+            Self::Other(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+impl Serialize for Store {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Files written before stores serialized as plain strings stored `Other`
+/// as `{"other": "name"}`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StoreRepr {
+    Name(String),
+    Tagged { other: String },
+}
+
+impl<'de> Deserialize<'de> for Store {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match StoreRepr::deserialize(deserializer)? {
+            StoreRepr::Name(name) => {
+                let Ok(store) = name.parse();
+                Ok(store)
+            }
+            StoreRepr::Tagged { other } => Ok(Self::Other(other)),
+        }
+    }
+}
+
 /// Product category matching Giant's store layout.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
+///
+/// Serialized as its snake_case name; unrecognized names load as
+/// `Other` so `grocery check` can report them instead of failing the load.
+/// An `Other` spelled like a known category is written `{"other": name}`
+/// so it reads back unchanged.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Category {
     Produce,
     Dairy,
@@ -36,7 +117,103 @@ pub enum Category {
     Other(String),
 }
 
-/// Frequency tier based on purchase history (N out of 18 Giant orders).
+impl Category {
+    /// Every named category, in store-layout order.
+    pub const KNOWN: &'static [Category] = &[
+        Self::Produce,
+        Self::Dairy,
+        Self::Meat,
+        Self::Deli,
+        Self::Frozen,
+        Self::Canned,
+        Self::Bread,
+        Self::Pasta,
+        Self::Beverages,
+        Self::Snacks,
+        Self::Condiments,
+        Self::Baking,
+        Self::Breakfast,
+        Self::Baby,
+        Self::Household,
+        Self::Health,
+        Self::Personal,
+        Self::Pet,
+    ];
+
+    /// The snake_case name used in data files.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Produce => "produce",
+            Self::Dairy => "dairy",
+            Self::Meat => "meat",
+            Self::Deli => "deli",
+            Self::Frozen => "frozen",
+            Self::Canned => "canned",
+            Self::Bread => "bread",
+            Self::Pasta => "pasta",
+            Self::Beverages => "beverages",
+            Self::Snacks => "snacks",
+            Self::Condiments => "condiments",
+            Self::Baking => "baking",
+            Self::Breakfast => "breakfast",
+            Self::Baby => "baby",
+            Self::Household => "household",
+            Self::Health => "health",
+            Self::Personal => "personal",
+            Self::Pet => "pet",
+            Self::Other(name) => name,
+        }
+    }
+
+    /// Look up a category by name (case-insensitive); unknown names become `Other`.
+    pub fn from_name(name: &str) -> Self {
+        let lower = name.trim().to_lowercase();
+        Self::KNOWN
+            .iter()
+            .find(|c| c.as_str() == lower)
+            .cloned()
+            .unwrap_or_else(|| Self::Other(name.trim().to_string()))
+    }
+
+    /// Whether this is one of the named categories.
+    pub fn is_known(&self) -> bool {
+        !matches!(self, Self::Other(_))
+    }
+}
+
+impl Serialize for Category {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Other(name) if Self::from_name(name).is_known() => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("other", name)?;
+                map.end()
+            }
+            _ => serializer.serialize_str(self.as_str()),
+        }
+    }
+}
+
+/// Files written before categories serialized as plain strings stored
+/// every `Other` as `{"other": "name"}`; the tagged form always loads as
+/// `Other`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum CategoryRepr {
+    Name(String),
+    Tagged { other: String },
+}
+
+impl<'de> Deserialize<'de> for Category {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match CategoryRepr::deserialize(deserializer)? {
+            CategoryRepr::Name(name) => Ok(Self::from_name(&name)),
+            CategoryRepr::Tagged { other } => Ok(Self::Other(other)),
+        }
+    }
+}
+
+/// Frequency tier based on purchase history (share of Giant orders containing the item).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum FrequencyTier {
     /// Tier 1: 12-18/18 orders — auto-add to every list
@@ -50,12 +227,18 @@ pub enum FrequencyTier {
 }
 
 impl FrequencyTier {
-    /// Classify an item based on how many of 18 orders it appeared in.
-    pub fn from_frequency(appearances: u8, total_orders: u8) -> Self {
+    /// Classify an item by the share of orders it appeared in.
+    ///
+    /// Boundaries are ratios (12/18, 7/18, 3/18) so any window size works.
+    pub fn from_frequency(appearances: u32, total_orders: u32) -> Self {
         if total_orders == 0 {
             return Self::Rare;
         }
-        let ratio = f64::from(appearances) / f64::from(total_orders);
+        Self::from_ratio(f64::from(appearances) / f64::from(total_orders))
+    }
+
+    /// Classify a purchase share or propensity in `0.0..=1.0`.
+    pub fn from_ratio(ratio: f64) -> Self {
         // Boundaries: 12/18, 7/18, 3/18
         if ratio >= 12.0 / 18.0 {
             Self::EveryOrder
@@ -54,6 +54,40 @@ fn test_category_serde_roundtrip() -> TestResult {
     Ok(())
 }
 
+#[test]
+fn test_unknown_category_loads_as_other() -> TestResult {
+    let parsed: Category = serde_json::from_str(r#""Veggies""#)?;
+    assert_eq!(parsed, Category::Other("Veggies".to_string()));
+    assert!(!parsed.is_known());
+    assert_eq!(serde_json::to_string(&parsed)?, r#""Veggies""#);
+
+    assert_eq!(Category::from_name("Produce"), Category::Produce);
+    assert!(Category::KNOWN.iter().all(|c| Category::from_name(c.as_str()) == *c));
+    Ok(())
+}
+
+#[test]
+fn test_tagged_other_category_still_loads() -> TestResult {
+    let parsed: Category = serde_json::from_str(r#"{"other": "Out of Stock Items"}"#)?;
+    assert_eq!(parsed, Category::Other("Out of Stock Items".to_string()));
+    Ok(())
+}
+
+#[test]
+fn test_other_category_named_like_a_known_one_roundtrips() -> TestResult {
+    let other = Category::Other("Produce".to_string());
+    let json = serde_json::to_string(&other)?;
+    assert_eq!(json, r#"{"other":"Produce"}"#);
+    assert_eq!(serde_json::from_str::<Category>(&json)?, other);
+
+    let yaml = serde_yaml::to_string(&other)?;
+    assert_eq!(serde_yaml::from_str::<Category>(&yaml)?, other);
+
+    // Plain names still resolve to the known category
+    assert_eq!(serde_json::from_str::<Category>(r#""Produce""#)?, Category::Produce);
+    Ok(())
+}
+
 #[test]
 fn test_store_serde_roundtrip() -> TestResult {
     let store = Store::Giant;
@@ -62,3 +96,33 @@ fn test_store_serde_roundtrip() -> TestResult {
     assert_eq!(parsed, store);
     Ok(())
 }
+
+#[test]
+fn test_store_names_serialize_as_strings() -> TestResult {
+    assert_eq!(serde_json::to_string(&Store::TraderJoes)?, r#""trader_joes""#);
+    let h_mart = Store::Other("H Mart".to_string());
+    assert_eq!(serde_json::to_string(&h_mart)?, r#""H Mart""#);
+    assert_eq!(serde_json::from_str::<Store>(r#""H Mart""#)?, h_mart);
+    assert_eq!(serde_json::from_str::<Store>(r#"{"other": "H Mart"}"#)?, h_mart);
+    assert_eq!(h_mart.to_string(), "H Mart");
+    assert_eq!(Store::TraderJoes.to_string(), "Trader Joe's");
+    Ok(())
+}
+
+#[test]
+fn test_store_from_str() -> TestResult {
+    assert_eq!("giant".parse::<Store>()?, Store::Giant);
+    assert_eq!("Trader Joe's".parse::<Store>()?, Store::TraderJoes);
+    assert_eq!("trader_joes".parse::<Store>()?, Store::TraderJoes);
+    assert_eq!("H Mart".parse::<Store>()?, Store::Other("H Mart".to_string()));
+    Ok(())
+}
+
+#[test]
+fn test_frequency_tier_uses_ratios_for_any_window() -> TestResult {
+    assert_eq!(FrequencyTier::from_frequency(4, 6), FrequencyTier::EveryOrder);
+    assert_eq!(FrequencyTier::from_frequency(3, 6), FrequencyTier::MostOrders);
+    assert_eq!(FrequencyTier::from_frequency(1, 6), FrequencyTier::Occasional);
+    assert_eq!(FrequencyTier::from_frequency(300, 400), FrequencyTier::EveryOrder);
+    Ok(())
+}
@@ -1,59 +1,128 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 use grocery_core::item::{ItemSource, ShoppingItem};
+use grocery_core::quantity::{Dimension, Quantity, Unit};
+use grocery_core::types::Category;
 
 /// Deduplicate and merge shopping items by canonical name.
 ///
 /// Items with the same name (case-insensitive) are merged:
-/// - Quantity: max of existing and new
+/// - Quantity: compatible quantities are summed with unit conversion
+///   (2 tbsp + ¼ cup = 6 tbsp); incompatible ones (1 cup vs 2 lb) stay as
+///   separate entries, each with a note explaining why
 /// - Source: Staple takes priority over Recipe
+/// - Store: the first one already assigned is kept
 /// - Notes: combined
 ///
-/// Results are sorted by category then name.
+/// Counts (limes, cans, cloves) are then rounded up to whole ones, since
+/// nobody buys half a lime.
+///
+/// Results are sorted by category, in the default store-layout order of
+/// [`Category::KNOWN`] with other categories after it by name, then by name.
+/// Same-name entries that couldn't be combined keep their input order.
 pub fn consolidate(items: Vec<ShoppingItem>) -> Vec<ShoppingItem> {
-    let mut map: HashMap<String, ShoppingItem> = HashMap::new();
+    let mut groups: BTreeMap<String, Vec<ShoppingItem>> = BTreeMap::new();
 
     for item in items {
         let key = item.name.to_lowercase();
+        let group = groups.entry(key).or_default();
+
+        match group
+            .iter_mut()
+            .find(|existing| existing.quantity.is_compatible(&item.quantity))
+        {
+            Some(existing) => merge_into(existing, item),
+            None => group.push(item),
+        }
+    }
 
-        if let Some(existing) = map.get_mut(&key) {
-            // Merge quantities (take the max)
-            if item.quantity > existing.quantity {
-                existing.quantity = item.quantity;
-            }
-
-            // Staple source takes priority
-            if item.source == ItemSource::Staple {
-                existing.source = ItemSource::Staple;
-            }
-
-            // Combine notes
-            if let Some(new_note) = &item.note {
-                match &mut existing.note {
-                    Some(existing_note) => {
-                        existing_note.push_str("; ");
-                        existing_note.push_str(new_note);
-                    }
-                    None => {
-                        existing.note = Some(new_note.clone());
-                    }
-                }
-            }
-        } else {
-            map.insert(key, item);
+    let mut result: Vec<ShoppingItem> = Vec::new();
+    for mut group in groups.into_values() {
+        for item in &mut group {
+            round_up_count(&mut item.quantity);
         }
+        if group.len() > 1 {
+            explain_incompatible(&mut group);
+        }
+        result.extend(group);
     }
 
-    let mut result: Vec<ShoppingItem> = map.into_values().collect();
     result.sort_by(|a, b| {
-        let cat_a = format!("{:?}", a.category);
-        let cat_b = format!("{:?}", b.category);
-        cat_a.cmp(&cat_b).then(a.name.cmp(&b.name))
+        category_rank(&a.category)
+            .cmp(&category_rank(&b.category))
+            .then_with(|| a.name.cmp(&b.name))
     });
 
     result
 }
 
+/// Round a count up to a whole number, ignoring float noise (3.0000001 stays 3).
+fn round_up_count(quantity: &mut Quantity) {
+    if quantity.dimension() == Dimension::Count {
+        quantity.amount = (quantity.amount - 1e-9).ceil().max(0.0);
+    }
+}
+
+fn category_rank(category: &Category) -> (usize, &str) {
+    let known = Category::KNOWN.iter().position(|c| c == category);
+    (known.unwrap_or(Category::KNOWN.len()), category.as_str())
+}
+
+/// Merge `item` into `existing`, which must have a compatible quantity.
+fn merge_into(existing: &mut ShoppingItem, item: ShoppingItem) {
+    if let Some(sum) = existing.quantity.checked_add(&item.quantity) {
+        existing.quantity = sum;
+    }
+
+    // Staple source takes priority
+    if item.source == ItemSource::Staple {
+        existing.source = ItemSource::Staple;
+    }
+
+    if existing.store.is_none() {
+        existing.store = item.store;
+    }
+
+    // Combine notes
+    if let Some(new_note) = &item.note {
+        append_note(existing, new_note);
+    }
+}
+
+/// Note on each entry of a same-name group which other amounts couldn't be combined with it.
+fn explain_incompatible(group: &mut [ShoppingItem]) {
+    let quantities: Vec<Quantity> = group.iter().map(|i| i.quantity.clone()).collect();
+    for (i, item) in group.iter_mut().enumerate() {
+        let mine = measure_kind(&item.quantity);
+        let reasons: Vec<String> = quantities
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| *j != i)
+            .map(|(_, q)| format!("{q} (can't convert {mine} to {})", measure_kind(q)))
+            .collect();
+        let note = format!("kept separate from {}", reasons.join(", "));
+        append_note(item, &note);
+    }
+}
+
+/// What a quantity measures, for explanations: "volume", "weight", "count", or the piece kind.
+fn measure_kind(quantity: &Quantity) -> String {
+    match &quantity.unit {
+        Unit::Piece(name) => name.clone(),
+        _ => quantity.dimension().to_string(),
+    }
+}
+
+fn append_note(item: &mut ShoppingItem, note: &str) {
+    match &mut item.note {
+        Some(existing_note) => {
+            existing_note.push_str("; ");
+            existing_note.push_str(note);
+        }
+        None => item.note = Some(note.to_string()),
+    }
+}
+
 #[cfg(test)]
 #[path = "consolidator_tests.rs"]
 mod tests;
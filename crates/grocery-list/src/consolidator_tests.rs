@@ -1,5 +1,6 @@
 use super::*;
 use grocery_core::item::{ItemSource, ShoppingItem};
+use grocery_core::quantity::{Quantity, Unit};
 use grocery_core::types::Category;
 
 type TestResult = Result<(), Box<dyn std::error::Error>>;
@@ -7,10 +8,12 @@ type TestResult = Result<(), Box<dyn std::error::Error>>;
 fn make_item(name: &str, qty: u32, category: Category, source: ItemSource) -> ShoppingItem {
     ShoppingItem {
         name: name.to_string(),
-        quantity: qty,
+        quantity: Quantity::count(f64::from(qty)),
         category,
         source,
         note: None,
+        packages: None,
+        store: None,
     }
 }
 
@@ -65,7 +68,7 @@ fn test_consolidate_staple_takes_priority() -> TestResult {
 }
 
 #[test]
-fn test_consolidate_takes_max_quantity() -> TestResult {
+fn test_consolidate_sums_count_quantities() -> TestResult {
     let items = vec![
         make_item("Yogurt", 3, Category::Dairy, ItemSource::Staple),
         make_item("Yogurt", 1, Category::Dairy, ItemSource::Recipe("Test".to_string())),
@@ -73,7 +76,7 @@ fn test_consolidate_takes_max_quantity() -> TestResult {
 
     let result = consolidate(items);
     assert_eq!(result.len(), 1);
-    assert_eq!(result[0].quantity, 3);
+    assert_eq!(result[0].quantity, Quantity::count(4.0));
     Ok(())
 }
 
@@ -110,11 +113,94 @@ fn test_consolidate_sorted_by_category_then_name() -> TestResult {
     ];
 
     let result = consolidate(items);
-    // Dairy < Meat < Produce (alphabetical by Debug format)
-    assert_eq!(result[0].category, Category::Dairy);
-    assert_eq!(result[1].category, Category::Meat);
+    // Produce < Dairy < Meat (default store-layout order)
     // Produce items sorted by name
-    assert_eq!(result[2].name, "Apple");
-    assert_eq!(result[3].name, "Banana");
+    assert_eq!(result[0].name, "Apple");
+    assert_eq!(result[1].name, "Banana");
+    assert_eq!(result[2].category, Category::Dairy);
+    assert_eq!(result[3].category, Category::Meat);
+    Ok(())
+}
+
+#[test]
+fn test_consolidate_converts_and_sums_volumes() -> TestResult {
+    let mut tbsp = make_item("Soy Sauce", 1, Category::Condiments, ItemSource::Recipe("A".to_string()));
+    tbsp.quantity = Quantity::new(2.0, Unit::Tablespoon);
+    let mut cup = make_item("soy sauce", 1, Category::Condiments, ItemSource::Recipe("B".to_string()));
+    cup.quantity = Quantity::new(0.25, Unit::Cup);
+
+    let result = consolidate(vec![tbsp, cup]);
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].quantity.unit, Unit::Tablespoon);
+    assert!((result[0].quantity.amount - 6.0).abs() < 1e-6);
+    assert_eq!(result[0].note, None);
+    Ok(())
+}
+
+#[test]
+fn test_consolidate_keeps_incompatible_side_by_side() -> TestResult {
+    let mut lb = make_item("Ground Chicken", 1, Category::Meat, ItemSource::Recipe("A".to_string()));
+    lb.quantity = Quantity::new(1.0, Unit::Pound);
+    let mut oz = make_item("Ground Chicken", 1, Category::Meat, ItemSource::Recipe("B".to_string()));
+    oz.quantity = Quantity::new(8.0, Unit::Ounce);
+    let mut cup = make_item("Ground Chicken", 1, Category::Meat, ItemSource::Recipe("C".to_string()));
+    cup.quantity = Quantity::new(2.0, Unit::Cup);
+
+    let result = consolidate(vec![lb, oz, cup]);
+    assert_eq!(result.len(), 2);
+
+    let weight = result
+        .iter()
+        .find(|i| i.quantity.unit == Unit::Pound)
+        .ok_or("missing weight entry")?;
+    assert!((weight.quantity.amount - 1.5).abs() < 1e-6);
+    let note = weight.note.as_deref().unwrap_or("");
+    assert!(note.contains("kept separate from 2 cup"), "got: {note}");
+    assert!(note.contains("weight to volume"), "got: {note}");
+
+    let volume = result
+        .iter()
+        .find(|i| i.quantity.unit == Unit::Cup)
+        .ok_or("missing volume entry")?;
+    assert!(volume.note.as_deref().unwrap_or("").contains("1.5 lb"));
+    Ok(())
+}
+
+#[test]
+fn test_consolidate_keeps_separate_entries_in_input_order() -> TestResult {
+    let items = || {
+        let mut cups = make_item("Flour", 1, Category::Baking, ItemSource::Recipe("A".to_string()));
+        cups.quantity = Quantity::new(2.0, Unit::Cup);
+        let mut bag = make_item("Flour", 1, Category::Baking, ItemSource::Recipe("B".to_string()));
+        bag.quantity = Quantity::new(1.0, Unit::Piece("bag".to_string()));
+        let sugar = make_item("Sugar", 1, Category::Baking, ItemSource::Staple);
+        vec![sugar, cups, bag]
+    };
+
+    let first: Vec<String> = consolidate(items()).iter().map(|i| format!("{} {}", i.name, i.quantity)).collect();
+    assert_eq!(first, vec!["Flour 2 cup", "Flour 1 bag", "Sugar 1"]);
+    for _ in 0..20 {
+        let again: Vec<String> = consolidate(items()).iter().map(|i| format!("{} {}", i.name, i.quantity)).collect();
+        assert_eq!(again, first);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_consolidate_rounds_counts_up() -> TestResult {
+    let staple = make_item("Limes", 1, Category::Produce, ItemSource::Staple);
+    let mut scaled = make_item("limes", 1, Category::Produce, ItemSource::Recipe("Larb".to_string()));
+    scaled.quantity = Quantity::count(2.5);
+    let mut tofu = make_item("Tofu", 1, Category::Produce, ItemSource::Recipe("Larb".to_string()));
+    tofu.quantity = Quantity::new(0.5, Unit::Piece("block".to_string()));
+    let mut lime_juice = make_item("Lime Juice", 1, Category::Produce, ItemSource::Recipe("Larb".to_string()));
+    lime_juice.quantity = Quantity::new(0.5, Unit::Cup);
+    let mut garlic = make_item("Garlic", 1, Category::Produce, ItemSource::Recipe("Larb".to_string()));
+    garlic.quantity = Quantity::count(0.1 + 0.2 + 2.7);
+
+    let result = consolidate(vec![staple, scaled, tofu, lime_juice, garlic]);
+    let lines: Vec<String> = result.iter().map(|i| format!("{} {}", i.name, i.quantity)).collect();
+    assert_eq!(lines, vec!["Garlic 3", "Lime Juice 0.5 cup", "Limes 4", "Tofu 1 block"]);
+    assert_eq!(result[2].source, ItemSource::Staple);
     Ok(())
 }
@@ -1,19 +1,41 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Write;
 
 use grocery_core::error::GroceryResult;
+use grocery_core::household::Stores;
 use grocery_core::item::{ItemSource, ShoppingItem};
+use grocery_core::layout::{Section, StoreLayout};
+use grocery_core::quantity::Unit;
+use grocery_core::types::Store;
 
 /// A finalized shopping list ready for output.
 #[derive(Debug, Clone)]
 pub struct ShoppingList {
     pub items: Vec<ShoppingItem>,
+    /// Walking order per store; stores without one list categories alphabetically.
+    pub layouts: HashMap<Store, StoreLayout>,
 }
 
 impl ShoppingList {
     /// Create a new shopping list from consolidated items.
     pub fn new(items: Vec<ShoppingItem>) -> Self {
-        Self { items }
+        Self {
+            items,
+            layouts: HashMap::new(),
+        }
+    }
+
+    /// Use the configured layout of every store on the list.
+    pub fn with_layouts(mut self, stores: &Stores) -> Self {
+        for item in &self.items {
+            let Some(store) = &item.store else {
+                continue;
+            };
+            if let Some(layout) = stores.layout(store) {
+                self.layouts.insert(store.clone(), layout.clone());
+            }
+        }
+        self
     }
 
     /// Total number of items.
@@ -36,20 +58,85 @@ impl ShoppingList {
         groups
     }
 
+    /// Group items by store, the store with the most items first.
+    ///
+    /// Items not yet routed to a store are grouped under `None`.
+    pub fn by_store(&self) -> Vec<(Option<&Store>, Vec<&ShoppingItem>)> {
+        let mut groups: Vec<(Option<&Store>, Vec<&ShoppingItem>)> = Vec::new();
+        for item in &self.items {
+            let store = item.store.as_ref();
+            match groups.iter_mut().find(|(s, _)| *s == store) {
+                Some((_, items)) => items.push(item),
+                None => groups.push((store, vec![item])),
+            }
+        }
+        groups.sort_by(|(a, a_items), (b, b_items)| {
+            b_items
+                .len()
+                .cmp(&a_items.len())
+                .then_with(|| store_display(*a).cmp(&store_display(*b)))
+        });
+        groups
+    }
+
+    /// Items grouped into one store's sections, in walking order when the
+    /// store has a layout and alphabetically otherwise.
+    pub fn sections<'a>(
+        &self,
+        store: Option<&Store>,
+        items: &[&'a ShoppingItem],
+    ) -> Vec<(String, Vec<&'a ShoppingItem>)> {
+        let layout = store.and_then(|s| self.layouts.get(s));
+        let mut sections: Vec<(Section, Vec<&ShoppingItem>)> = Vec::new();
+        for &item in items {
+            let section = match layout {
+                Some(layout) => layout.section_for(&item.name, &item.category),
+                None => Section::Category(item.category.clone()),
+            };
+            match sections.iter_mut().find(|(s, _)| *s == section) {
+                Some((_, grouped)) => grouped.push(item),
+                None => sections.push((section, vec![item])),
+            }
+        }
+
+        let mut named: Vec<(Option<usize>, String, Vec<&ShoppingItem>)> = sections
+            .into_iter()
+            .map(|(section, grouped)| {
+                let position = layout.and_then(|l| l.position(&section));
+                (position, section_display(&section), grouped)
+            })
+            .collect();
+        // Sections the layout doesn't mention go last
+        named.sort_by(|(a_pos, a_name, _), (b_pos, b_name, _)| {
+            a_pos
+                .unwrap_or(usize::MAX)
+                .cmp(&b_pos.unwrap_or(usize::MAX))
+                .then_with(|| a_name.cmp(b_name))
+        });
+        named.into_iter().map(|(_, name, grouped)| (name, grouped)).collect()
+    }
+
     /// Format as human-readable text with markdown checkboxes.
+    ///
+    /// A list spanning several stores gets one `# Store` section per store.
     pub fn format_text(&self) -> String {
         let mut out = String::new();
-        let groups = self.by_category();
-
-        for (category, items) in &groups {
-            let _ = writeln!(out, "## {category}");
-            for item in items {
-                let source = source_label(&item.source);
-                let _ = writeln!(out, "- [ ] {} ({}) [{}]", item.name, item.quantity, source);
+        let stores = self.by_store();
+        if stores.len() <= 1 {
+            for (store, items) in stores {
+                write_text_sections(&mut out, self.sections(store, &items));
             }
-            out.push('\n');
+            return out;
         }
 
+        for (store, items) in stores {
+            let count = match items.len() {
+                1 => "1 item".to_string(),
+                n => format!("{n} items"),
+            };
+            let _ = writeln!(out, "# {} ({count})\n", store_display(store));
+            write_text_sections(&mut out, self.sections(store, &items));
+        }
         out
     }
 
@@ -62,27 +149,72 @@ impl ShoppingList {
     /// Format as compact text for iMessage (fits in one message).
     pub fn format_compact(&self) -> String {
         let mut out = format!("Shopping List ({} items)\n", self.items.len());
-        let groups = self.by_category();
-
-        for (category, items) in &groups {
-            let upper = category.to_uppercase();
-            let names: Vec<String> = items
-                .iter()
-                .map(|item| {
-                    if item.quantity > 1 {
-                        format!("{} x{}", item.name, item.quantity)
-                    } else {
-                        item.name.clone()
-                    }
-                })
-                .collect();
-            let _ = writeln!(out, "{upper}: {}", names.join(", "));
+        let stores = self.by_store();
+        let split = stores.len() > 1;
+        for (store, items) in stores {
+            if split {
+                let _ = writeln!(out, "\n{}:", store_display(store).to_uppercase());
+            }
+            write_compact_lines(&mut out, self.sections(store, &items));
         }
-
         out
     }
 }
 
+/// One `## Section` heading of checkbox lines per section.
+fn write_text_sections(out: &mut String, groups: Vec<(String, Vec<&ShoppingItem>)>) {
+    for (section, items) in &groups {
+        let _ = writeln!(out, "## {section}");
+        for item in items {
+            let source = source_label(&item.source);
+            let amount = match &item.packages {
+                Some(plan) if plan.has_leftover() => {
+                    format!("{plan}; need {}, ~{} spare", item.quantity, plan.leftover)
+                }
+                Some(plan) => format!("{plan}; need {}", item.quantity),
+                None => item.quantity.to_string(),
+            };
+            let _ = writeln!(out, "- [ ] {} ({}) [{}]", item.name, amount, source);
+        }
+        out.push('\n');
+    }
+}
+
+/// One `SECTION: a, b, c` line per section.
+fn write_compact_lines(out: &mut String, groups: Vec<(String, Vec<&ShoppingItem>)>) {
+    for (section, items) in &groups {
+        let upper = section.to_uppercase();
+        let names: Vec<String> = items
+            .iter()
+            .map(|item| {
+                if let Some(ref plan) = item.packages {
+                    format!("{} {}", item.name, plan)
+                } else if item.quantity.unit != Unit::Each {
+                    format!("{} {}", item.name, item.quantity)
+                } else if item.quantity.amount > 1.0 {
+                    format!("{} x{}", item.name, item.quantity)
+                } else {
+                    item.name.clone()
+                }
+            })
+            .collect();
+        let _ = writeln!(out, "{upper}: {}", names.join(", "));
+    }
+}
+
+/// Section heading for a store group.
+fn store_display(store: Option<&Store>) -> String {
+    store.map_or_else(|| "Any store".to_string(), Store::to_string)
+}
+
+/// Heading for a section: the category's name, or the aisle as written.
+fn section_display(section: &Section) -> String {
+    match section {
+        Section::Category(category) => category_display(category),
+        Section::Aisle(name) => name.clone(),
+    }
+}
+
 /// Human-readable category name.
 fn category_display(category: &grocery_core::types::Category) -> String {
     use grocery_core::types::Category;
@@ -1,16 +1,21 @@
 use super::*;
 use grocery_core::item::{ItemSource, ShoppingItem};
-use grocery_core::types::Category;
+use grocery_core::layout::StoreLayout;
+use grocery_core::package::PackageSize;
+use grocery_core::quantity::{Quantity, Unit};
+use grocery_core::types::{Category, Store};
 
 type TestResult = Result<(), Box<dyn std::error::Error>>;
 
 fn make_item(name: &str, qty: u32, category: Category, source: ItemSource) -> ShoppingItem {
     ShoppingItem {
         name: name.to_string(),
-        quantity: qty,
+        quantity: Quantity::count(f64::from(qty)),
         category,
         source,
         note: None,
+        packages: None,
+        store: None,
     }
 }
 
@@ -128,6 +133,17 @@ fn test_format_compact_quantity_notation() -> TestResult {
     Ok(())
 }
 
+#[test]
+fn test_format_shows_measured_units() -> TestResult {
+    let mut soy = make_item("Soy Sauce", 1, Category::Condiments, ItemSource::Recipe("A".to_string()));
+    soy.quantity = Quantity::new(6.0, Unit::Tablespoon);
+    let list = ShoppingList::new(vec![soy]);
+
+    assert!(list.format_text().contains("Soy Sauce (6 tbsp)"));
+    assert!(list.format_compact().contains("Soy Sauce 6 tbsp"));
+    Ok(())
+}
+
 #[test]
 fn test_format_empty_list() -> TestResult {
     let list = ShoppingList::new(vec![]);
@@ -141,3 +157,125 @@ fn test_format_empty_list() -> TestResult {
     assert_eq!(json.trim(), "[]");
     Ok(())
 }
+
+#[test]
+fn test_format_shows_packages_and_leftover() -> TestResult {
+    let mut chicken = make_item("Ground Chicken", 1, Category::Meat, ItemSource::Recipe("A".to_string()));
+    chicken.quantity = Quantity::new(1.5, Unit::Pound);
+    let pack = PackageSize {
+        size: Quantity::new(1.0, Unit::Pound),
+        label: Some("1 lb pack".to_string()),
+    };
+    chicken.packages = pack.plan_for(&chicken.quantity);
+    let list = ShoppingList::new(vec![chicken]);
+
+    let text = list.format_text();
+    assert!(text.contains("Ground Chicken (2 × 1 lb pack; need 1.5 lb, ~0.5 lb spare)"), "got: {text}");
+    assert!(list.format_compact().contains("Ground Chicken 2 × 1 lb pack"));
+    Ok(())
+}
+
+#[test]
+fn test_format_text_splits_by_store() -> TestResult {
+    let mut list = sample_list();
+    for item in &mut list.items {
+        item.store = Some(Store::Giant);
+    }
+    let mut gochujang = make_item("Gochujang", 1, Category::Condiments, ItemSource::Recipe("Bulgogi Burgers".to_string()));
+    gochujang.store = Some(Store::Other("H Mart".to_string()));
+    list.items.push(gochujang);
+
+    let stores: Vec<(String, usize)> = list
+        .by_store()
+        .iter()
+        .map(|(store, items)| (store.map(Store::to_string).unwrap_or_default(), items.len()))
+        .collect();
+    assert_eq!(stores, vec![("Giant".to_string(), 5), ("H Mart".to_string(), 1)]);
+
+    let text = list.format_text();
+    let giant = text.find("# Giant (5 items)").ok_or("missing Giant section")?;
+    let h_mart = text.find("# H Mart (1 item)").ok_or("missing H Mart section")?;
+    assert!(giant < h_mart);
+    assert!(text[h_mart..].contains("## Condiments\n- [ ] Gochujang (1) [Bulgogi Burgers]"));
+    assert!(!text[h_mart..].contains("Cilantro"));
+
+    let compact = list.format_compact();
+    assert!(compact.contains("\nH MART:\nCONDIMENTS: Gochujang\n"), "{compact}");
+    Ok(())
+}
+
+#[test]
+fn test_single_store_list_has_no_store_headings() -> TestResult {
+    let mut list = sample_list();
+    for item in &mut list.items {
+        item.store = Some(Store::Giant);
+    }
+    assert!(!list.format_text().contains("# Giant"));
+    assert!(list.format_text().starts_with("## Dairy"));
+    Ok(())
+}
+
+#[test]
+fn test_layout_lists_sections_in_walking_order() -> TestResult {
+    let mut list = sample_list();
+    let mut gochujang = make_item("Gochujang", 1, Category::Condiments, ItemSource::Recipe("Bulgogi Burgers".to_string()));
+    gochujang.store = Some(Store::Giant);
+    list.items.push(gochujang);
+    list.items.push(make_item("Tortillas", 1, Category::Bread, ItemSource::Staple));
+    for item in &mut list.items {
+        item.store = Some(Store::Giant);
+    }
+
+    let household = grocery_core::household::HouseholdModel::from_yaml(
+        r#"
+family:
+  members: []
+stores:
+  giant:
+    type: grocery
+    layout:
+      order: [meat, produce, Aisle 7 International, deli, dairy]
+      items:
+        gochujang: Aisle 7 International
+        Tortillas: deli
+"#,
+    )?;
+    let list = list.with_layouts(&household.stores);
+
+    let text = list.format_text();
+    let headings: Vec<&str> = text.lines().filter(|l| l.starts_with("## ")).collect();
+    assert_eq!(
+        headings,
+        vec!["## Meat", "## Produce", "## Aisle 7 International", "## Deli", "## Dairy"]
+    );
+    assert!(text.contains("## Deli\n- [ ] Tortillas (1) [staple]"));
+
+    let compact = list.format_compact();
+    let lines: Vec<&str> = compact.lines().skip(1).collect();
+    assert_eq!(lines[0], "MEAT: Ground Chicken");
+    assert_eq!(lines[2], "AISLE 7 INTERNATIONAL: Gochujang");
+    Ok(())
+}
+
+#[test]
+fn test_unlisted_sections_follow_the_layout() -> TestResult {
+    let mut list = sample_list();
+    for item in &mut list.items {
+        item.store = Some(Store::Giant);
+    }
+    list.layouts.insert(
+        Store::Giant,
+        StoreLayout {
+            order: vec!["meat".to_string()],
+            ..StoreLayout::default()
+        },
+    );
+    let sections: Vec<String> = list
+        .by_store()
+        .iter()
+        .flat_map(|(store, items)| list.sections(*store, items))
+        .map(|(name, _)| name)
+        .collect();
+    assert_eq!(sections, vec!["Meat", "Dairy", "Produce"]);
+    Ok(())
+}
@@ -1,162 +1,125 @@
+use chrono::NaiveDate;
+use grocery_core::categorize::resolve_category;
 use grocery_core::household::HouseholdModel;
+use grocery_core::ingredient::parse_ingredient;
 use grocery_core::item::{ItemSource, ShoppingItem};
+use grocery_core::quantity::Quantity;
 use grocery_core::recipe::Recipe;
+use grocery_core::replenish::Replenishment;
 use grocery_core::scoring::ScoringConfig;
-use grocery_core::types::Category;
 
-/// Generate a raw (not yet deduplicated) shopping list from recipes and household staples.
+/// Generate a raw (not yet deduplicated) shopping list from recipes and,
+/// with `include_staples`, household staples.
+///
+/// With `servings`, each recipe's quantities are scaled from what it serves
+/// to that many; recipes that don't say how many they serve are left as is.
+/// The household's categories apply to recipe items either way.
 pub fn generate_list(
     recipes: &[&Recipe],
     household: &HouseholdModel,
     config: &ScoringConfig,
+    servings: Option<f64>,
+    include_staples: bool,
 ) -> Vec<ShoppingItem> {
     let mut items = Vec::new();
 
-    // 1. Collect staples from household model (tier 1 Giant items)
-    for staple in household.staples() {
-        items.push(ShoppingItem {
-            name: staple.item.clone(),
-            quantity: 1,
-            category: staple.category.clone(),
-            source: ItemSource::Staple,
-            note: None,
-        });
+    // 1. Collect staples from household model (tier 1 items at any store)
+    if include_staples {
+        for staple in household.staples() {
+            items.push(ShoppingItem {
+                name: staple.item.clone(),
+                quantity: Quantity::count(1.0),
+                category: staple.category.clone(),
+                source: ItemSource::Staple,
+                note: None,
+                packages: None,
+                store: Some(staple.store.clone()),
+            });
+        }
     }
 
     // 2. Collect recipe ingredients
     for recipe in recipes {
+        let factor = servings.and_then(|target| recipe.scale_factor(target)).unwrap_or(1.0);
         for ingredient in &recipe.ingredients {
-            items.push(resolve_ingredient(ingredient, &recipe.name, config));
+            items.push(resolve_ingredient(ingredient, &recipe.name, factor, config, household));
         }
     }
 
     items
 }
 
+/// Shopping items for cycle items that run out within `lookahead_days` of `today`.
+pub fn replenishment_items(
+    predictions: &[Replenishment],
+    today: NaiveDate,
+    lookahead_days: i64,
+) -> Vec<ShoppingItem> {
+    predictions
+        .iter()
+        .filter(|p| p.is_due(today, lookahead_days))
+        .map(|p| {
+            let days = p.days_until_due(today);
+            let when = match days {
+                d if d < 0 => format!("overdue since {}", p.due.format("%b %-d")),
+                0 => "due today".to_string(),
+                _ => format!("due {}", p.due.format("%b %-d")),
+            };
+            ShoppingItem {
+                name: p.item.clone(),
+                quantity: Quantity::count(1.0),
+                category: p.category.clone(),
+                source: ItemSource::FrequencyTrigger,
+                note: Some(format!("{}: {when} ({})", p.store, p.describe())),
+                packages: None,
+                store: Some(p.store.clone()),
+            }
+        })
+        .collect()
+}
+
 /// Resolve a recipe ingredient string to a ShoppingItem.
 ///
-/// Uses the scoring config's ingredient_map to find canonical names via substring
-/// alias matching. Falls back to cleaning the raw ingredient string.
+/// Parses the line into quantity, unit and name, then uses the scoring config's
+/// ingredient_map to find canonical names via substring alias matching on the
+/// parsed name. Falls back to the parsed name itself. The category comes from
+/// [`resolve_category`]. Quantities are multiplied by `factor`.
 fn resolve_ingredient(
     ingredient: &str,
     recipe_name: &str,
+    factor: f64,
     config: &ScoringConfig,
+    household: &HouseholdModel,
 ) -> ShoppingItem {
-    let lower = ingredient.to_lowercase();
-
-    // Try to match against ingredient_map aliases (substring match)
-    let mut best_match: Option<(&str, &grocery_core::scoring::IngredientMapping)> = None;
-    let mut best_alias_len = 0;
-
-    for (key, mapping) in &config.ingredient_map {
-        for alias in &mapping.aliases {
-            let alias_lower = alias.to_lowercase();
-            if lower.contains(&alias_lower) && alias_lower.len() > best_alias_len {
-                best_match = Some((key.as_str(), mapping));
-                best_alias_len = alias_lower.len();
-            }
-        }
-    }
-
-    if let Some((_key, mapping)) = best_match {
-        let name = mapping
-            .model_item
-            .clone()
-            .unwrap_or_else(|| clean_ingredient_name(ingredient));
-
-        ShoppingItem {
-            name,
-            quantity: 1,
-            category: Category::Other("mapped".to_string()),
-            source: ItemSource::Recipe(recipe_name.to_string()),
-            note: None,
-        }
-    } else {
-        ShoppingItem {
-            name: clean_ingredient_name(ingredient),
-            quantity: 1,
-            category: Category::Other("unknown".to_string()),
-            source: ItemSource::Recipe(recipe_name.to_string()),
-            note: None,
-        }
+    let parsed = parse_ingredient(ingredient).scaled(factor);
+    let best_match = config.mapping_for(&parsed.name).map(|(_, mapping)| mapping);
+
+    let quantity = parsed.as_quantity().unwrap_or_else(|| Quantity::count(1.0));
+    let category = resolve_category(&parsed.name, best_match, household);
+    let name = best_match
+        .and_then(|mapping| mapping.model_item.clone())
+        .unwrap_or(parsed.name);
+
+    ShoppingItem {
+        name,
+        quantity,
+        category,
+        source: ItemSource::Recipe(recipe_name.to_string()),
+        note: None,
+        packages: None,
+        store: None,
     }
 }
 
-/// Strip leading quantity and unit text from a raw ingredient string.
+/// Strip leading quantity, unit and trailing prep text from a raw ingredient string.
 ///
 /// Examples:
 /// - "2 Tbsp. extra-virgin olive oil" → "extra-virgin olive oil"
 /// - "1/2 cup coconut milk" → "coconut milk"
-/// - "8 garlic cloves, thinly sliced" → "garlic cloves, thinly sliced"
+/// - "8 garlic cloves, thinly sliced" → "garlic"
 /// - "salt and pepper" → "salt and pepper"
 pub fn clean_ingredient_name(raw: &str) -> String {
-    let trimmed = raw.trim();
-    if trimmed.is_empty() {
-        return String::new();
-    }
-
-    let chars: Vec<char> = trimmed.chars().collect();
-    let mut pos = 0;
-
-    // Skip leading digits, fractions, decimals
-    while pos < chars.len()
-        && (chars[pos].is_ascii_digit() || chars[pos] == '/' || chars[pos] == '.')
-    {
-        pos += 1;
-    }
-
-    // If we consumed digits, skip whitespace after them
-    if pos > 0 {
-        while pos < chars.len() && chars[pos] == ' ' {
-            pos += 1;
-        }
-
-        // Check if next word looks like a unit — skip it too
-        let rest = &trimmed[pos..];
-        let unit_end = skip_unit(rest);
-        if unit_end > 0 {
-            pos += unit_end;
-            // Skip whitespace after unit
-            while pos < chars.len() && chars[pos] == ' ' {
-                pos += 1;
-            }
-        }
-    }
-
-    let result = trimmed[pos..].to_string();
-    if result.is_empty() {
-        trimmed.to_string()
-    } else {
-        result
-    }
-}
-
-/// If the string starts with a common cooking unit, return how many bytes to skip.
-fn skip_unit(s: &str) -> usize {
-    let units = [
-        "tablespoons", "tablespoon", "teaspoons", "teaspoon",
-        "tbsp.", "tbsp", "tsp.", "tsp",
-        "cups", "cup", "ounces", "ounce", "oz.",
-        "pounds", "pound", "lbs.", "lbs", "lb.",
-        "inches", "inch", "\"",
-    ];
-
-    let lower = s.to_lowercase();
-    for unit in &units {
-        if lower.starts_with(unit) {
-            // Make sure the unit is followed by a space or end of string
-            let after = &s[unit.len()..];
-            if after.is_empty() || after.starts_with(' ') || after.starts_with('.') {
-                // Include trailing period if present
-                if after.starts_with('.') {
-                    return unit.len() + 1;
-                }
-                return unit.len();
-            }
-        }
-    }
-
-    0
+    parse_ingredient(raw).name
 }
 
 #[cfg(test)]
@@ -1,7 +1,9 @@
 use super::*;
 use grocery_core::household::HouseholdModel;
 use grocery_core::item::ItemSource;
+use grocery_core::quantity::Unit;
 use grocery_core::scoring::ScoringConfig;
+use grocery_core::types::Category;
 
 type TestResult = Result<(), Box<dyn std::error::Error>>;
 
@@ -69,7 +71,7 @@ fn test_generate_staples_only() -> TestResult {
     let config = test_config()?;
     let recipes: Vec<&Recipe> = vec![];
 
-    let items = generate_list(&recipes, &household, &config);
+    let items = generate_list(&recipes, &household, &config, None, true);
 
     // Only tier 1 (EveryOrder) items: cucumber (14/18), cilantro (13/18), ground chicken (14/18)
     // "Rare Item" (1/18) should NOT be included
@@ -95,7 +97,7 @@ stores: {}
     }"#)?;
     let recipes = vec![&recipe];
 
-    let items = generate_list(&recipes, &household, &config);
+    let items = generate_list(&recipes, &household, &config, None, true);
 
     assert_eq!(items.len(), 3);
     assert!(items.iter().all(|i| matches!(&i.source, ItemSource::Recipe(name) if name == "Test Dish")));
@@ -114,7 +116,7 @@ fn test_generate_recipes_and_staples() -> TestResult {
     }"#)?;
     let recipes = vec![&recipe];
 
-    let items = generate_list(&recipes, &household, &config);
+    let items = generate_list(&recipes, &household, &config, None, true);
 
     // 3 staples + 2 recipe ingredients = 5 (not yet deduped)
     assert_eq!(items.len(), 5);
@@ -141,7 +143,7 @@ stores: {}
     let config = test_config()?;
     let recipes: Vec<&Recipe> = vec![];
 
-    let items = generate_list(&recipes, &household, &config);
+    let items = generate_list(&recipes, &household, &config, None, true);
     assert!(items.is_empty());
     Ok(())
 }
@@ -149,7 +151,8 @@ stores: {}
 #[test]
 fn test_resolve_ingredient_known() -> TestResult {
     let config = test_config()?;
-    let item = resolve_ingredient("4 garlic cloves, minced", "Test", &config);
+    let household = test_household()?;
+    let item = resolve_ingredient("4 garlic cloves, minced", "Test", 1.0, &config, &household);
     assert_eq!(item.name, "Garlic");
     assert!(matches!(item.source, ItemSource::Recipe(ref name) if name == "Test"));
     Ok(())
@@ -158,7 +161,8 @@ fn test_resolve_ingredient_known() -> TestResult {
 #[test]
 fn test_resolve_ingredient_unknown() -> TestResult {
     let config = test_config()?;
-    let item = resolve_ingredient("2 Tbsp. fish sauce", "Test", &config);
+    let household = test_household()?;
+    let item = resolve_ingredient("2 Tbsp. fish sauce", "Test", 1.0, &config, &household);
     // Should fall back to cleaned raw string
     assert_eq!(item.name, "fish sauce");
     Ok(())
@@ -167,18 +171,80 @@ fn test_resolve_ingredient_unknown() -> TestResult {
 #[test]
 fn test_resolve_ingredient_uses_model_item_name() -> TestResult {
     let config = test_config()?;
-    let item = resolve_ingredient("fresh cilantro", "Test", &config);
+    let household = test_household()?;
+    let item = resolve_ingredient("fresh cilantro", "Test", 1.0, &config, &household);
     assert_eq!(item.name, "Cilantro Fresh");
     Ok(())
 }
 
+#[test]
+fn test_resolve_ingredient_carries_parsed_quantity_and_unit() -> TestResult {
+    let config = test_config()?;
+    let household = test_household()?;
+    let item = resolve_ingredient("1 1/2 cups fish sauce", "Test", 1.0, &config, &household);
+    assert_eq!(item.name, "fish sauce");
+    assert_eq!(item.quantity, Quantity::new(1.5, Unit::Cup));
+
+    let item = resolve_ingredient("2–3 garlic cloves, minced", "Test", 1.0, &config, &household);
+    assert_eq!(item.name, "Garlic");
+    assert_eq!(item.quantity, Quantity::new(3.0, Unit::Piece("clove".to_string())));
+
+    let item = resolve_ingredient("fresh cilantro", "Test", 1.0, &config, &household);
+    assert_eq!(item.quantity, Quantity::count(1.0));
+    Ok(())
+}
+
+#[test]
+fn test_resolve_ingredient_ignores_prep_text_for_matching() -> TestResult {
+    let config = test_config()?;
+    let household = test_household()?;
+    // "cilantro" only appears in the prep note, so this must not map to Cilantro Fresh.
+    let item = resolve_ingredient("1 cup rice, for serving with cilantro", "Test", 1.0, &config, &household);
+    assert_eq!(item.name, "rice");
+    Ok(())
+}
+
+// ── replenishment_items ─────────────────────────────────────────
+
+#[test]
+fn test_replenishment_items_only_include_due() -> TestResult {
+    let household = HouseholdModel::from_yaml(
+        r#"
+family:
+  members: []
+stores: {}
+amazon_recurring:
+  - item: "Pampers Diapers Size 4"
+    category: baby
+    cycle_days: "30 days"
+    last_seen: "2025-08-28"
+  - item: "Dog Food"
+    category: pet
+    cycle_days: "60 days"
+    last_seen: "2025-08-28"
+"#,
+    )?;
+    let predictions = grocery_core::replenish::predict_replenishments(&household, None);
+    let today = chrono::NaiveDate::from_ymd_opt(2025, 9, 30).ok_or("bad date")?;
+
+    let items = replenishment_items(&predictions, today, 7);
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].name, "Pampers Diapers Size 4");
+    assert_eq!(items[0].source, ItemSource::FrequencyTrigger);
+    assert_eq!(
+        items[0].note.as_deref(),
+        Some("Amazon: overdue since Sep 27 (every 30 days, last bought Aug 28)")
+    );
+    Ok(())
+}
+
 // ── clean_ingredient_name ───────────────────────────────────────
 
 #[test]
 fn test_clean_strips_quantity_and_unit() -> TestResult {
     assert_eq!(clean_ingredient_name("2 Tbsp. olive oil"), "olive oil");
     assert_eq!(clean_ingredient_name("1/2 cup coconut milk"), "coconut milk");
-    assert_eq!(clean_ingredient_name("8 garlic cloves"), "garlic cloves");
+    assert_eq!(clean_ingredient_name("8 garlic cloves"), "garlic");
     assert_eq!(clean_ingredient_name("1 lemon"), "lemon");
     Ok(())
 }
@@ -196,3 +262,125 @@ fn test_clean_empty_string() -> TestResult {
     assert_eq!(clean_ingredient_name("  "), "");
     Ok(())
 }
+
+#[test]
+fn test_clean_strips_trailing_prep() -> TestResult {
+    assert_eq!(clean_ingredient_name("8 garlic cloves, thinly sliced"), "garlic");
+    assert_eq!(clean_ingredient_name("1 (14-oz.) can coconut milk"), "coconut milk");
+    Ok(())
+}
+
+#[test]
+fn test_resolve_ingredient_gets_a_real_category() -> TestResult {
+    let config = test_config()?;
+    let household = test_household()?;
+
+    // From the recurring item the mapping points at
+    let item = resolve_ingredient("1 lb ground chicken", "Test", 1.0, &config, &household);
+    assert_eq!(item.category, Category::Meat);
+    // Mapped, but the model item isn't recurring: keyword lexicon
+    let item = resolve_ingredient("4 garlic cloves", "Test", 1.0, &config, &household);
+    assert_eq!(item.category, Category::Produce);
+    // Unmapped
+    let item = resolve_ingredient("2 Tbsp. fish sauce", "Test", 1.0, &config, &household);
+    assert_eq!(item.category, Category::Condiments);
+    let item = resolve_ingredient("1 can coconut milk", "Test", 1.0, &config, &household);
+    assert_eq!(item.category, Category::Canned);
+    let item = resolve_ingredient("1 cup rice, for serving with cilantro", "Test", 1.0, &config, &household);
+    assert_eq!(item.category, Category::Pasta);
+    Ok(())
+}
+
+#[test]
+fn test_generated_items_never_get_placeholder_categories() -> TestResult {
+    let household = test_household()?;
+    let config = test_config()?;
+    let recipe = test_recipe(r#"{
+        "name": "Test Dish",
+        "url": "https://example.com",
+        "ingredients": ["2 english cucumber", "4 garlic cloves", "truffle oil", "1 tsp za'atar"]
+    }"#)?;
+    let items = generate_list(&[&recipe], &household, &config, None, true);
+    assert!(items.iter().all(|i| i.category.is_known()), "{items:?}");
+    Ok(())
+}
+
+#[test]
+fn test_recipes_scale_to_planned_servings() -> TestResult {
+    let household = test_household()?;
+    let config = test_config()?;
+    let recipe = test_recipe(r#"{
+        "name": "Serves Two",
+        "url": "https://example.com",
+        "servings": "Serves 2",
+        "ingredients": ["1 lb ground chicken", "2–3 garlic cloves", "fresh cilantro"]
+    }"#)?;
+    let unknown = test_recipe(r#"{
+        "name": "No Servings",
+        "url": "https://example.com",
+        "ingredients": ["1 can coconut milk"]
+    }"#)?;
+    let items = generate_list(&[&recipe, &unknown], &household, &config, Some(5.0), true);
+    let recipe_items: Vec<String> = items
+        .iter()
+        .filter(|i| matches!(i.source, ItemSource::Recipe(_)))
+        .map(|i| format!("{} {}", i.quantity, i.name))
+        .collect();
+    // 5 servings from a 2-serving recipe is ×2.5 and the top of a range is
+    // what's bought; lines without an amount stay at one
+    assert_eq!(
+        recipe_items,
+        vec!["2.5 lb Ground Chicken", "7.5 clove Garlic", "1 Cilantro Fresh", "1 can coconut milk"]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_garlic_merges_in_either_word_order() -> TestResult {
+    let household = test_household()?;
+    let config = test_config()?;
+    let a = test_recipe(r#"{"name": "A", "ingredients": ["4 garlic cloves"]}"#)?;
+    let b = test_recipe(r#"{"name": "B", "ingredients": ["3 cloves garlic, minced"]}"#)?;
+    let items = crate::consolidate(generate_list(&[&a, &b], &household, &config, None, true));
+    let garlic: Vec<String> = items
+        .iter()
+        .filter(|i| i.name == "Garlic")
+        .map(|i| i.quantity.to_string())
+        .collect();
+    assert_eq!(garlic, vec!["7 clove"]);
+    Ok(())
+}
+
+#[test]
+fn test_staple_plus_scaled_recipe_buys_whole_items() -> TestResult {
+    let household = test_household()?;
+    let config = test_config()?;
+    let recipe = test_recipe(r#"{
+        "name": "Smashed Cucumbers",
+        "servings": "Serves 2",
+        "ingredients": ["1 english cucumber"]
+    }"#)?;
+    let items = crate::consolidate(generate_list(&[&recipe], &household, &config, Some(5.0), true));
+    let cucumber = items
+        .iter()
+        .find(|i| i.name == "English Cucumber")
+        .ok_or("missing cucumber")?;
+    // 1 staple + 2.5 for the recipe
+    assert_eq!(cucumber.quantity, Quantity::count(4.0));
+    Ok(())
+}
+
+#[test]
+fn test_without_staples_recipe_items_keep_household_categories() -> TestResult {
+    let household = test_household()?;
+    let config = test_config()?;
+    let recipe = test_recipe(r#"{"name": "Snack Plate", "ingredients": ["1 rare item"]}"#)?;
+
+    let with = generate_list(&[&recipe], &household, &config, None, true);
+    let without = generate_list(&[&recipe], &household, &config, None, false);
+    assert_eq!(with.len(), 4);
+    assert_eq!(without.len(), 1);
+    assert_eq!(without[0].category, Category::Snacks);
+    assert_eq!(with.last().map(|i| &i.category), Some(&without[0].category));
+    Ok(())
+}
@@ -1,7 +1,11 @@
 pub mod consolidator;
 pub mod formatter;
 pub mod generator;
+pub mod packager;
+pub mod router;
 
 pub use consolidator::consolidate;
 pub use formatter::ShoppingList;
-pub use generator::generate_list;
+pub use generator::{generate_list, replenishment_items};
+pub use packager::apply_package_sizes;
+pub use router::route_to_stores;
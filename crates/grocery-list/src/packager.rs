@@ -0,0 +1,23 @@
+use grocery_core::item::ShoppingItem;
+use grocery_core::scoring::ScoringConfig;
+
+/// Round each consolidated need up to whole store packages.
+///
+/// Package sizes are looked up by item name, which for mapped ingredients is the
+/// canonical `model_item`. Items with no known package size, or whose amount
+/// can't be converted to the package's unit, are left unchanged.
+pub fn apply_package_sizes(items: Vec<ShoppingItem>, config: &ScoringConfig) -> Vec<ShoppingItem> {
+    items
+        .into_iter()
+        .map(|mut item| {
+            if let Some(package) = config.package_size(&item.name) {
+                item.packages = package.plan_for(&item.quantity);
+            }
+            item
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "packager_tests.rs"]
+mod tests;
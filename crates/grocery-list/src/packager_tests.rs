@@ -0,0 +1,80 @@
+use super::*;
+use grocery_core::item::ItemSource;
+use grocery_core::quantity::{Quantity, Unit};
+use grocery_core::types::Category;
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+fn test_config() -> Result<ScoringConfig, Box<dyn std::error::Error>> {
+    let yaml = r#"
+weights:
+  ingredient_overlap: 0.40
+  protein_alignment: 0.20
+  cuisine_affinity: 0.15
+  practical_friction: 0.15
+  family_fit: 0.10
+package_sizes:
+  "Ground Chicken":
+    amount: 1
+    unit: lb
+    label: "1 lb pack"
+  "Limes":
+    amount: 6
+    unit: each
+    label: "bag of 6"
+"#;
+    Ok(ScoringConfig::from_yaml(yaml)?)
+}
+
+fn make_item(name: &str, quantity: Quantity) -> ShoppingItem {
+    ShoppingItem {
+        name: name.to_string(),
+        quantity,
+        category: Category::Other("mapped".to_string()),
+        source: ItemSource::Recipe("Test".to_string()),
+        note: None,
+        packages: None,
+        store: None,
+    }
+}
+
+#[test]
+fn test_apply_package_sizes_rounds_up() -> TestResult {
+    let config = test_config()?;
+    let items = vec![make_item("Ground Chicken", Quantity::new(24.0, Unit::Ounce))];
+
+    let result = apply_package_sizes(items, &config);
+    let plan = result[0].packages.as_ref().ok_or("expected a package plan")?;
+    assert_eq!(plan.count, 2);
+    assert_eq!(plan.to_string(), "2 × 1 lb pack");
+    assert!((plan.leftover.amount - 0.5).abs() < 1e-6);
+    // The underlying need is preserved
+    assert_eq!(result[0].quantity, Quantity::new(24.0, Unit::Ounce));
+    Ok(())
+}
+
+#[test]
+fn test_apply_package_sizes_counts() -> TestResult {
+    let config = test_config()?;
+    let items = vec![make_item("limes", Quantity::count(4.0))];
+
+    let result = apply_package_sizes(items, &config);
+    let plan = result[0].packages.as_ref().ok_or("expected a package plan")?;
+    assert_eq!(plan.count, 1);
+    assert!((plan.leftover.amount - 2.0).abs() < 1e-6);
+    Ok(())
+}
+
+#[test]
+fn test_apply_package_sizes_leaves_unknown_and_incompatible() -> TestResult {
+    let config = test_config()?;
+    let items = vec![
+        make_item("Truffle Oil", Quantity::new(1.0, Unit::Tablespoon)),
+        make_item("Ground Chicken", Quantity::count(1.0)),
+    ];
+
+    let result = apply_package_sizes(items, &config);
+    assert!(result[0].packages.is_none());
+    assert!(result[1].packages.is_none());
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use grocery_core::household::HouseholdModel;
+use grocery_core::item::ShoppingItem;
+
+/// Assign each item the store to buy it at.
+///
+/// Items that already have a store (staples and cycle items carry their
+/// recurring item's store) keep it. Everything else goes through
+/// [`HouseholdModel::route`]: a matching recurring item's store, then the
+/// availability map, then the default store. Routing notes such as
+/// "not at Giant" are appended to the item's note.
+pub fn route_to_stores(items: Vec<ShoppingItem>, household: &HouseholdModel) -> Vec<ShoppingItem> {
+    items
+        .into_iter()
+        .map(|mut item| {
+            if item.store.is_some() {
+                return item;
+            }
+            let route = household.route(&item.name);
+            if let Some(note) = route.note {
+                item.note = Some(match item.note.take() {
+                    Some(existing) => format!("{existing}; {note}"),
+                    None => note,
+                });
+            }
+            item.store = Some(route.store);
+            item
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "router_tests.rs"]
+mod tests;
@@ -0,0 +1,63 @@
+use super::*;
+use grocery_core::item::ItemSource;
+use grocery_core::quantity::Quantity;
+use grocery_core::types::{Category, Store};
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+fn household() -> Result<HouseholdModel, Box<dyn std::error::Error>> {
+    let yaml = r#"
+family:
+  members: []
+stores: {}
+recurring:
+  - item: "Diapers"
+    category: baby
+    store: amazon
+routing:
+  availability:
+    gochujang:
+      not_at: [giant]
+      buy_at: [H Mart, amazon]
+"#;
+    Ok(HouseholdModel::from_yaml(yaml)?)
+}
+
+fn make_item(name: &str, store: Option<Store>) -> ShoppingItem {
+    ShoppingItem {
+        name: name.to_string(),
+        quantity: Quantity::count(1.0),
+        category: Category::Other("unknown".to_string()),
+        source: ItemSource::Recipe("Bulgogi Burgers".to_string()),
+        note: None,
+        packages: None,
+        store,
+    }
+}
+
+#[test]
+fn test_items_are_routed_to_stores() -> TestResult {
+    let items = vec![
+        make_item("ground beef", None),
+        make_item("gochujang", None),
+        make_item("diapers", None),
+    ];
+    let routed = route_to_stores(items, &household()?);
+
+    assert_eq!(routed[0].store, Some(Store::Giant));
+    assert_eq!(routed[0].note, None);
+    assert_eq!(routed[1].store, Some(Store::Other("H Mart".to_string())));
+    assert_eq!(routed[1].note.as_deref(), Some("not at Giant; also at Amazon"));
+    assert_eq!(routed[2].store, Some(Store::Amazon));
+    Ok(())
+}
+
+#[test]
+fn test_assigned_stores_are_kept() -> TestResult {
+    let mut item = make_item("gochujang", Some(Store::TraderJoes));
+    item.note = Some("from the freezer aisle".to_string());
+    let routed = route_to_stores(vec![item], &household()?);
+    assert_eq!(routed[0].store, Some(Store::TraderJoes));
+    assert_eq!(routed[0].note.as_deref(), Some("from the freezer aisle"));
+    Ok(())
+}
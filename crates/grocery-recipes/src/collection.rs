@@ -1,16 +1,23 @@
 use std::path::Path;
 
-use grocery_core::error::GroceryResult;
+use grocery_core::atomic::write_atomic;
+use grocery_core::cooking::CookingEvent;
+use grocery_core::diet::{DietConflict, DietFilter, Diners};
+use grocery_core::error::{GroceryError, GroceryResult};
 use grocery_core::recipe::Recipe;
 use grocery_core::scoring::ScoringConfig;
 
+use crate::dimension::DimensionRegistry;
+use crate::loader::{self, RecordWarning};
 use crate::matcher::{self, DEFAULT_THRESHOLD};
-use crate::scorer::{self, RecipeScore};
+use crate::scorer::{self, RecipeScore, ScoringContext};
 
 /// A collection of recipes loaded from JSON.
 #[derive(Debug, Clone)]
 pub struct RecipeCollection {
     recipes: Vec<Recipe>,
+    schema_version: u32,
+    warnings: Vec<RecordWarning>,
 }
 
 impl RecipeCollection {
@@ -20,10 +27,63 @@ impl RecipeCollection {
         Self::from_json(&content)
     }
 
-    /// Parse from a JSON string.
+    /// Parse from a JSON string in any supported schema version.
+    ///
+    /// Records that needed repair or had to be skipped are listed in
+    /// [`warnings`](Self::warnings).
     pub fn from_json(json: &str) -> GroceryResult<Self> {
-        let recipes: Vec<Recipe> = serde_json::from_str(json)?;
-        Ok(Self { recipes })
+        let loaded = loader::load_recipes(json)?;
+        Ok(Self {
+            recipes: loaded.recipes,
+            schema_version: loaded.version,
+            warnings: loaded.warnings,
+        })
+    }
+
+    /// Serialize in the current schema version.
+    pub fn to_canonical_json(&self) -> GroceryResult<String> {
+        loader::to_canonical_json(&self.recipes)
+    }
+
+    /// Write the collection to `path` in the current schema version,
+    /// atomically replacing the old file.
+    ///
+    /// Refuses if any record was skipped while loading, since rewriting the
+    /// file would delete it.
+    pub fn save(&self, path: &Path) -> GroceryResult<()> {
+        let skipped: Vec<String> = self.skipped().map(ToString::to_string).collect();
+        if !skipped.is_empty() {
+            return Err(GroceryError::RecipeParse(format!(
+                "not rewriting {}: it would drop {} unreadable record(s): {}",
+                path.display(),
+                skipped.len(),
+                skipped.join("; ")
+            )));
+        }
+        write_atomic(path, &self.to_canonical_json()?)
+    }
+
+    /// Fold a cooking event into the recipe at `index`. Returns the updated
+    /// recipe, or `None` if there's no such index.
+    pub fn record_cooked(&mut self, index: usize, event: &CookingEvent) -> Option<&Recipe> {
+        let recipe = self.recipes.get_mut(index)?;
+        recipe.record_cooked(event);
+        Some(recipe)
+    }
+
+    /// Schema version the collection was loaded from.
+    pub fn schema_version(&self) -> u32 {
+        self.schema_version
+    }
+
+    /// Per-record problems found while loading.
+    pub fn warnings(&self) -> &[RecordWarning] {
+        &self.warnings
+    }
+
+    /// Records that couldn't be read at all and aren't in the collection.
+    pub fn skipped(&self) -> impl Iterator<Item = &RecordWarning> {
+        self.warnings.iter().filter(|w| w.skipped)
     }
 
     /// Fuzzy-match recipes by name.
@@ -65,16 +125,45 @@ impl RecipeCollection {
             .collect()
     }
 
-    /// Score every recipe that has ingredients.
+    /// Check every recipe against the people eating the meal.
+    ///
+    /// Returns each kept recipe with its conflicts (empty if none). With
+    /// [`DietFilter::Exclude`], recipes with a hard conflict such as an
+    /// allergen are dropped.
+    pub fn filter_for_diners(
+        &self,
+        diners: &Diners<'_>,
+        mode: DietFilter,
+    ) -> Vec<(&Recipe, Vec<DietConflict>)> {
+        self.recipes
+            .iter()
+            .map(|r| (r, diners.conflicts(r)))
+            .filter(|(_, conflicts)| mode == DietFilter::Flag || !conflicts.iter().any(|c| c.hard))
+            .collect()
+    }
+
+    /// Score every recipe that has ingredients on the built-in dimensions.
     ///
     /// Returns `(index, RecipeScore)` sorted by score descending.
     pub fn score_all(&self, config: &ScoringConfig) -> Vec<(usize, RecipeScore)> {
+        self.score_all_for(config, &scorer::BUILTIN, &ScoringContext::default())
+    }
+
+    /// Like [`score_all`](Self::score_all), on `dimensions`, with Family Fit
+    /// and Recency scored for the people at the table and the day being
+    /// planned.
+    pub fn score_all_for(
+        &self,
+        config: &ScoringConfig,
+        dimensions: &DimensionRegistry,
+        context: &ScoringContext<'_>,
+    ) -> Vec<(usize, RecipeScore)> {
         let mut scored: Vec<(usize, RecipeScore)> = self
             .recipes
             .iter()
             .enumerate()
             .filter(|(_, r)| r.has_ingredients())
-            .map(|(i, r)| (i, scorer::score_recipe(r, config)))
+            .map(|(i, r)| (i, scorer::score_recipe_for(r, config, dimensions, context)))
             .collect();
 
         scored.sort_by(|a, b| {
@@ -93,7 +93,15 @@ fn test_from_json_file_real_data() -> TestResult {
     }
 
     let coll = RecipeCollection::from_json_file(&path)?;
-    assert!(coll.len() >= 90, "expected ~91 recipes, got {}", coll.len());
+    assert!(coll.warnings().is_empty(), "unexpected warnings: {:?}", coll.warnings());
+
+    // Every record in the file loads
+    let raw: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path)?)?;
+    let records = raw
+        .as_array()
+        .or_else(|| raw.get("recipes").and_then(|r| r.as_array()))
+        .ok_or("unexpected recipe-links.json layout")?;
+    assert_eq!(coll.len(), records.len());
     Ok(())
 }
 
@@ -180,3 +188,72 @@ fn test_recipes_accessor() -> TestResult {
     assert_eq!(slice[0].name, "Sambal Noodles");
     Ok(())
 }
+
+#[test]
+fn test_filter_for_diners_excludes_or_flags() -> TestResult {
+    use grocery_core::diet::{DietFilter, Diners};
+    use grocery_core::household::HouseholdModel;
+
+    let collection = RecipeCollection::from_json(small_collection_json())?;
+    let household = HouseholdModel::from_yaml(
+        r#"
+family:
+  members:
+    - name: Christine
+      allergens: [soy]
+    - name: Daughter
+      dislikes: [garlic]
+stores: {}
+"#,
+    )?;
+    let diners = Diners::everyone(&household.family);
+
+    let kept = collection.filter_for_diners(&diners, DietFilter::Exclude);
+    let names: Vec<&str> = kept.iter().map(|(r, _)| r.name.as_str()).collect();
+    assert_eq!(names, vec!["Family Chicken"]);
+
+    let flagged = collection.filter_for_diners(&diners, DietFilter::Flag);
+    assert_eq!(flagged.len(), 3);
+    let tofu = &flagged[1];
+    assert_eq!(tofu.0.name, "Tofu Stir Fry");
+    let reasons: Vec<String> = tofu.1.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        reasons,
+        vec![
+            "Christine avoids soy: \"firm tofu\"",
+            "Daughter dislikes garlic: \"garlic\"",
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_save_keeps_unknown_keys_and_refuses_to_drop_records() -> TestResult {
+    let dir = std::env::temp_dir().join(format!("grocery-collection-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join("recipe-links.json");
+
+    let mut collection = RecipeCollection::from_json(
+        r#"[{"name": "Larb", "photo": "larb.jpg", "notes": {"from": "Aunt May"}}]"#,
+    )?;
+    let event = CookingEvent {
+        recipe: "Larb".to_string(),
+        date: "2024-03-01".parse()?,
+        rating: None,
+        note: None,
+    };
+    collection.record_cooked(0, &event);
+    collection.save(&path)?;
+    let saved = std::fs::read_to_string(&path)?;
+    assert!(saved.contains(r#""photo": "larb.jpg""#), "{saved}");
+    assert!(saved.contains(r#""from": "Aunt May""#), "{saved}");
+    assert!(saved.contains(r#""times_made": 1"#), "{saved}");
+
+    let broken = RecipeCollection::from_json(r#"[{"name": "Larb"}, {"url": "https://x.com"}]"#)?;
+    let err = broken.save(&path).err().ok_or("expected save to refuse")?;
+    assert!(err.to_string().contains("record 1: skipped: missing field `name`"), "{err}");
+    assert_eq!(std::fs::read_to_string(&path)?, saved);
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
@@ -0,0 +1,169 @@
+use std::collections::BTreeSet;
+
+use grocery_core::check;
+use grocery_core::diagnostics::PathSegment::{Index, Key};
+use grocery_core::diagnostics::{locate_yaml, Diagnostic, Location};
+use grocery_core::recipe::Recipe;
+use grocery_core::scoring::{ScoringConfig, DEFAULT_WEIGHTS};
+
+use crate::scorer::{
+    member_fits, score_label, trace_ingredients, CuisineAffinity, DimensionScore, FamilyFit, IngredientOverlap,
+    PracticalFriction, ProteinAlignment, RecipeScore, Recency, ScoringContext, TrackRecord,
+};
+
+/// One way of scoring a recipe, 0-100.
+///
+/// The config weights and disables dimensions by [`key`](Self::key). A
+/// household can score with its own dimensions by adding them to a
+/// [`DimensionRegistry`].
+pub trait ScoringDimension: Send + Sync {
+    /// Name shown in the breakdown, e.g. "Family Fit".
+    fn name(&self) -> &'static str;
+
+    /// Key under `weights` and in `disabled_dimensions`, e.g. "family_fit".
+    fn key(&self) -> &'static str;
+
+    fn compute(&self, recipe: &Recipe, config: &ScoringConfig, context: &ScoringContext<'_>) -> f64;
+
+    /// Lines saying what moved the score, for `grocery score`.
+    fn explain(&self, _recipe: &Recipe, _config: &ScoringConfig, _context: &ScoringContext<'_>) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// The dimensions a recipe is scored on, in breakdown order.
+pub struct DimensionRegistry {
+    dimensions: Vec<Box<dyn ScoringDimension>>,
+}
+
+impl DimensionRegistry {
+    /// A registry with no dimensions.
+    pub fn empty() -> Self {
+        Self { dimensions: Vec::new() }
+    }
+
+    /// The seven built-in dimensions.
+    pub fn builtin() -> Self {
+        let mut registry = Self::empty();
+        registry.register(Box::new(IngredientOverlap));
+        registry.register(Box::new(ProteinAlignment));
+        registry.register(Box::new(CuisineAffinity));
+        registry.register(Box::new(PracticalFriction));
+        registry.register(Box::new(FamilyFit));
+        registry.register(Box::new(Recency));
+        registry.register(Box::new(TrackRecord));
+        registry
+    }
+
+    /// Add a dimension at the end, or replace the one with the same key in
+    /// place.
+    pub fn register(&mut self, dimension: Box<dyn ScoringDimension>) {
+        match self.dimensions.iter_mut().find(|d| d.key() == dimension.key()) {
+            Some(existing) => *existing = dimension,
+            None => self.dimensions.push(dimension),
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&dyn ScoringDimension> {
+        self.dimensions.iter().find(|d| d.key() == key).map(|d| d.as_ref())
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.dimensions.iter().map(|d| d.key())
+    }
+
+    /// Score a recipe on every enabled dimension.
+    ///
+    /// Overall is the weighted sum, clamped to 0-100.
+    pub fn score(&self, recipe: &Recipe, config: &ScoringConfig, context: &ScoringContext<'_>) -> RecipeScore {
+        let dimensions: Vec<DimensionScore> = self
+            .dimensions
+            .iter()
+            .filter(|d| config.is_enabled(d.key()))
+            .map(|d| {
+                let raw_score = d.compute(recipe, config, context);
+                let weight = config.weight(d.key());
+                DimensionScore {
+                    name: d.name(),
+                    key: d.key(),
+                    raw_score,
+                    weight,
+                    weighted: raw_score * weight,
+                }
+            })
+            .collect();
+
+        let overall = dimensions
+            .iter()
+            .map(|d| d.weighted)
+            .sum::<f64>()
+            .clamp(0.0, 100.0);
+
+        RecipeScore {
+            overall,
+            label: score_label(overall),
+            dimensions,
+            members: member_fits(recipe, &context.diners),
+            ingredients: trace_ingredients(&recipe.parsed_ingredients(), config),
+        }
+    }
+
+    /// Validate recipe-scoring-config.yaml: everything
+    /// [`check_scoring_config`](check::check_scoring_config) reports, plus
+    /// weights and disabled dimensions that name no dimension here, and a
+    /// warning for each enabled dimension the weights leave to its default,
+    /// since that default shrinks every weight the config does give.
+    pub fn check_config(&self, yaml: &str) -> Vec<Diagnostic> {
+        let mut diagnostics = check::check_scoring_config(yaml);
+        let Ok(config) = ScoringConfig::from_yaml(yaml) else {
+            return diagnostics;
+        };
+
+        let known: BTreeSet<&str> = self.keys().collect();
+        let expected = known.iter().copied().collect::<Vec<_>>().join(", ");
+        let unknown = |key: &str, location: Location| {
+            Diagnostic::warning(
+                location,
+                format!("no scoring dimension named {key:?} (expected one of: {expected})"),
+            )
+        };
+        for (key, _) in config.weights.named().filter(|(key, _)| !known.contains(key)) {
+            diagnostics.push(unknown(key, locate_yaml(yaml, &[Key("weights"), Key(key)])));
+        }
+        for (i, key) in config.disabled_dimensions.iter().enumerate() {
+            if !known.contains(key.as_str()) {
+                diagnostics.push(unknown(key, locate_yaml(yaml, &[Key("disabled_dimensions"), Index(i)])));
+            }
+        }
+        let defaulted = DEFAULT_WEIGHTS
+            .iter()
+            .filter(|(key, _)| known.contains(key) && !config.weights.contains(key) && config.is_enabled(key));
+        for (key, default) in defaulted {
+            diagnostics.push(Diagnostic::warning(
+                locate_yaml(yaml, &[Key("weights")]),
+                format!(
+                    "no weight for {key:?}, so it gets the default {default:.2} and the other weights are \
+                     scaled down to make room (write `{key}: 0` to turn it off)"
+                ),
+            ));
+        }
+
+        diagnostics.sort_by(|a, b| a.location.cmp(&b.location).then(a.message.cmp(&b.message)));
+        diagnostics
+    }
+}
+
+impl Default for DimensionRegistry {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+/// Validate recipe-scoring-config.yaml against the built-in dimensions.
+pub fn check_scoring_config(yaml: &str) -> Vec<Diagnostic> {
+    DimensionRegistry::builtin().check_config(yaml)
+}
+
+#[cfg(test)]
+#[path = "dimension_tests.rs"]
+mod tests;
@@ -0,0 +1,174 @@
+use super::*;
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+const CONFIG: &str = "\
+weights:
+  ingredient_overlap: 0.40
+  protein_alignment: 0.20
+  cuisine_affinity: 0.15
+  practical_friction: 0.15
+  family_fit: 0.10
+";
+
+fn recipe() -> Result<Recipe, Box<dyn std::error::Error>> {
+    Ok(serde_json::from_str(
+        r#"{"name": "Tacos", "tags": ["weeknight"], "ingredients": ["1 lb ground chicken"], "primary_protein": "chicken"}"#,
+    )?)
+}
+
+/// Full marks for anything tagged "weeknight".
+struct Weeknight;
+
+impl ScoringDimension for Weeknight {
+    fn name(&self) -> &'static str {
+        "Weeknight"
+    }
+
+    fn key(&self) -> &'static str {
+        "weeknight"
+    }
+
+    fn compute(&self, recipe: &Recipe, _config: &ScoringConfig, _context: &ScoringContext<'_>) -> f64 {
+        if recipe.tags.iter().any(|t| t == "weeknight") {
+            100.0
+        } else {
+            0.0
+        }
+    }
+}
+
+#[test]
+fn test_builtin_dimensions_in_breakdown_order() -> TestResult {
+    let registry = DimensionRegistry::builtin();
+    let keys: Vec<&str> = registry.keys().collect();
+    assert_eq!(
+        keys,
+        vec![
+            "ingredient_overlap",
+            "protein_alignment",
+            "cuisine_affinity",
+            "practical_friction",
+            "family_fit",
+            "recency",
+            "track_record",
+        ]
+    );
+    assert_eq!(registry.get("family_fit").map(|d| d.name()), Some("Family Fit"));
+    assert!(registry.get("nope").is_none());
+    Ok(())
+}
+
+#[test]
+fn test_disabled_dimensions_are_left_out() -> TestResult {
+    let config = ScoringConfig::from_yaml(&format!("{CONFIG}disabled_dimensions: [family_fit, recency]\n"))?;
+    let score = DimensionRegistry::builtin().score(&recipe()?, &config, &ScoringContext::default());
+    let keys: Vec<&str> = score.dimensions.iter().map(|d| d.key).collect();
+    assert_eq!(
+        keys,
+        vec!["ingredient_overlap", "protein_alignment", "cuisine_affinity", "practical_friction", "track_record"]
+    );
+    let sum: f64 = score.dimensions.iter().map(|d| d.weighted).sum();
+    assert!((score.overall - sum).abs() < 1e-9);
+    Ok(())
+}
+
+#[test]
+fn test_custom_dimension_is_weighted_by_key() -> TestResult {
+    let mut config = ScoringConfig::from_yaml(CONFIG)?;
+    let recipe = recipe()?;
+    let mut registry = DimensionRegistry::builtin();
+    let before = registry.score(&recipe, &config, &ScoringContext::default()).overall;
+
+    registry.register(Box::new(Weeknight));
+    config.weights.set("weeknight", 0.10);
+    let score = registry.score(&recipe, &config, &ScoringContext::default());
+    let last = score.dimensions.last().ok_or("no dimensions")?;
+    assert_eq!((last.name, last.key), ("Weeknight", "weeknight"));
+    // Written weights make room for the default recency and track_record
+    // shares: 0.10 × 0.8
+    assert!((score.overall - before - 8.0).abs() < 1e-9);
+    Ok(())
+}
+
+#[test]
+fn test_register_replaces_same_key_in_place() -> TestResult {
+    struct Flat;
+    impl ScoringDimension for Flat {
+        fn name(&self) -> &'static str {
+            "Flat Family Fit"
+        }
+        fn key(&self) -> &'static str {
+            "family_fit"
+        }
+        fn compute(&self, _recipe: &Recipe, _config: &ScoringConfig, _context: &ScoringContext<'_>) -> f64 {
+            42.0
+        }
+    }
+
+    let mut registry = DimensionRegistry::builtin();
+    registry.register(Box::new(Flat));
+    assert_eq!(registry.keys().count(), 7);
+    let score = registry.score(&recipe()?, &ScoringConfig::from_yaml(CONFIG)?, &ScoringContext::default());
+    let family = &score.dimensions[4];
+    assert_eq!(family.name, "Flat Family Fit");
+    assert!((family.raw_score - 42.0).abs() < f64::EPSILON);
+    Ok(())
+}
+
+#[test]
+fn test_check_config_flags_unknown_dimensions() -> TestResult {
+    let yaml = format!("{CONFIG}  weeknite: 0.0\n  track_record: 0.0\ndisabled_dimensions:\n  - recency\n  - novelty\n");
+    let lines: Vec<String> = check_scoring_config(&yaml).iter().map(ToString::to_string).collect();
+    let expected = "expected one of: cuisine_affinity, family_fit, ingredient_overlap, practical_friction, \
+                    protein_alignment, recency, track_record";
+    assert_eq!(
+        lines,
+        vec![
+            format!("7:3: warning: no scoring dimension named \"weeknite\" ({expected})"),
+            format!("11:3: warning: no scoring dimension named \"novelty\" ({expected})"),
+        ]
+    );
+
+    let mut registry = DimensionRegistry::builtin();
+    registry.register(Box::new(Weeknight));
+    let yaml = format!("{CONFIG}  weeknight: 0.0\n  recency: 0.0\n  track_record: 0.0\n");
+    assert!(registry.check_config(&yaml).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_check_config_warns_about_defaulted_recency() -> TestResult {
+    let yaml = format!("{CONFIG}  track_record: 0.0\n");
+    let lines: Vec<String> = check_scoring_config(&yaml).iter().map(ToString::to_string).collect();
+    assert_eq!(
+        lines,
+        vec![
+            "1:1: warning: no weight for \"recency\", so it gets the default 0.10 and the other weights are \
+             scaled down to make room (write `recency: 0` to turn it off)"
+                .to_string()
+        ]
+    );
+
+    let yaml = format!("{CONFIG}  track_record: 0.0\ndisabled_dimensions: [recency]\n");
+    assert!(check_scoring_config(&yaml).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_check_config_warns_about_defaulted_track_record() -> TestResult {
+    let yaml = format!("{CONFIG}  recency: 0.0\n");
+    let lines: Vec<String> = check_scoring_config(&yaml).iter().map(ToString::to_string).collect();
+    assert_eq!(
+        lines,
+        vec![
+            "1:1: warning: no weight for \"track_record\", so it gets the default 0.10 and the other weights \
+             are scaled down to make room (write `track_record: 0` to turn it off)"
+                .to_string()
+        ]
+    );
+
+    // Both left out: one warning each
+    assert_eq!(check_scoring_config(CONFIG).len(), 2);
+    Ok(())
+}
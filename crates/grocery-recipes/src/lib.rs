@@ -1,7 +1,18 @@
 pub mod collection;
+pub mod dimension;
+pub mod loader;
 pub mod matcher;
+pub mod planner;
 pub mod scorer;
+pub mod sharing;
 
 pub use collection::RecipeCollection;
+pub use dimension::{check_scoring_config, DimensionRegistry, ScoringDimension};
+pub use loader::{check_recipe_links, load_recipes, LoadedRecipes, RecordWarning, CURRENT_VERSION};
 pub use matcher::{find_recipes_by_name, MatchResult, DEFAULT_THRESHOLD};
-pub use scorer::{score_label, score_recipe, DimensionScore, RecipeScore};
+pub use planner::{plan_meals, MealPlan, PassedOver, PlanConstraints, PlannedMeal};
+pub use scorer::{
+    score_label, score_recipe, score_recipe_for, trace_ingredients, DimensionScore, FitAdjustment, IngredientTrace,
+    MemberFit, RecipeScore, ScoringContext,
+};
+pub use sharing::{perishables, suggest_complements, Sharing, Suggestion};
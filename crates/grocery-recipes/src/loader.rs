@@ -0,0 +1,324 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use grocery_core::diagnostics::{Diagnostic, Location};
+use grocery_core::error::{GroceryError, GroceryResult};
+use grocery_core::recipe::Recipe;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// Schema version written by [`to_canonical_json`].
+///
+/// - v1: a bare JSON array of recipes; ratings may be strings ("5") and
+///   URLs may be null or missing.
+/// - v2: `{"version": 2, "recipes": [...]}` with numeric ratings.
+pub const CURRENT_VERSION: u32 = 2;
+
+/// A recipe record that was repaired or skipped while loading.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordWarning {
+    /// 0-based position in the file's recipe list.
+    pub index: usize,
+    pub name: Option<String>,
+    pub message: String,
+    /// Whether the record was dropped rather than repaired.
+    pub skipped: bool,
+}
+
+impl fmt::Display for RecordWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "record {} ({name:?}): {}", self.index, self.message),
+            None => write!(f, "record {}: {}", self.index, self.message),
+        }
+    }
+}
+
+/// Recipes loaded from recipe-links.json, plus what had to be fixed up.
+#[derive(Debug, Clone, Default)]
+pub struct LoadedRecipes {
+    /// Schema version the file was written in.
+    pub version: u32,
+    pub recipes: Vec<Recipe>,
+    pub warnings: Vec<RecordWarning>,
+}
+
+/// Parse recipe-links.json in any known schema version.
+///
+/// Records are normalized to the current [`Recipe`] shape one at a time; a
+/// record that still can't be read is skipped with a warning rather than
+/// failing the whole load. Only unreadable JSON or an unknown layout or
+/// version is an error.
+pub fn load_recipes(json: &str) -> GroceryResult<LoadedRecipes> {
+    let document: Value = serde_json::from_str(json)?;
+
+    let (version, records) = match document {
+        Value::Array(records) => (1, records),
+        Value::Object(mut object) => {
+            let version = object
+                .get("version")
+                .and_then(Value::as_u64)
+                .ok_or_else(|| GroceryError::RecipeParse("missing numeric \"version\"".to_string()))?;
+            if version == 0 || version > u64::from(CURRENT_VERSION) {
+                return Err(GroceryError::RecipeParse(format!(
+                    "unsupported recipe-links.json version {version} (this build reads up to {CURRENT_VERSION})"
+                )));
+            }
+            let Some(Value::Array(records)) = object.remove("recipes") else {
+                return Err(GroceryError::RecipeParse("missing \"recipes\" array".to_string()));
+            };
+            (version as u32, records)
+        }
+        _ => {
+            return Err(GroceryError::RecipeParse(
+                "expected a recipe array or a {\"version\", \"recipes\"} object".to_string(),
+            ))
+        }
+    };
+
+    let mut loaded = LoadedRecipes {
+        version,
+        ..LoadedRecipes::default()
+    };
+
+    for (index, record) in records.into_iter().enumerate() {
+        let Value::Object(mut fields) = record else {
+            loaded.warnings.push(RecordWarning {
+                index,
+                name: None,
+                message: "skipped: not a JSON object".to_string(),
+                skipped: true,
+            });
+            continue;
+        };
+        let name = fields.get("name").and_then(Value::as_str).map(str::to_string);
+        let mut warn = |message: String| {
+            loaded.warnings.push(RecordWarning {
+                index,
+                name: name.clone(),
+                skipped: message.starts_with("skipped:"),
+                message,
+            });
+        };
+
+        normalize_record(&mut fields, &mut warn);
+
+        match serde_json::from_value::<Recipe>(Value::Object(fields)) {
+            Ok(recipe) => loaded.recipes.push(recipe),
+            Err(e) => warn(format!("skipped: {e}")),
+        }
+    }
+
+    Ok(loaded)
+}
+
+/// Repair known legacy quirks in place, reporting anything lossy.
+fn normalize_record(fields: &mut Map<String, Value>, warn: &mut impl FnMut(String)) {
+    if let Some(rating) = fields.get("rating").cloned() {
+        let normalized = match rating {
+            Value::Null => Value::Null,
+            Value::String(text) if text.trim().is_empty() => Value::Null,
+            Value::String(text) => match text.trim().parse::<f64>() {
+                Ok(n) => rating_value(n, &text, warn),
+                Err(_) => {
+                    warn(format!("dropped unreadable rating {text:?}"));
+                    Value::Null
+                }
+            },
+            Value::Number(n) => match n.as_f64() {
+                Some(f) => rating_value(f, &n.to_string(), warn),
+                None => Value::Null,
+            },
+            other => {
+                warn(format!("dropped unreadable rating {other}"));
+                Value::Null
+            }
+        };
+        fields.insert("rating".to_string(), normalized);
+    }
+
+    if fields
+        .get("url")
+        .and_then(Value::as_str)
+        .is_some_and(|u| u.trim().is_empty())
+    {
+        fields.insert("url".to_string(), Value::Null);
+    }
+
+    // Older exports wrote tags as one comma-separated string
+    if let Some(Value::String(tags)) = fields.get("tags").cloned() {
+        let list = tags
+            .split(',')
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .map(|t| Value::String(t.to_string()))
+            .collect();
+        fields.insert("tags".to_string(), Value::Array(list));
+    }
+}
+
+/// A 0–5 star rating as a JSON integer, rounding half stars.
+fn rating_value(n: f64, raw: &str, warn: &mut impl FnMut(String)) -> Value {
+    if !(0.0..=5.0).contains(&n) {
+        warn(format!("dropped out-of-range rating {raw}"));
+        return Value::Null;
+    }
+    let rounded = n.round();
+    if (rounded - n).abs() > f64::EPSILON {
+        warn(format!("rounded rating {raw} to {rounded}"));
+    }
+    Value::from(rounded as u8)
+}
+
+#[derive(Serialize)]
+struct CanonicalFile<'a> {
+    version: u32,
+    recipes: &'a [Recipe],
+}
+
+/// Serialize recipes in the current schema version.
+pub fn to_canonical_json(recipes: &[Recipe]) -> GroceryResult<String> {
+    let file = CanonicalFile {
+        version: CURRENT_VERSION,
+        recipes,
+    };
+    let mut json = serde_json::to_string_pretty(&file)?;
+    json.push('\n');
+    Ok(json)
+}
+
+/// Validate recipe-links.json, reporting every problem with its location.
+///
+/// Repaired records are warnings, skipped records are errors, and a file
+/// still in an older schema version gets a warning suggesting migration.
+pub fn check_recipe_links(json: &str) -> Vec<Diagnostic> {
+    let loaded = match load_recipes(json) {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            let location = e.location().unwrap_or(Location::START);
+            return vec![Diagnostic::error(location, e.to_string())];
+        }
+    };
+
+    let offsets = record_offsets(json);
+    let record_location = |index: usize| {
+        offsets
+            .get(index)
+            .map_or(Location::START, |&offset| Location::at_offset(json, offset))
+    };
+
+    let mut diagnostics = Vec::new();
+    if loaded.version < CURRENT_VERSION {
+        diagnostics.push(Diagnostic::warning(
+            Location::START,
+            format!(
+                "schema v{} is out of date; run `grocery recipes migrate` to upgrade to v{CURRENT_VERSION}",
+                loaded.version
+            ),
+        ));
+    }
+
+    for warning in &loaded.warnings {
+        let location = record_location(warning.index);
+        let message = warning.to_string();
+        diagnostics.push(if warning.skipped {
+            Diagnostic::error(location, message)
+        } else {
+            Diagnostic::warning(location, message)
+        });
+    }
+
+    // Positions of loaded recipes shift past skipped records, so look names
+    // up in the raw records instead.
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    for (index, &offset) in offsets.iter().enumerate() {
+        let Some(name) = record_name(json, offset) else {
+            continue;
+        };
+        if let Some(first) = seen.insert(name.to_lowercase(), index) {
+            diagnostics.push(Diagnostic::warning(
+                record_location(index),
+                format!("record {index} ({name:?}): duplicate of record {first}"),
+            ));
+        }
+    }
+
+    diagnostics.sort_by(|a, b| a.location.cmp(&b.location).then(a.message.cmp(&b.message)));
+    diagnostics
+}
+
+/// Byte offset where each recipe record starts, in either layout.
+fn record_offsets(json: &str) -> Vec<usize> {
+    let start = skip_whitespace(json, 0);
+    let array = match json.as_bytes().get(start) {
+        Some(b'[') => Some(start),
+        Some(b'{') => member_value_offset(json, start, "recipes"),
+        _ => None,
+    };
+    array.map_or_else(Vec::new, |open| array_element_offsets(json, open))
+}
+
+/// Offsets of the elements of the array opening at `open`.
+fn array_element_offsets(json: &str, open: usize) -> Vec<usize> {
+    let mut offsets = Vec::new();
+    let mut pos = skip_whitespace(json, open + 1);
+    while pos < json.len() && json.as_bytes()[pos] != b']' {
+        offsets.push(pos);
+        let Some(end) = value_end(json, pos) else {
+            break;
+        };
+        pos = skip_whitespace(json, end);
+        if json.as_bytes().get(pos) == Some(&b',') {
+            pos = skip_whitespace(json, pos + 1);
+        }
+    }
+    offsets
+}
+
+/// Offset of the value for `key` in the object opening at `open`.
+fn member_value_offset(json: &str, open: usize, key: &str) -> Option<usize> {
+    let mut pos = skip_whitespace(json, open + 1);
+    while json.as_bytes().get(pos) == Some(&b'"') {
+        let key_end = value_end(json, pos)?;
+        let name: String = serde_json::from_str(json.get(pos..key_end)?).ok()?;
+        pos = skip_whitespace(json, key_end);
+        if json.as_bytes().get(pos) != Some(&b':') {
+            return None;
+        }
+        pos = skip_whitespace(json, pos + 1);
+        if name == key {
+            return Some(pos);
+        }
+        pos = skip_whitespace(json, value_end(json, pos)?);
+        if json.as_bytes().get(pos) == Some(&b',') {
+            pos = skip_whitespace(json, pos + 1);
+        }
+    }
+    None
+}
+
+/// End offset of the JSON value starting at `start`.
+fn value_end(json: &str, start: usize) -> Option<usize> {
+    let rest = json.get(start..)?;
+    let mut stream = serde_json::Deserializer::from_str(rest).into_iter::<Value>();
+    stream.next()?.ok()?;
+    Some(start + stream.byte_offset())
+}
+
+/// The `name` of the record starting at `offset`, if it has one.
+fn record_name(json: &str, offset: usize) -> Option<String> {
+    let end = value_end(json, offset)?;
+    let record: Value = serde_json::from_str(json.get(offset..end)?).ok()?;
+    record.get("name")?.as_str().map(str::to_string)
+}
+
+fn skip_whitespace(json: &str, mut pos: usize) -> usize {
+    while json.as_bytes().get(pos).is_some_and(u8::is_ascii_whitespace) {
+        pos += 1;
+    }
+    pos
+}
+
+#[cfg(test)]
+#[path = "loader_tests.rs"]
+mod tests;
@@ -0,0 +1,122 @@
+use super::*;
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+const LEGACY: &str = r#"[
+    {"name": "Sambal Noodles", "url": "https://example.com", "tags": ["Pork"], "rating": "5", "needs_fixing": false},
+    {"name": "Big Ol Mess", "url": null, "tags": ["Sausage"], "rating": "4", "needs_fixing": false},
+    {"name": "No Url", "tags": "Quick, Tofu", "rating": 3}
+]"#;
+
+#[test]
+fn test_load_legacy_array_with_string_ratings() -> TestResult {
+    let loaded = load_recipes(LEGACY)?;
+    assert_eq!(loaded.version, 1);
+    assert!(loaded.warnings.is_empty(), "{:?}", loaded.warnings);
+    assert_eq!(loaded.recipes.len(), 3);
+    assert_eq!(loaded.recipes[0].rating, Some(5));
+    assert_eq!(loaded.recipes[1].url, None);
+    assert_eq!(loaded.recipes[2].url, None);
+    assert_eq!(loaded.recipes[2].tags, vec!["Quick", "Tofu"]);
+    Ok(())
+}
+
+#[test]
+fn test_bad_records_warn_instead_of_failing() -> TestResult {
+    let json = r#"[
+        {"name": "Good", "rating": "4"},
+        {"name": "Odd Rating", "rating": "five"},
+        {"name": "Half Star", "rating": "3.5"},
+        {"name": "Too Many Stars", "rating": 9},
+        {"name": "Bad Date", "last_made": "someday"},
+        {"rating": 5},
+        "just a string"
+    ]"#;
+    let loaded = load_recipes(json)?;
+
+    let names: Vec<&str> = loaded.recipes.iter().map(|r| r.name.as_str()).collect();
+    assert_eq!(names, vec!["Good", "Odd Rating", "Half Star", "Too Many Stars"]);
+    assert_eq!(loaded.recipes[1].rating, None);
+    assert_eq!(loaded.recipes[2].rating, Some(4));
+    assert_eq!(loaded.recipes[3].rating, None);
+
+    let warnings: Vec<String> = loaded.warnings.iter().map(ToString::to_string).collect();
+    assert_eq!(warnings.len(), 6, "{warnings:#?}");
+    assert_eq!(warnings[0], r#"record 1 ("Odd Rating"): dropped unreadable rating "five""#);
+    assert_eq!(warnings[1], r#"record 2 ("Half Star"): rounded rating 3.5 to 4"#);
+    assert!(warnings[3].contains("invalid last_made \"someday\""));
+    assert!(warnings[4].starts_with("record 5: skipped: missing field `name`"));
+    assert_eq!(warnings[5], "record 6: skipped: not a JSON object");
+    Ok(())
+}
+
+#[test]
+fn test_canonical_round_trip() -> TestResult {
+    let loaded = load_recipes(LEGACY)?;
+    let json = to_canonical_json(&loaded.recipes)?;
+    assert!(json.contains(r#""version": 2"#));
+    assert!(json.contains(r#""rating": 5"#));
+    assert!(!json.contains("null"));
+
+    let reloaded = load_recipes(&json)?;
+    assert_eq!(reloaded.version, CURRENT_VERSION);
+    assert!(reloaded.warnings.is_empty());
+    assert_eq!(reloaded.recipes.len(), 3);
+    assert_eq!(reloaded.recipes[2].tags, vec!["Quick", "Tofu"]);
+    Ok(())
+}
+
+#[test]
+fn test_unknown_layouts_are_errors() -> TestResult {
+    assert!(load_recipes(r#"{"version": 99, "recipes": []}"#).is_err());
+    assert!(load_recipes(r#"{"recipes": []}"#).is_err());
+    assert!(load_recipes(r#"{"version": 2}"#).is_err());
+    assert!(load_recipes(r#""recipes""#).is_err());
+    assert!(load_recipes("not json").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_check_recipe_links_locates_records() -> TestResult {
+    let json = r#"{"version": 2, "recipes": [
+  {"name": "Good", "rating": 4},
+  {"name": "Odd Rating", "rating": "five"},
+  {"rating": 5},
+  {"name": "good"}
+]}
+"#;
+    let lines: Vec<String> = check_recipe_links(json).iter().map(ToString::to_string).collect();
+    assert_eq!(
+        lines,
+        vec![
+            r#"3:3: warning: record 1 ("Odd Rating"): dropped unreadable rating "five""#,
+            "4:3: error: record 2: skipped: missing field `name`",
+            r#"5:3: warning: record 3 ("good"): duplicate of record 0"#,
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_check_recipe_links_flags_old_schema_and_syntax() -> TestResult {
+    let lines: Vec<String> = check_recipe_links(LEGACY).iter().map(ToString::to_string).collect();
+    assert_eq!(lines.len(), 1);
+    assert!(lines[0].starts_with("1:1: warning: schema v1 is out of date"));
+
+    let broken = "[\n  {\"name\": \"A\"},\n  {\"name\": }\n]";
+    let diagnostics = check_recipe_links(broken);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].location.line, 3);
+    Ok(())
+}
+
+#[test]
+fn test_migration_keeps_unknown_keys() -> TestResult {
+    let loaded = load_recipes(r#"[{"name": "Larb", "rating": "5", "photo": "larb.jpg", "servings_note": null}]"#)?;
+    let json = to_canonical_json(&loaded.recipes)?;
+    let reloaded = load_recipes(&json)?;
+    assert_eq!(reloaded.recipes[0].rating, Some(5));
+    assert_eq!(reloaded.recipes[0].extra.get("photo"), Some(&Value::from("larb.jpg")));
+    assert_eq!(reloaded.recipes[0].extra.get("servings_note"), Some(&Value::Null));
+    Ok(())
+}
@@ -0,0 +1,260 @@
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+use grocery_core::diet::Diners;
+use grocery_core::recipe::Recipe;
+use grocery_core::scoring::ScoringConfig;
+
+use crate::collection::RecipeCollection;
+use crate::dimension::DimensionRegistry;
+use crate::scorer::{RecipeScore, ScoringContext};
+
+/// Limits on an automatic meal plan.
+#[derive(Debug, Clone)]
+pub struct PlanConstraints {
+    /// Day of the first meal; each following meal is the next day.
+    pub start: NaiveDate,
+    pub meals: usize,
+    /// Most meals sharing a primary protein.
+    pub max_per_protein: usize,
+    /// Most "Stretch" or "Adventure" picks.
+    pub max_stretch: usize,
+    /// Longest cook time allowed on a weeknight (Sunday to Thursday).
+    pub weeknight_max_minutes: u32,
+    /// Skip recipes made within this many weeks of `start`.
+    pub no_repeat_weeks: u32,
+}
+
+impl PlanConstraints {
+    /// `meals` dinners from `start`, with the default limits.
+    pub fn new(start: NaiveDate, meals: usize) -> Self {
+        Self {
+            start,
+            meals,
+            max_per_protein: 2,
+            max_stretch: 1,
+            weeknight_max_minutes: 45,
+            no_repeat_weeks: 2,
+        }
+    }
+}
+
+/// A recipe the planner picked, and why.
+#[derive(Debug, Clone)]
+pub struct PlannedMeal<'a> {
+    pub date: NaiveDate,
+    pub recipe: &'a Recipe,
+    pub score: RecipeScore,
+    pub reasons: Vec<String>,
+}
+
+/// A recipe that outscored a pick but broke a constraint.
+#[derive(Debug, Clone)]
+pub struct PassedOver<'a> {
+    pub recipe: &'a Recipe,
+    pub overall: f64,
+    pub reason: String,
+}
+
+/// The planner's result: meals in date order, plus the higher-scoring
+/// recipes it had to skip.
+#[derive(Debug, Clone, Default)]
+pub struct MealPlan<'a> {
+    pub meals: Vec<PlannedMeal<'a>>,
+    pub passed_over: Vec<PassedOver<'a>>,
+}
+
+impl<'a> MealPlan<'a> {
+    pub fn recipes(&self) -> Vec<&'a Recipe> {
+        self.meals.iter().map(|m| m.recipe).collect()
+    }
+
+    pub fn total_score(&self) -> f64 {
+        self.meals.iter().map(|m| m.score.overall).sum()
+    }
+}
+
+/// Pick `constraints.meals` recipes that maximise total score on `dimensions`.
+///
+/// Greedy: recipes are taken highest score first and placed on the earliest
+/// open day they fit, quick ones on weeknights before weekends, so long
+/// cooks still find a Friday or Saturday. A recipe is skipped when it needs
+/// fixing, was made too recently, would be a second stretch pick or one
+/// protein too many, or fits no open day: too slow for a weeknight, a hard
+/// diet conflict on that day, or the same protein as the night before or
+/// after.
+pub fn plan_meals<'a>(
+    collection: &'a RecipeCollection,
+    config: &ScoringConfig,
+    dimensions: &DimensionRegistry,
+    diners: &Diners<'_>,
+    constraints: &PlanConstraints,
+) -> MealPlan<'a> {
+    let dates: Vec<NaiveDate> = (0..constraints.meals)
+        .filter_map(|i| constraints.start.checked_add_days(Days::new(i as u64)))
+        .collect();
+    let mut slots: Vec<Option<PlannedMeal<'a>>> = vec![None; dates.len()];
+    let mut passed_over = Vec::new();
+    let repeat_cutoff = constraints
+        .start
+        .checked_sub_days(Days::new(u64::from(constraints.no_repeat_weeks) * 7))
+        .unwrap_or(NaiveDate::MIN);
+
+    let context = ScoringContext::new(diners.clone(), constraints.start);
+    for (index, score) in collection.score_all_for(config, dimensions, &context) {
+        if slots.iter().all(Option::is_some) {
+            break;
+        }
+        let Some(recipe) = collection.recipes().get(index) else {
+            continue;
+        };
+        let mut pass = |reason: String| {
+            passed_over.push(PassedOver {
+                recipe,
+                overall: score.overall,
+                reason,
+            })
+        };
+
+        if recipe.needs_fixing {
+            pass("marked as needing fixes".to_string());
+            continue;
+        }
+        if let Some(made) = recipe.last_made.filter(|d| *d > repeat_cutoff) {
+            pass(format!("made {made}, within {} weeks", constraints.no_repeat_weeks));
+            continue;
+        }
+        let stretches = slots.iter().flatten().filter(|m| is_stretch(&m.score)).count();
+        if is_stretch(&score) && stretches >= constraints.max_stretch {
+            pass(format!("already have {stretches} stretch pick(s)"));
+            continue;
+        }
+        let protein = protein_key(recipe);
+        let same_protein = slots
+            .iter()
+            .flatten()
+            .filter(|m| protein.is_some() && protein_key(m.recipe) == protein)
+            .count();
+        if let Some(p) = protein.as_deref().filter(|_| same_protein >= constraints.max_per_protein) {
+            pass(format!("already have {same_protein} {p} meal(s)"));
+            continue;
+        }
+
+        let minutes = recipe.cook_minutes();
+        let quick = minutes.is_none_or(|m| m <= constraints.weeknight_max_minutes);
+        let mut misfits: Vec<String> = Vec::new();
+        let mut open: Vec<usize> = Vec::new();
+        for (slot, date) in dates.iter().enumerate().filter(|(i, _)| slots[*i].is_none()) {
+            match day_misfit(recipe, protein.as_deref(), *date, slot, &slots, diners, constraints) {
+                Some(reason) if !misfits.contains(&reason) => misfits.push(reason),
+                Some(_) => {}
+                None => open.push(slot),
+            }
+        }
+        // Quick recipes take weeknights first, saving weekends for long cooks
+        let Some(slot) = open.into_iter().min_by_key(|&i| (quick && !is_weeknight(dates[i]), i)) else {
+            pass(misfits.join("; "));
+            continue;
+        };
+
+        let reasons = explain(recipe, &score, dates[slot], minutes, constraints);
+        slots[slot] = Some(PlannedMeal {
+            date: dates[slot],
+            recipe,
+            score,
+            reasons,
+        });
+    }
+
+    MealPlan {
+        meals: slots.into_iter().flatten().collect(),
+        passed_over,
+    }
+}
+
+/// Why a recipe can't go on a given open day, or `None` if it can.
+fn day_misfit(
+    recipe: &Recipe,
+    protein: Option<&str>,
+    date: NaiveDate,
+    slot: usize,
+    slots: &[Option<PlannedMeal<'_>>],
+    diners: &Diners<'_>,
+    constraints: &PlanConstraints,
+) -> Option<String> {
+    if let Some(minutes) = recipe.cook_minutes() {
+        if is_weeknight(date) && minutes > constraints.weeknight_max_minutes {
+            return Some(format!(
+                "takes {minutes} min, over the {}-minute weeknight cap",
+                constraints.weeknight_max_minutes
+            ));
+        }
+    }
+
+    let on_day = Diners {
+        members: diners.members.clone(),
+        day: Some(date.weekday()),
+    };
+    if let Some(conflict) = on_day.conflicts(recipe).into_iter().find(|c| c.hard) {
+        return Some(conflict.to_string());
+    }
+
+    let neighbours = [slot.checked_sub(1), Some(slot + 1)];
+    let repeats = neighbours
+        .into_iter()
+        .flatten()
+        .filter_map(|i| slots.get(i).and_then(Option::as_ref))
+        .any(|m| protein.is_some() && protein_key(m.recipe).as_deref() == protein);
+    if let Some(p) = protein.filter(|_| repeats) {
+        return Some(format!("would put {p} on two nights running"));
+    }
+    None
+}
+
+/// The lines shown under a pick.
+fn explain(
+    recipe: &Recipe,
+    score: &RecipeScore,
+    date: NaiveDate,
+    minutes: Option<u32>,
+    constraints: &PlanConstraints,
+) -> Vec<String> {
+    let mut reasons = vec![format!("{:.0}/100 ({})", score.overall, score.label)];
+    if let Some(best) = score.dimensions.iter().max_by(|a, b| a.raw_score.total_cmp(&b.raw_score)) {
+        reasons.push(format!("strongest on {} ({:.0})", best.name, best.raw_score));
+    }
+    reasons.push(match minutes {
+        Some(m) if is_weeknight(date) => format!("{m} min, fine for a weeknight"),
+        Some(m) if m > constraints.weeknight_max_minutes => {
+            format!("{m} min, so it goes on {}", date.weekday())
+        }
+        Some(m) => format!("{m} min"),
+        None => "cook time unknown".to_string(),
+    });
+    if let Some(protein) = &recipe.primary_protein {
+        reasons.push(format!("protein: {protein}"));
+    }
+    reasons.push(match recipe.last_made {
+        Some(made) => format!("last made {made}"),
+        None => "not made before".to_string(),
+    });
+    reasons
+}
+
+fn is_weeknight(date: NaiveDate) -> bool {
+    !matches!(date.weekday(), Weekday::Fri | Weekday::Sat)
+}
+
+fn is_stretch(score: &RecipeScore) -> bool {
+    matches!(score.label, "Stretch" | "Adventure")
+}
+
+fn protein_key(recipe: &Recipe) -> Option<String> {
+    recipe
+        .primary_protein
+        .as_ref()
+        .map(|p| p.trim().to_lowercase().replace(' ', "_"))
+        .filter(|p| !p.is_empty())
+}
+
+#[cfg(test)]
+#[path = "planner_tests.rs"]
+mod tests;
@@ -0,0 +1,172 @@
+use super::*;
+use crate::dimension::ScoringDimension;
+use grocery_core::household::HouseholdModel;
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+fn test_config() -> Result<ScoringConfig, Box<dyn std::error::Error>> {
+    let yaml = r#"
+weights:
+  ingredient_overlap: 0.50
+  protein_alignment: 0.30
+  cuisine_affinity: 0.0
+  practical_friction: 0.20
+  family_fit: 0.0
+protein_scores:
+  chicken: 100
+  tofu: 90
+  pork: 70
+  beef: 60
+  lamb: 10
+ingredient_map:
+  rice:
+    tier: 1
+    aliases: ["rice"]
+  garlic:
+    tier: 1
+    aliases: ["garlic"]
+"#;
+    Ok(ScoringConfig::from_yaml(yaml)?)
+}
+
+fn recipe(name: &str, protein: &str, cook_time: &str, extra: &str) -> String {
+    format!(
+        r#"{{"name": "{name}", "primary_protein": "{protein}", "cook_time": "{cook_time}",
+            "ingredients": ["1 cup rice", "2 garlic cloves", "1 lb {protein}"]{extra}}}"#
+    )
+}
+
+fn collection(recipes: &[String]) -> Result<RecipeCollection, Box<dyn std::error::Error>> {
+    Ok(RecipeCollection::from_json(&format!("[{}]", recipes.join(",")))?)
+}
+
+fn household() -> Result<HouseholdModel, Box<dyn std::error::Error>> {
+    Ok(HouseholdModel::from_yaml("family:\n  members: []\nstores: {}")?)
+}
+
+/// A Monday.
+fn monday() -> Result<NaiveDate, Box<dyn std::error::Error>> {
+    NaiveDate::from_ymd_opt(2026, 10, 19).ok_or_else(|| "bad date".into())
+}
+
+fn names<'a>(plan: &'a MealPlan<'_>) -> Vec<&'a str> {
+    plan.meals.iter().map(|m| m.recipe.name.as_str()).collect()
+}
+
+#[test]
+fn test_highest_scores_rotate_proteins() -> TestResult {
+    let recipes = collection(&[
+        recipe("Chicken A", "chicken", "30 min", ""),
+        recipe("Chicken B", "chicken", "30 min", ""),
+        recipe("Chicken C", "chicken", "30 min", ""),
+        recipe("Tofu", "tofu", "30 min", ""),
+        recipe("Pork", "pork", "30 min", ""),
+    ])?;
+    let household = household()?;
+    let diners = Diners::everyone(&household.family);
+    let plan = plan_meals(&recipes, &test_config()?, &DimensionRegistry::builtin(), &diners, &PlanConstraints::new(monday()?, 4));
+
+    // Two chickens at most, never on consecutive nights
+    assert_eq!(names(&plan), vec!["Chicken A", "Tofu", "Chicken B", "Pork"]);
+    let skipped: Vec<(&str, &str)> = plan
+        .passed_over
+        .iter()
+        .map(|p| (p.recipe.name.as_str(), p.reason.as_str()))
+        .collect();
+    assert_eq!(skipped, vec![("Chicken C", "already have 2 chicken meal(s)")]);
+    Ok(())
+}
+
+#[test]
+fn test_long_cooks_wait_for_the_weekend() -> TestResult {
+    let recipes = collection(&[
+        recipe("Braised Chicken", "chicken", "2 hours", ""),
+        recipe("Tofu", "tofu", "20 min", ""),
+        recipe("Pork", "pork", "25 min", ""),
+    ])?;
+    let household = household()?;
+    let diners = Diners::everyone(&household.family);
+    let thursday = monday()?.checked_add_days(Days::new(3)).ok_or("bad date")?;
+    let plan = plan_meals(&recipes, &test_config()?, &DimensionRegistry::builtin(), &diners, &PlanConstraints::new(thursday, 3));
+
+    let days: Vec<(Weekday, &str)> = plan.meals.iter().map(|m| (m.date.weekday(), m.recipe.name.as_str())).collect();
+    assert_eq!(
+        days,
+        vec![(Weekday::Thu, "Tofu"), (Weekday::Fri, "Braised Chicken"), (Weekday::Sat, "Pork")]
+    );
+    assert!(plan.meals[1].reasons.contains(&"120 min, so it goes on Fri".to_string()));
+    Ok(())
+}
+
+#[test]
+fn test_recent_and_stretch_recipes_are_limited() -> TestResult {
+    let recipes = collection(&[
+        recipe("Recent Chicken", "chicken", "20 min", r#", "last_made": "2026-10-12""#),
+        recipe("Lamb One", "lamb", "20 min", r#", "ingredients": ["1 lb lamb", "sumac"]"#),
+        recipe("Lamb Two", "lamb_shoulder", "20 min", r#", "ingredients": ["2 lb lamb shoulder", "za'atar"]"#),
+        recipe("Beef", "beef", "20 min", ""),
+    ])?;
+    let household = household()?;
+    let diners = Diners::everyone(&household.family);
+    let plan = plan_meals(&recipes, &test_config()?, &DimensionRegistry::builtin(), &diners, &PlanConstraints::new(monday()?, 3));
+
+    assert_eq!(names(&plan), vec!["Beef", "Lamb One"]);
+    let reasons: Vec<&str> = plan.passed_over.iter().map(|p| p.reason.as_str()).collect();
+    assert_eq!(reasons, vec!["made 2026-10-12, within 2 weeks", "already have 1 stretch pick(s)"]);
+    Ok(())
+}
+
+#[test]
+fn test_hard_conflicts_block_only_their_day() -> TestResult {
+    let recipes = collection(&[recipe("Chicken", "chicken", "20 min", ""), recipe("Tofu", "tofu", "20 min", "")])?;
+    let household = HouseholdModel::from_yaml(
+        "family:\n  members:\n    - name: David\n      vegetarian_days: [mon]\nstores: {}",
+    )?;
+    let diners = Diners::everyone(&household.family);
+    let plan = plan_meals(&recipes, &test_config()?, &DimensionRegistry::builtin(), &diners, &PlanConstraints::new(monday()?, 2));
+
+    let days: Vec<(Weekday, &str)> = plan.meals.iter().map(|m| (m.date.weekday(), m.recipe.name.as_str())).collect();
+    assert_eq!(days, vec![(Weekday::Mon, "Tofu"), (Weekday::Tue, "Chicken")]);
+    Ok(())
+}
+
+#[test]
+fn test_registered_dimension_changes_the_pick() -> TestResult {
+    /// Full marks for anything tagged "kid favourite".
+    struct KidFavourite;
+    impl ScoringDimension for KidFavourite {
+        fn name(&self) -> &'static str {
+            "Kid Favourite"
+        }
+        fn key(&self) -> &'static str {
+            "kid_favourite"
+        }
+        fn compute(&self, recipe: &Recipe, _config: &ScoringConfig, _context: &ScoringContext<'_>) -> f64 {
+            if recipe.tags.iter().any(|t| t == "kid favourite") {
+                100.0
+            } else {
+                0.0
+            }
+        }
+    }
+
+    let recipes = collection(&[
+        recipe("Chicken", "chicken", "20 min", ""),
+        recipe("Tofu", "tofu", "20 min", r#", "tags": ["kid favourite"]"#),
+    ])?;
+    let household = household()?;
+    let diners = Diners::everyone(&household.family);
+    let mut config = test_config()?;
+    config.weights.set("kid_favourite", 0.20);
+    let constraints = PlanConstraints::new(monday()?, 1);
+
+    let plan = plan_meals(&recipes, &config, &DimensionRegistry::builtin(), &diners, &constraints);
+    assert_eq!(names(&plan), vec!["Chicken"]);
+
+    let mut dimensions = DimensionRegistry::builtin();
+    dimensions.register(Box::new(KidFavourite));
+    let plan = plan_meals(&recipes, &config, &dimensions, &diners, &constraints);
+    assert_eq!(names(&plan), vec!["Tofu"]);
+    assert!(plan.meals[0].score.dimensions.iter().any(|d| d.key == "kid_favourite"));
+    Ok(())
+}
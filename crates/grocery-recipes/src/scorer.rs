@@ -1,10 +1,22 @@
+use std::sync::LazyLock;
+
+use chrono::NaiveDate;
+use grocery_core::categorize::contains_word;
+use grocery_core::diet::Diners;
+use grocery_core::household::FamilyMember;
+use grocery_core::ingredient::ParsedIngredient;
 use grocery_core::recipe::Recipe;
-use grocery_core::scoring::ScoringConfig;
+use grocery_core::scoring::{FeedbackLexicon, RecencyConfig, ScoringConfig};
+use grocery_core::stage::Suitability;
+
+use crate::dimension::{DimensionRegistry, ScoringDimension};
 
 /// Per-dimension breakdown of a recipe score.
 #[derive(Debug, Clone)]
 pub struct DimensionScore {
     pub name: &'static str,
+    /// The dimension's config key, e.g. "family_fit".
+    pub key: &'static str,
     pub raw_score: f64,
     pub weight: f64,
     pub weighted: f64,
@@ -16,37 +28,110 @@ pub struct RecipeScore {
     pub overall: f64,
     pub label: &'static str,
     pub dimensions: Vec<DimensionScore>,
+    /// Each diner's own Family Fit, in table order. Empty when scored
+    /// without a household.
+    pub members: Vec<MemberFit>,
+    /// How each ingredient counted toward Ingredient Overlap and Practical
+    /// Friction, in recipe order.
+    pub ingredients: Vec<IngredientTrace>,
+}
+
+impl RecipeScore {
+    /// The least happy diner, if their preferences cost the recipe points.
+    pub fn pulled_down_by(&self) -> Option<&MemberFit> {
+        least_happy(&self.members)
+    }
+}
+
+fn least_happy(members: &[MemberFit]) -> Option<&MemberFit> {
+    members
+        .iter()
+        .filter(|m| m.net() < 0.0)
+        .min_by(|a, b| a.score.total_cmp(&b.score))
+}
+
+/// One diner's Family Fit and what moved it.
+#[derive(Debug, Clone)]
+pub struct MemberFit {
+    pub member: String,
+    pub score: f64,
+    pub adjustments: Vec<FitAdjustment>,
+}
+
+impl MemberFit {
+    /// Sum of this member's preference adjustments.
+    pub fn net(&self) -> f64 {
+        self.adjustments.iter().map(|a| a.points).sum()
+    }
+}
+
+/// How one ingredient was matched against `ingredient_map` and what it
+/// earned.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IngredientTrace {
+    /// The parsed ingredient name, e.g. "garlic cloves".
+    pub ingredient: String,
+    /// The `ingredient_map` key it matched, e.g. "garlic".
+    pub key: Option<String>,
+    /// The alias that matched, e.g. "garlic cloves".
+    pub alias: Option<String>,
+    pub tier: Option<u8>,
+    /// Ingredient Overlap points, out of 3.
+    pub points: f64,
+    /// Flavor booster keywords found in the name.
+    pub boosters: Vec<String>,
+    /// Ingredient Overlap bonus from those boosters.
+    pub booster_bonus: f64,
+    /// Practical Friction points lost: unmapped or tier 0 ingredients.
+    pub friction: f64,
+}
+
+/// A preference that moved a member's fit, e.g. "dislikes mushrooms" (-15).
+#[derive(Debug, Clone, PartialEq)]
+pub struct FitAdjustment {
+    pub reason: String,
+    pub points: f64,
+}
+
+/// What a score depends on besides the recipe and the config.
+#[derive(Debug, Clone, Default)]
+pub struct ScoringContext<'a> {
+    /// Who's eating, for Family Fit.
+    pub diners: Diners<'a>,
+    /// The day being planned for, for Recency. Without it every recipe
+    /// scores as if never made.
+    pub today: Option<NaiveDate>,
+}
+
+impl<'a> ScoringContext<'a> {
+    pub fn new(diners: Diners<'a>, today: NaiveDate) -> Self {
+        Self {
+            diners,
+            today: Some(today),
+        }
+    }
 }
 
 /// Score a recipe against the household scoring config.
 ///
-/// Returns overall score (0-100) with per-dimension breakdowns.
+/// Returns overall score (0-100) with per-dimension breakdowns, on the
+/// built-in dimensions. Family Fit only looks at the recipe itself and
+/// Recency treats it as untried; use [`score_recipe_for`] to take the
+/// family and the date into account.
 pub fn score_recipe(recipe: &Recipe, config: &ScoringConfig) -> RecipeScore {
-    let ingredient = score_ingredient_overlap(recipe, config);
-    let protein = score_protein_alignment(recipe, config);
-    let cuisine = score_cuisine_affinity(recipe, config);
-    let friction = score_practical_friction(recipe, config);
-    let family = score_family_fit(recipe);
-
-    let dimensions = vec![
-        make_dimension("Ingredient Overlap", ingredient, config.weights.ingredient_overlap),
-        make_dimension("Protein Alignment", protein, config.weights.protein_alignment),
-        make_dimension("Cuisine Affinity", cuisine, config.weights.cuisine_affinity),
-        make_dimension("Practical Friction", friction, config.weights.practical_friction),
-        make_dimension("Family Fit", family, config.weights.family_fit),
-    ];
-
-    let overall = dimensions
-        .iter()
-        .map(|d| d.weighted)
-        .sum::<f64>()
-        .clamp(0.0, 100.0);
+    score_recipe_for(recipe, config, &BUILTIN, &ScoringContext::default())
+}
 
-    RecipeScore {
-        overall,
-        label: score_label(overall),
-        dimensions,
-    }
+pub(crate) static BUILTIN: LazyLock<DimensionRegistry> = LazyLock::new(DimensionRegistry::builtin);
+
+/// Score a recipe on `dimensions` for the people at the table on a given day.
+pub fn score_recipe_for(
+    recipe: &Recipe,
+    config: &ScoringConfig,
+    dimensions: &DimensionRegistry,
+    context: &ScoringContext<'_>,
+) -> RecipeScore {
+    dimensions.score(recipe, config, context)
 }
 
 /// Map a numeric score to a human-readable label.
@@ -64,83 +149,126 @@ pub fn score_label(score: f64) -> &'static str {
     }
 }
 
-fn make_dimension(name: &'static str, raw_score: f64, weight: f64) -> DimensionScore {
-    DimensionScore {
-        name,
-        raw_score,
-        weight,
-        weighted: raw_score * weight,
+// ── Dimension 1: Ingredient Overlap ─────────────────────────────
+
+pub struct IngredientOverlap;
+
+impl ScoringDimension for IngredientOverlap {
+    fn name(&self) -> &'static str {
+        "Ingredient Overlap"
     }
-}
 
-// ── Dimension 1: Ingredient Overlap ─────────────────────────────
+    fn key(&self) -> &'static str {
+        "ingredient_overlap"
+    }
 
-fn score_ingredient_overlap(recipe: &Recipe, config: &ScoringConfig) -> f64 {
-    if recipe.ingredients.is_empty() {
-        return 0.0;
+    fn compute(&self, recipe: &Recipe, config: &ScoringConfig, _context: &ScoringContext<'_>) -> f64 {
+        score_ingredient_overlap(&recipe.parsed_ingredients(), config)
     }
+}
 
-    let mut points = 0.0;
-    for ingredient in &recipe.ingredients {
-        points += match resolve_ingredient_tier(ingredient, config) {
-            Some(1) => 3.0,
-            Some(2) => 2.0,
-            Some(3) => 1.0,
-            Some(0) => 0.5,
-            _ => 0.0,
-        };
+/// Overlap points by tier: staples we always buy score highest.
+fn tier_points(tier: Option<u8>) -> f64 {
+    match tier {
+        Some(1) => 3.0,
+        Some(2) => 2.0,
+        Some(3) => 1.0,
+        Some(0) => 0.5,
+        _ => 0.0,
     }
+}
 
-    let max_possible = recipe.ingredients.len() as f64 * 3.0;
-    let mut score = (points / max_possible) * 100.0;
+const MAX_TIER_POINTS: f64 = 3.0;
+const HIGH_BOOSTER_BONUS: f64 = 10.0;
+const MEDIUM_BOOSTER_BONUS: f64 = 5.0;
+const UNMAPPED_FRICTION: f64 = -5.0;
+const TIER_0_FRICTION: f64 = -10.0;
 
-    // Apply flavor booster bonuses
-    if let Some(ref boosters) = config.flavor_boosters {
-        for ingredient in &recipe.ingredients {
-            let lower = ingredient.to_lowercase();
-            for keyword in &boosters.high {
-                if lower.contains(&keyword.to_lowercase()) {
-                    score += 10.0;
+/// Match every ingredient against the config and record what it earns.
+pub fn trace_ingredients(ingredients: &[ParsedIngredient], config: &ScoringConfig) -> Vec<IngredientTrace> {
+    ingredients
+        .iter()
+        .map(|ingredient| {
+            let matched = resolve_ingredient(&ingredient.name, config);
+            let tier = matched.map(|(_, _, tier)| tier);
+
+            let lower = ingredient.name.to_lowercase();
+            let mut boosters = Vec::new();
+            let mut booster_bonus = 0.0;
+            if let Some(ref config) = config.flavor_boosters {
+                for (keywords, bonus) in [(&config.high, HIGH_BOOSTER_BONUS), (&config.medium, MEDIUM_BOOSTER_BONUS)] {
+                    for keyword in keywords.iter().filter(|k| lower.contains(&k.to_lowercase())) {
+                        boosters.push(keyword.clone());
+                        booster_bonus += bonus;
+                    }
                 }
             }
-            for keyword in &boosters.medium {
-                if lower.contains(&keyword.to_lowercase()) {
-                    score += 5.0;
-                }
+
+            IngredientTrace {
+                ingredient: ingredient.name.clone(),
+                key: matched.map(|(key, _, _)| key.to_string()),
+                alias: matched.map(|(_, alias, _)| alias.to_string()),
+                tier,
+                points: tier_points(tier),
+                boosters,
+                booster_bonus,
+                friction: match tier {
+                    None => UNMAPPED_FRICTION,
+                    Some(0) => TIER_0_FRICTION,
+                    Some(_) => 0.0,
+                },
             }
-        }
+        })
+        .collect()
+}
+
+fn score_ingredient_overlap(ingredients: &[ParsedIngredient], config: &ScoringConfig) -> f64 {
+    if ingredients.is_empty() {
+        return 0.0;
     }
 
-    score.clamp(0.0, 100.0)
+    let trace = trace_ingredients(ingredients, config);
+    let points: f64 = trace.iter().map(|t| t.points).sum();
+    let max_possible = ingredients.len() as f64 * MAX_TIER_POINTS;
+    let bonus: f64 = trace.iter().map(|t| t.booster_bonus).sum();
+    ((points / max_possible) * 100.0 + bonus).clamp(0.0, 100.0)
 }
 
-/// Substring-match a recipe ingredient string against all aliases in the config.
+/// Substring-match a parsed ingredient name against all aliases in the config.
 ///
-/// Recipe ingredients are full descriptions like "8 garlic cloves, thinly sliced".
-/// Returns the highest tier among all matching aliases.
-fn resolve_ingredient_tier(ingredient: &str, config: &ScoringConfig) -> Option<u8> {
+/// Names come from [`ParsedIngredient::name`], so "8 garlic cloves, thinly sliced"
+/// is matched as "garlic". The mapping is [`ScoringConfig::mapping_for`]'s pick,
+/// the same one the list generator uses; returns its key, the alias that
+/// matched and its tier.
+fn resolve_ingredient<'a>(ingredient: &str, config: &'a ScoringConfig) -> Option<(&'a str, &'a str, u8)> {
     let lower = ingredient.to_lowercase();
-    let mut best_tier: Option<u8> = None;
-
-    for mapping in config.ingredient_map.values() {
-        for alias in &mapping.aliases {
-            if lower.contains(&alias.to_lowercase()) {
-                match best_tier {
-                    None => best_tier = Some(mapping.tier),
-                    Some(current) if mapping.tier > current => {
-                        best_tier = Some(mapping.tier);
-                    }
-                    _ => {}
-                }
-            }
-        }
-    }
-
-    best_tier
+    let (key, mapping) = config.mapping_for(ingredient)?;
+    let alias = mapping
+        .aliases
+        .iter()
+        .filter(|alias| lower.contains(&alias.to_lowercase()))
+        .max_by(|a, b| a.len().cmp(&b.len()).then_with(|| b.cmp(a)))?;
+    Some((key, alias.as_str(), mapping.tier))
 }
 
 // ── Dimension 2: Protein Alignment ──────────────────────────────
 
+pub struct ProteinAlignment;
+
+impl ScoringDimension for ProteinAlignment {
+    fn name(&self) -> &'static str {
+        "Protein Alignment"
+    }
+
+    fn key(&self) -> &'static str {
+        "protein_alignment"
+    }
+
+    fn compute(&self, recipe: &Recipe, config: &ScoringConfig, _context: &ScoringContext<'_>) -> f64 {
+        score_protein_alignment(recipe, config)
+    }
+}
+
 fn score_protein_alignment(recipe: &Recipe, config: &ScoringConfig) -> f64 {
     let Some(ref protein) = recipe.primary_protein else {
         return 50.0;
@@ -166,6 +294,22 @@ fn score_protein_alignment(recipe: &Recipe, config: &ScoringConfig) -> f64 {
 
 // ── Dimension 3: Cuisine Affinity ───────────────────────────────
 
+pub struct CuisineAffinity;
+
+impl ScoringDimension for CuisineAffinity {
+    fn name(&self) -> &'static str {
+        "Cuisine Affinity"
+    }
+
+    fn key(&self) -> &'static str {
+        "cuisine_affinity"
+    }
+
+    fn compute(&self, recipe: &Recipe, config: &ScoringConfig, _context: &ScoringContext<'_>) -> f64 {
+        score_cuisine_affinity(recipe, config)
+    }
+}
+
 fn score_cuisine_affinity(recipe: &Recipe, config: &ScoringConfig) -> f64 {
     let mut best: Option<u32> = None;
 
@@ -195,46 +339,80 @@ fn score_cuisine_affinity(recipe: &Recipe, config: &ScoringConfig) -> f64 {
 
 // ── Dimension 4: Practical Friction ─────────────────────────────
 
-fn score_practical_friction(recipe: &Recipe, config: &ScoringConfig) -> f64 {
-    let mut score: f64 = 80.0;
+pub struct PracticalFriction;
 
-    for ingredient in &recipe.ingredients {
-        match resolve_ingredient_tier(ingredient, config) {
-            None => score -= 5.0,
-            Some(0) => score -= 10.0,
-            _ => {}
-        }
+impl ScoringDimension for PracticalFriction {
+    fn name(&self) -> &'static str {
+        "Practical Friction"
     }
 
-    score.clamp(0.0, 100.0)
+    fn key(&self) -> &'static str {
+        "practical_friction"
+    }
+
+    fn compute(&self, recipe: &Recipe, config: &ScoringConfig, _context: &ScoringContext<'_>) -> f64 {
+        score_practical_friction(&recipe.parsed_ingredients(), config)
+    }
+}
+
+fn score_practical_friction(ingredients: &[ParsedIngredient], config: &ScoringConfig) -> f64 {
+    let lost: f64 = trace_ingredients(ingredients, config).iter().map(|t| t.friction).sum();
+    (80.0 + lost).clamp(0.0, 100.0)
 }
 
 // ── Dimension 5: Family Fit ─────────────────────────────────────
 
+pub struct FamilyFit;
+
+impl ScoringDimension for FamilyFit {
+    fn name(&self) -> &'static str {
+        "Family Fit"
+    }
+
+    fn key(&self) -> &'static str {
+        "family_fit"
+    }
+
+    fn compute(&self, recipe: &Recipe, _config: &ScoringConfig, context: &ScoringContext<'_>) -> f64 {
+        aggregate_family_fit(&member_fits(recipe, &context.diners)).unwrap_or_else(|| score_family_fit(recipe))
+    }
+
+    /// Each diner's fit with what moved it, then who pulled it down.
+    fn explain(&self, recipe: &Recipe, _config: &ScoringConfig, context: &ScoringContext<'_>) -> Vec<String> {
+        let members = member_fits(recipe, &context.diners);
+        let mut lines: Vec<String> = members
+            .iter()
+            .map(|fit| {
+                let reasons: Vec<String> = fit
+                    .adjustments
+                    .iter()
+                    .map(|a| format!("{} ({:+.0})", a.reason, a.points))
+                    .collect();
+                if reasons.is_empty() {
+                    format!("{}: {:.0}/100", fit.member, fit.score)
+                } else {
+                    format!("{}: {:.0}/100, {}", fit.member, fit.score, reasons.join(", "))
+                }
+            })
+            .collect();
+        if let Some(fit) = least_happy(&members) {
+            lines.push(format!("Pulled down by {}", fit.member));
+        }
+        lines
+    }
+}
+
 fn score_family_fit(recipe: &Recipe) -> f64 {
     let mut score: f64 = 60.0;
 
     // Servings bonus
-    if let Some(ref s) = recipe.servings {
-        if let Some(n) = extract_first_number(s) {
-            if n >= 4 {
-                score += 20.0;
-            }
-        }
+    if recipe.servings_range().is_some_and(|s| s.min >= 4) {
+        score += 20.0;
     }
 
-    // Cook time bonus (only if minutes, not hours)
-    if let Some(ref ct) = recipe.cook_time {
-        if !ct.is_empty() {
-            let lower = ct.to_lowercase();
-            if !lower.contains("hour") {
-                if let Some(minutes) = extract_first_number(ct) {
-                    if minutes <= 30 {
-                        score += 10.0;
-                    }
-                }
-            }
-        }
+    // Cook time bonus, on the same reading as the planner's weeknight cap
+    if recipe.cook_minutes().is_some_and(|minutes| minutes <= 30) {
+        score += 10.0;
     }
 
     // Tag-based bonus
@@ -252,25 +430,255 @@ fn score_family_fit(recipe: &Recipe) -> f64 {
     score.clamp(0.0, 100.0)
 }
 
-/// Extract the first integer from a string. E.g., "6-8 servings" -> Some(6).
-fn extract_first_number(s: &str) -> Option<u32> {
-    let mut start = None;
-    for (i, c) in s.chars().enumerate() {
-        if c.is_ascii_digit() {
-            if start.is_none() {
-                start = Some(i);
-            }
-        } else if start.is_some() {
+/// Each diner's fit, in table order.
+pub(crate) fn member_fits(recipe: &Recipe, diners: &Diners<'_>) -> Vec<MemberFit> {
+    let base = score_family_fit(recipe);
+    diners
+        .members
+        .iter()
+        .map(|member| score_member_fit(recipe, member, base))
+        .collect()
+}
+
+/// Points for each liked ingredient, up to [`MAX_LIKED_INGREDIENT_POINTS`].
+const LIKED_INGREDIENT_POINTS: f64 = 5.0;
+const MAX_LIKED_INGREDIENT_POINTS: f64 = 15.0;
+const DISLIKED_INGREDIENT_POINTS: f64 = -15.0;
+const LIKED_PROTEIN_POINTS: f64 = 10.0;
+const DISLIKED_PROTEIN_POINTS: f64 = -20.0;
+const LIKED_CUISINE_POINTS: f64 = 10.0;
+const DISLIKED_CUISINE_POINTS: f64 = -15.0;
+
+/// One member's fit: the recipe's base family fit moved by their likes and
+/// dislikes, and by anything too spicy or hard to eat at their age.
+fn score_member_fit(recipe: &Recipe, member: &FamilyMember, base: f64) -> MemberFit {
+    let mut adjustments = Vec::new();
+    let lines: Vec<String> = recipe.ingredients.iter().map(|i| i.to_lowercase()).collect();
+    let has_ingredient = |word: &str| {
+        let word = word.to_lowercase();
+        lines.iter().any(|line| contains_word(line, &word))
+    };
+
+    let mut liked_points = 0.0;
+    for like in member.likes.iter().filter(|l| has_ingredient(l)) {
+        let points = LIKED_INGREDIENT_POINTS.min(MAX_LIKED_INGREDIENT_POINTS - liked_points);
+        if points <= 0.0 {
             break;
         }
+        liked_points += points;
+        adjustments.push(FitAdjustment {
+            reason: format!("likes {like}"),
+            points,
+        });
+    }
+    for dislike in member.dislikes.iter().filter(|d| has_ingredient(d)) {
+        adjustments.push(FitAdjustment {
+            reason: format!("dislikes {dislike}"),
+            points: DISLIKED_INGREDIENT_POINTS,
+        });
+    }
+
+    if let Some(protein) = &recipe.primary_protein {
+        let protein = normalize_key(protein);
+        let matches = |p: &&String| {
+            let key = normalize_key(p);
+            protein.contains(&key) || key.contains(&protein)
+        };
+        if let Some(p) = member.liked_proteins.iter().find(matches) {
+            adjustments.push(FitAdjustment {
+                reason: format!("likes {p}"),
+                points: LIKED_PROTEIN_POINTS,
+            });
+        }
+        if let Some(p) = member.disliked_proteins.iter().find(matches) {
+            adjustments.push(FitAdjustment {
+                reason: format!("dislikes {p}"),
+                points: DISLIKED_PROTEIN_POINTS,
+            });
+        }
+    }
+
+    let tags: Vec<String> = recipe.tags.iter().map(|t| normalize_key(t)).collect();
+    let tagged = |cuisine: &&String| {
+        let key = normalize_key(cuisine);
+        tags.iter().any(|t| t.contains(&key) || key.contains(t.as_str()))
+    };
+    if let Some(c) = member.liked_cuisines.iter().find(tagged) {
+        adjustments.push(FitAdjustment {
+            reason: format!("likes {c} food"),
+            points: LIKED_CUISINE_POINTS,
+        });
+    }
+    if let Some(c) = member.disliked_cuisines.iter().find(tagged) {
+        adjustments.push(FitAdjustment {
+            reason: format!("dislikes {c} food"),
+            points: DISLIKED_CUISINE_POINTS,
+        });
+    }
+
+    for concern in Suitability::for_member(recipe, member).concerns {
+        adjustments.push(FitAdjustment {
+            reason: concern.to_string(),
+            points: concern.points,
+        });
     }
 
-    let start = start?;
-    let end = s[start..]
-        .find(|c: char| !c.is_ascii_digit())
-        .map_or(s.len(), |offset| start + offset);
+    let net: f64 = adjustments.iter().map(|a| a.points).sum();
+    MemberFit {
+        member: member.name.clone(),
+        score: (base + net).clamp(0.0, 100.0),
+        adjustments,
+    }
+}
+
+/// Mean member fit averaged with the lowest, so a single unhappy member
+/// drags the recipe down; `None` with nobody at the table.
+fn aggregate_family_fit(members: &[MemberFit]) -> Option<f64> {
+    let lowest = members.iter().map(|m| m.score).reduce(f64::min)?;
+    let mean = members.iter().map(|m| m.score).sum::<f64>() / members.len() as f64;
+    Some((mean + lowest) / 2.0)
+}
 
-    s[start..end].parse().ok()
+fn normalize_key(name: &str) -> String {
+    name.trim().to_lowercase().replace([' ', '-'], "_")
+}
+
+// ── Dimension 6: Recency ────────────────────────────────────────
+
+pub struct Recency;
+
+impl ScoringDimension for Recency {
+    fn name(&self) -> &'static str {
+        "Recency"
+    }
+
+    fn key(&self) -> &'static str {
+        "recency"
+    }
+
+    fn compute(&self, recipe: &Recipe, config: &ScoringConfig, context: &ScoringContext<'_>) -> f64 {
+        score_recency(recipe, &config.recency, context.today)
+    }
+
+    fn explain(&self, recipe: &Recipe, config: &ScoringConfig, context: &ScoringContext<'_>) -> Vec<String> {
+        let config = &config.recency;
+        let Some(made) = recipe.last_made else {
+            return vec!["never made".to_string()];
+        };
+        let Some(today) = context.today else {
+            return vec![format!("last made {made}")];
+        };
+        let days = (today - made).num_days().max(0);
+        let line = if days < i64::from(config.window_days) {
+            format!("made {made}, {days} days ago, within the {}-day window", config.window_days)
+        } else if score_recency(recipe, config, Some(today)) >= 100.0 {
+            format!("made {made}, {days} days ago; a well-rated recipe due a comeback")
+        } else {
+            format!("made {made}, {days} days ago")
+        };
+        vec![line]
+    }
+}
+
+/// Share of full marks a recipe made at the very end of the window gets;
+/// it climbs linearly from 0 the day it was made.
+const RECENT_CEILING: f64 = 60.0;
+
+fn score_recency(recipe: &Recipe, config: &RecencyConfig, today: Option<NaiveDate>) -> f64 {
+    let baseline = f64::from(config.baseline_score).clamp(0.0, 100.0);
+    let (Some(today), Some(made)) = (today, recipe.last_made) else {
+        return baseline;
+    };
+    let days = (today - made).num_days().max(0) as f64;
+    let window = f64::from(config.window_days);
+
+    if days < window {
+        return RECENT_CEILING * days / window;
+    }
+    let revive = config.revive_after_days.is_some_and(|after| days >= f64::from(after))
+        && recipe.rating.is_some_and(|r| r >= config.revive_min_rating);
+    if revive {
+        100.0
+    } else {
+        baseline
+    }
+}
+
+// ── Dimension 7: Track Record ───────────────────────────────────
+
+pub struct TrackRecord;
+
+impl ScoringDimension for TrackRecord {
+    fn name(&self) -> &'static str {
+        "Track Record"
+    }
+
+    fn key(&self) -> &'static str {
+        "track_record"
+    }
+
+    fn compute(&self, recipe: &Recipe, config: &ScoringConfig, _context: &ScoringContext<'_>) -> f64 {
+        score_track_record(recipe, &config.feedback_lexicon)
+    }
+
+    /// The rating and history, then the feedback cues found.
+    fn explain(&self, recipe: &Recipe, config: &ScoringConfig, _context: &ScoringContext<'_>) -> Vec<String> {
+        let mut history = vec![match recipe.rating {
+            Some(stars) => format!("rated {stars}/5"),
+            None => "unrated".to_string(),
+        }];
+        history.push(match recipe.times_made {
+            0 => "never made".to_string(),
+            1 => "made once".to_string(),
+            n => format!("made {n} times"),
+        });
+        if recipe.needs_fixing {
+            history.push("needs fixing".to_string());
+        }
+        let mut lines = vec![history.join(", ")];
+
+        let cues = config.feedback_lexicon.cues(&recipe.feedback);
+        let marked: Vec<String> = cues
+            .positive
+            .iter()
+            .map(|c| format!("+{c}"))
+            .chain(cues.negative.iter().map(|c| format!("-{c}")))
+            .collect();
+        if !marked.is_empty() {
+            lines.push(format!("feedback: {}", marked.join(", ")));
+        }
+        lines
+    }
+}
+
+/// Score for a recipe nobody has rated; also the starting point before
+/// history and feedback.
+const UNRATED_TRACK_RECORD: f64 = 50.0;
+/// Points for each time made, up to [`MAX_TIMES_MADE_POINTS`].
+const TIMES_MADE_POINTS: f64 = 5.0;
+const MAX_TIMES_MADE_POINTS: f64 = 20.0;
+const NEEDS_FIXING_POINTS: f64 = -40.0;
+const POSITIVE_CUE_POINTS: f64 = 8.0;
+const NEGATIVE_CUE_POINTS: f64 = -12.0;
+/// Most that feedback can move the score either way.
+const MAX_FEEDBACK_POINTS: f64 = 25.0;
+
+/// How the recipe has gone before: stars (20 points each) or 50 if
+/// unrated, plus points for each time made, less a flat penalty when it
+/// needs fixing, moved by sentiment cues in the feedback.
+fn score_track_record(recipe: &Recipe, lexicon: &FeedbackLexicon) -> f64 {
+    let mut score = recipe
+        .rating
+        .map_or(UNRATED_TRACK_RECORD, |stars| f64::from(stars.min(5)) * 20.0);
+    score += (TIMES_MADE_POINTS * f64::from(recipe.times_made)).min(MAX_TIMES_MADE_POINTS);
+    if recipe.needs_fixing {
+        score += NEEDS_FIXING_POINTS;
+    }
+    let cues = lexicon.cues(&recipe.feedback);
+    let feedback = POSITIVE_CUE_POINTS * cues.positive.len() as f64
+        + NEGATIVE_CUE_POINTS * cues.negative.len() as f64;
+    score += feedback.clamp(-MAX_FEEDBACK_POINTS, MAX_FEEDBACK_POINTS);
+    score.clamp(0.0, 100.0)
 }
 
 #[cfg(test)]
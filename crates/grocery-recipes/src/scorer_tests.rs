@@ -137,6 +137,61 @@ fn test_score_ingredient_overlap_capped_at_100() -> TestResult {
     Ok(())
 }
 
+#[test]
+fn test_ingredient_trace_explains_points() -> TestResult {
+    let config = test_config()?;
+    let recipe = make_recipe(r#"{
+        "name": "T", "url": "https://x.com",
+        "ingredients": ["1 English cucumber", "1/4 cup fresh cilantro", "1 lb italian sausage", "truffle oil"]
+    }"#)?;
+    let score = score_recipe(&recipe, &config);
+    let traced = |ingredient: &str, matched: Option<(&str, &str, u8)>, points, boosters: &[&str], friction| {
+        IngredientTrace {
+            ingredient: ingredient.to_string(),
+            key: matched.map(|(key, _, _)| key.to_string()),
+            alias: matched.map(|(_, alias, _)| alias.to_string()),
+            tier: matched.map(|(_, _, tier)| tier),
+            points,
+            boosters: boosters.iter().map(|b| b.to_string()).collect(),
+            booster_bonus: 10.0 * boosters.len() as f64,
+            friction,
+        }
+    };
+    assert_eq!(
+        score.ingredients,
+        vec![
+            // The longer alias wins when both match
+            traced("English cucumber", Some(("cucumber", "english cucumber", 1)), 3.0, &[], 0.0),
+            traced("fresh cilantro", Some(("cilantro", "fresh cilantro", 1)), 3.0, &["cilantro"], 0.0),
+            traced("italian sausage", Some(("sausage", "italian sausage", 0)), 0.5, &[], -10.0),
+            traced("truffle oil", None, 0.0, &[], -5.0),
+        ]
+    );
+
+    // The trace adds up to the dimension scores
+    let points: f64 = score.ingredients.iter().map(|t| t.points).sum();
+    let bonus: f64 = score.ingredients.iter().map(|t| t.booster_bonus).sum();
+    let friction: f64 = score.ingredients.iter().map(|t| t.friction).sum();
+    assert!((score.dimensions[0].raw_score - (points / 12.0 * 100.0 + bonus)).abs() < 1e-9);
+    assert!((score.dimensions[3].raw_score - (80.0 + friction)).abs() < 1e-9);
+    Ok(())
+}
+
+#[test]
+fn test_ingredient_trace_names_the_mapping_the_list_uses() -> TestResult {
+    let config = test_config()?;
+    let recipe = make_recipe(r#"{"name": "T", "ingredients": ["1 tbsp fresh ginger lime paste"]}"#)?;
+    let score = score_recipe(&recipe, &config);
+    // "lime" is tier 1, but the longer "fresh ginger" is what the list buys
+    let (key, _) = config.mapping_for("fresh ginger lime paste").ok_or("no mapping")?;
+    assert_eq!(key, "ginger");
+    let trace = &score.ingredients[0];
+    assert_eq!(trace.key.as_deref(), Some(key));
+    assert_eq!(trace.alias.as_deref(), Some("fresh ginger"));
+    assert_eq!(trace.tier, Some(0));
+    Ok(())
+}
+
 // ── Protein Alignment ───────────────────────────────────────────
 
 #[test]
@@ -321,11 +376,25 @@ fn test_score_family_fit_hours_no_quick_bonus() -> TestResult {
     }"#)?;
     let score = score_recipe(&recipe, &config);
     let family = &score.dimensions[4];
-    // 60 base, no cook time bonus (contains "hour")
+    // 60 base, no cook time bonus (195 minutes)
     assert!((family.raw_score - 60.0).abs() < f64::EPSILON);
     Ok(())
 }
 
+#[test]
+fn test_score_family_fit_reads_cook_time_like_the_planner() -> TestResult {
+    let config = test_config()?;
+    let family_fit = |cook_time: &str| -> Result<f64, Box<dyn std::error::Error>> {
+        let json = format!(r#"{{"name": "T", "url": "https://x.com", "cook_time": {cook_time:?}}}"#);
+        Ok(score_recipe(&make_recipe(&json)?, &config).dimensions[4].raw_score)
+    };
+    assert!((family_fit("25–30 min")? - 70.0).abs() < f64::EPSILON);
+    assert!((family_fit("about 30min")? - 70.0).abs() < f64::EPSILON);
+    assert!((family_fit("1 1/2 hours")? - 60.0).abs() < f64::EPSILON);
+    assert!((family_fit("1hr")? - 60.0).abs() < f64::EPSILON);
+    Ok(())
+}
+
 #[test]
 fn test_score_family_fit_easy_tag() -> TestResult {
     let config = test_config()?;
@@ -340,6 +409,244 @@ fn test_score_family_fit_easy_tag() -> TestResult {
     Ok(())
 }
 
+fn household() -> Result<grocery_core::household::HouseholdModel, Box<dyn std::error::Error>> {
+    let yaml = r#"
+family:
+  members:
+    - name: David
+      likes: [cilantro, lime]
+      liked_cuisines: [korean]
+    - name: Christine
+      liked_proteins: [tofu]
+    - name: Daughter
+      dislikes: [mushrooms]
+      spice_tolerance: mild
+stores: {}
+"#;
+    Ok(grocery_core::household::HouseholdModel::from_yaml(yaml)?)
+}
+
+#[test]
+fn test_family_fit_aggregates_member_fit() -> TestResult {
+    use grocery_core::diet::Diners;
+
+    let config = test_config()?;
+    let household = household()?;
+    let recipe = make_recipe(r#"{
+        "name": "Spicy Tofu Bowls", "url": "https://x.com",
+        "tags": ["Korean"],
+        "primary_protein": "tofu",
+        "ingredients": ["1 block firm tofu", "2 Tbsp gochujang", "1 cup shiitake mushrooms", "1 lime"]
+    }"#)?;
+    let score = score_recipe_for(&recipe, &config, &BUILTIN, &ScoringContext {
+        diners: Diners::everyone(&household.family),
+        today: None,
+    });
+
+    let fits: Vec<(&str, f64)> = score.members.iter().map(|m| (m.member.as_str(), m.score)).collect();
+    // Base 60. David: +5 lime, +10 korean. Christine: +10 tofu.
+    // Daughter: -15 mushrooms, -15 one step over mild.
+    assert_eq!(fits, vec![("David", 75.0), ("Christine", 70.0), ("Daughter", 30.0)]);
+
+    // Mean 58.33 averaged with the lowest (30)
+    let family = &score.dimensions[4];
+    assert!((family.raw_score - (175.0 / 3.0 + 30.0) / 2.0).abs() < 1e-9,
+        "got {}", family.raw_score);
+
+    let worst = score.pulled_down_by().ok_or("expected someone to pull it down")?;
+    assert_eq!(worst.member, "Daughter");
+    let reasons: Vec<&str> = worst.adjustments.iter().map(|a| a.reason.as_str()).collect();
+    assert_eq!(reasons, vec![
+        "dislikes mushrooms",
+        "too spicy (medium heat, tolerates mild): \"2 Tbsp gochujang\"",
+    ]);
+    Ok(())
+}
+
+#[test]
+fn test_family_fit_without_preferences_matches_base() -> TestResult {
+    use grocery_core::diet::Diners;
+    use grocery_core::household::HouseholdModel;
+
+    let config = test_config()?;
+    let household = HouseholdModel::from_yaml("family:\n  members:\n    - name: A\n    - name: B\nstores: {}\n")?;
+    let recipe = make_recipe(r#"{
+        "name": "T", "url": "https://x.com",
+        "servings": "4 servings"
+    }"#)?;
+    let score = score_recipe_for(&recipe, &config, &BUILTIN, &ScoringContext {
+        diners: Diners::everyone(&household.family),
+        today: None,
+    });
+    assert!((score.dimensions[4].raw_score - 80.0).abs() < f64::EPSILON);
+    assert!(score.pulled_down_by().is_none());
+    assert!(score_recipe(&recipe, &config).members.is_empty());
+    Ok(())
+}
+
+// ── Recency ─────────────────────────────────────────────────────
+
+fn recency_on(recipe: &Recipe, config: &ScoringConfig, today: &str) -> Result<f64, Box<dyn std::error::Error>> {
+    let context = ScoringContext {
+        diners: Default::default(),
+        today: Some(today.parse()?),
+    };
+    let score = score_recipe_for(recipe, config, &BUILTIN, &context);
+    let dimension = score.dimensions.get(5).ok_or("missing Recency dimension")?;
+    assert_eq!(dimension.name, "Recency");
+    Ok(dimension.raw_score)
+}
+
+#[test]
+fn test_recency_penalises_recent_meals() -> TestResult {
+    let config = test_config()?;
+    let recipe = make_recipe(r#"{"name": "T", "url": "https://x.com", "last_made": "2024-03-01"}"#)?;
+    assert!(recency_on(&recipe, &config, "2024-03-01")?.abs() < f64::EPSILON);
+    // Two thirds of the way through the 21-day window
+    assert!((recency_on(&recipe, &config, "2024-03-15")? - 40.0).abs() < 1e-9);
+    assert!((recency_on(&recipe, &config, "2024-04-01")? - 80.0).abs() < f64::EPSILON);
+    Ok(())
+}
+
+#[test]
+fn test_recency_untried_scores_baseline() -> TestResult {
+    let config = test_config()?;
+    let recipe = make_recipe(r#"{"name": "T", "url": "https://x.com"}"#)?;
+    assert!((recency_on(&recipe, &config, "2024-03-01")? - 80.0).abs() < f64::EPSILON);
+
+    // Without a date nothing counts as recent
+    let made = make_recipe(r#"{"name": "T", "url": "https://x.com", "last_made": "2024-03-01"}"#)?;
+    let score = score_recipe(&made, &config);
+    assert!((score.dimensions[5].raw_score - 80.0).abs() < f64::EPSILON);
+    Ok(())
+}
+
+#[test]
+fn test_recency_revives_well_rated_favourites() -> TestResult {
+    let mut config = test_config()?;
+    config.recency.revive_after_days = Some(90);
+    let loved = make_recipe(r#"{"name": "T", "url": "https://x.com", "last_made": "2024-01-01", "rating": 5}"#)?;
+    let meh = make_recipe(r#"{"name": "T", "url": "https://x.com", "last_made": "2024-01-01", "rating": 3}"#)?;
+
+    assert!((recency_on(&loved, &config, "2024-03-01")? - 80.0).abs() < f64::EPSILON);
+    assert!((recency_on(&loved, &config, "2024-04-01")? - 100.0).abs() < f64::EPSILON);
+    assert!((recency_on(&meh, &config, "2024-04-01")? - 80.0).abs() < f64::EPSILON);
+    Ok(())
+}
+
+#[test]
+fn test_recency_weight_rotates_recent_meals_down() -> TestResult {
+    let mut config = test_config()?;
+    let made = make_recipe(r#"{"name": "T", "url": "https://x.com", "last_made": "2024-03-01"}"#)?;
+    let untried = make_recipe(r#"{"name": "T", "url": "https://x.com"}"#)?;
+    let today = ScoringContext {
+        diners: Default::default(),
+        today: Some("2024-03-01".parse()?),
+    };
+    // Configs without a recency weight get the default share
+    let made_score = score_recipe_for(&made, &config, &BUILTIN, &today);
+    let untried_score = score_recipe_for(&untried, &config, &BUILTIN, &today);
+    assert!((made_score.dimensions[5].weight - 0.10).abs() < f64::EPSILON);
+    assert!(made_score.dimensions[5].weighted.abs() < f64::EPSILON);
+    assert!((untried_score.overall - made_score.overall - 8.0).abs() < 1e-9);
+
+    // Made long enough ago, it's back level with the untried one
+    let later = ScoringContext {
+        diners: Default::default(),
+        today: Some("2024-06-01".parse()?),
+    };
+    let rested = score_recipe_for(&made, &config, &BUILTIN, &later).overall;
+    assert!((rested - score_recipe_for(&untried, &config, &BUILTIN, &later).overall).abs() < 1e-9);
+    assert!(rested > made_score.overall);
+
+    // Written as 0, recency is off and last_made doesn't matter
+    config.weights.set("recency", 0.0);
+    let made_score = score_recipe_for(&made, &config, &BUILTIN, &today).overall;
+    assert!((made_score - score_recipe_for(&untried, &config, &BUILTIN, &today).overall).abs() < 1e-9);
+
+    // Written as 0.10, it shrinks with the others to make room for track_record
+    config.weights.set("recency", 0.10);
+    let made_score = score_recipe_for(&made, &config, &BUILTIN, &today);
+    let untried_score = score_recipe_for(&untried, &config, &BUILTIN, &today);
+    assert!(made_score.dimensions[5].weighted.abs() < f64::EPSILON);
+    assert!((untried_score.overall - made_score.overall - 7.2).abs() < 1e-9);
+    Ok(())
+}
+
+// ── Track Record ────────────────────────────────────────────────
+
+fn track_record(recipe: &Recipe, config: &ScoringConfig) -> Result<f64, Box<dyn std::error::Error>> {
+    let score = score_recipe(recipe, config);
+    let dimension = score.dimensions.get(6).ok_or("missing Track Record dimension")?;
+    assert_eq!(dimension.name, "Track Record");
+    Ok(dimension.raw_score)
+}
+
+#[test]
+fn test_track_record_untested_is_neutral() -> TestResult {
+    let config = test_config()?;
+    let recipe = make_recipe(r#"{"name": "T", "url": "https://x.com"}"#)?;
+    assert!((track_record(&recipe, &config)? - 50.0).abs() < f64::EPSILON);
+    Ok(())
+}
+
+#[test]
+fn test_track_record_combines_rating_history_and_flags() -> TestResult {
+    let config = test_config()?;
+    let decent = make_recipe(r#"{"name": "T", "url": "https://x.com", "rating": 3, "times_made": 2}"#)?;
+    assert!((track_record(&decent, &config)? - 70.0).abs() < f64::EPSILON);
+
+    // Times made counts for at most 20
+    let staple = make_recipe(r#"{"name": "T", "url": "https://x.com", "rating": 3, "times_made": 12}"#)?;
+    assert!((track_record(&staple, &config)? - 80.0).abs() < f64::EPSILON);
+
+    let broken = make_recipe(r#"{"name": "T", "url": "https://x.com", "rating": 3, "needs_fixing": true}"#)?;
+    assert!((track_record(&broken, &config)? - 20.0).abs() < f64::EPSILON);
+    Ok(())
+}
+
+#[test]
+fn test_track_record_reads_feedback_sentiment() -> TestResult {
+    let config = test_config()?;
+    let praised = make_recipe(r#"{
+        "name": "T", "url": "https://x.com",
+        "feedback": ["Delicious", "Everyone had seconds"]
+    }"#)?;
+    assert!((track_record(&praised, &config)? - 66.0).abs() < f64::EPSILON);
+
+    let panned = make_recipe(r#"{
+        "name": "T", "url": "https://x.com",
+        "feedback": ["Bland and dry", "Too salty, never again"]
+    }"#)?;
+    // Four negative cues, capped at -25
+    assert!((track_record(&panned, &config)? - 25.0).abs() < f64::EPSILON);
+    Ok(())
+}
+
+#[test]
+fn test_proven_favourite_outranks_untested() -> TestResult {
+    // The shipped weights, with no track_record entry
+    let config = test_config()?;
+    let favourite = make_recipe(r#"{
+        "name": "Favourite", "url": "https://x.com",
+        "ingredients": ["cucumber", "limes", "firm tofu"],
+        "rating": 5, "times_made": 6, "feedback": ["Kids loved it"]
+    }"#)?;
+    let untested = make_recipe(r#"{
+        "name": "New", "url": "https://x.com",
+        "ingredients": ["cucumber", "limes", "cilantro"]
+    }"#)?;
+    let favourite = score_recipe(&favourite, &config);
+    let untested = score_recipe(&untested, &config);
+    assert!((favourite.dimensions[6].weight - 0.10).abs() < f64::EPSILON);
+    // The new recipe is the better ingredient match, but not by much
+    let overlap_gap = untested.dimensions[0].raw_score - favourite.dimensions[0].raw_score;
+    assert!(overlap_gap > 0.0 && overlap_gap < 20.0, "overlap gap {overlap_gap}");
+    assert!(favourite.overall > untested.overall,
+        "favourite {} vs untested {}", favourite.overall, untested.overall);
+    Ok(())
+}
+
 // ── Overall + Labels ────────────────────────────────────────────
 
 #[test]
@@ -374,14 +681,3 @@ fn test_score_labels() -> TestResult {
     assert_eq!(score_label(0.0), "Adventure");
     Ok(())
 }
-
-#[test]
-fn test_extract_first_number_various() -> TestResult {
-    assert_eq!(extract_first_number("6-8 servings"), Some(6));
-    assert_eq!(extract_first_number("45 minutes"), Some(45));
-    assert_eq!(extract_first_number("2 to 3 servings"), Some(2));
-    assert_eq!(extract_first_number("no numbers here"), None);
-    assert_eq!(extract_first_number(""), None);
-    assert_eq!(extract_first_number("about 30min"), Some(30));
-    Ok(())
-}
@@ -0,0 +1,130 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use grocery_core::recipe::Recipe;
+use grocery_core::scoring::ScoringConfig;
+
+use crate::collection::RecipeCollection;
+use crate::dimension::DimensionRegistry;
+use crate::scorer::{score_recipe_for, ScoringContext};
+
+/// Points for each of the fixed recipes' perishables a suggestion uses up.
+const USES_UP_POINTS: f64 = 20.0;
+/// Points lost for each new perishable a suggestion would leave over.
+const ADDS_POINTS: f64 = -10.0;
+/// Weight of the suggestion's own recipe score.
+const RECIPE_SCORE_WEIGHT: f64 = 0.5;
+
+/// Canonical `ingredient_map` keys of the perishables a recipe uses.
+pub fn perishables(recipe: &Recipe, config: &ScoringConfig) -> BTreeSet<String> {
+    recipe
+        .parsed_ingredients()
+        .iter()
+        .filter_map(|i| config.mapping_for(&i.name))
+        .filter(|(key, mapping)| mapping.is_perishable(key))
+        .map(|(key, _)| key.to_string())
+        .collect()
+}
+
+/// How well a set of recipes shares its perishables.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Sharing {
+    /// Perishables used by two or more recipes, with the recipes using them.
+    pub shared: BTreeMap<String, Vec<String>>,
+    /// Perishables only one recipe uses: likely half-used and thrown out.
+    pub leftover: BTreeSet<String>,
+}
+
+impl Sharing {
+    pub fn of(recipes: &[&Recipe], config: &ScoringConfig) -> Self {
+        let mut users: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for recipe in recipes {
+            for key in perishables(recipe, config) {
+                users.entry(key).or_default().push(recipe.name.clone());
+            }
+        }
+        let (shared, single): (BTreeMap<_, _>, BTreeMap<_, _>) =
+            users.into_iter().partition(|(_, names)| names.len() > 1);
+        Self {
+            shared,
+            leftover: single.into_keys().collect(),
+        }
+    }
+
+    /// Share of the set's perishables used by more than one recipe, 0-100.
+    pub fn score(&self) -> f64 {
+        let total = self.shared.len() + self.leftover.len();
+        if total == 0 {
+            return 0.0;
+        }
+        self.shared.len() as f64 / total as f64 * 100.0
+    }
+}
+
+/// A recipe that would use up perishables bought for the fixed recipes.
+#[derive(Debug, Clone)]
+pub struct Suggestion<'a> {
+    pub recipe: &'a Recipe,
+    /// The fixed recipes' perishables it also uses.
+    pub uses_up: Vec<String>,
+    /// Perishables it needs that the fixed recipes don't.
+    pub adds: Vec<String>,
+    /// The recipe's own score, 0-100.
+    pub overall: f64,
+    /// Ranking value: perishables used up, less new ones, plus half the
+    /// recipe score.
+    pub value: f64,
+}
+
+/// Recipes that complement `fixed` by using up its perishables.
+///
+/// Candidates must share at least one perishable with the fixed recipes and
+/// have no hard diet conflict for the context's diners; recipes marked as
+/// needing fixes are left out. Recipe scores come from `dimensions`.
+/// Returns up to `limit`, best first.
+pub fn suggest_complements<'a>(
+    collection: &'a RecipeCollection,
+    fixed: &[&Recipe],
+    config: &ScoringConfig,
+    dimensions: &DimensionRegistry,
+    context: &ScoringContext<'_>,
+    limit: usize,
+) -> Vec<Suggestion<'a>> {
+    let bought: BTreeSet<String> = fixed.iter().flat_map(|r| perishables(r, config)).collect();
+    let mut suggestions: Vec<Suggestion<'a>> = collection
+        .with_ingredients()
+        .into_iter()
+        .filter(|r| !r.needs_fixing && !fixed.iter().any(|f| f.name == r.name))
+        .filter(|r| !context.diners.conflicts(r).iter().any(|c| c.hard))
+        .filter_map(|recipe| {
+            let own = perishables(recipe, config);
+            let uses_up: Vec<String> = own.intersection(&bought).cloned().collect();
+            if uses_up.is_empty() {
+                return None;
+            }
+            let adds: Vec<String> = own.difference(&bought).cloned().collect();
+            let overall = score_recipe_for(recipe, config, dimensions, context).overall;
+            let value = USES_UP_POINTS * uses_up.len() as f64
+                + ADDS_POINTS * adds.len() as f64
+                + RECIPE_SCORE_WEIGHT * overall;
+            Some(Suggestion {
+                recipe,
+                uses_up,
+                adds,
+                overall,
+                value,
+            })
+        })
+        .collect();
+
+    suggestions.sort_by(|a, b| {
+        b.value
+            .total_cmp(&a.value)
+            .then_with(|| a.recipe.name.cmp(&b.recipe.name))
+    });
+    suggestions.truncate(limit);
+    suggestions
+}
+
+#[cfg(test)]
+#[path = "sharing_tests.rs"]
+mod tests;
@@ -0,0 +1,112 @@
+use super::*;
+use grocery_core::diet::Diners;
+use grocery_core::household::HouseholdModel;
+
+type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+fn test_config() -> Result<ScoringConfig, Box<dyn std::error::Error>> {
+    let yaml = r#"
+weights:
+  ingredient_overlap: 0.40
+  protein_alignment: 0.20
+  cuisine_affinity: 0.15
+  practical_friction: 0.15
+  family_fit: 0.10
+ingredient_map:
+  cilantro:
+    tier: 1
+    aliases: ["cilantro"]
+  limes:
+    tier: 1
+    aliases: ["lime"]
+  green_onions:
+    tier: 1
+    aliases: ["green onion", "scallion"]
+  garlic:
+    tier: 3
+    aliases: ["garlic"]
+  fish_sauce:
+    tier: 2
+    aliases: ["fish sauce"]
+  basil:
+    tier: 2
+    aliases: ["basil"]
+"#;
+    Ok(ScoringConfig::from_yaml(yaml)?)
+}
+
+fn collection() -> Result<RecipeCollection, Box<dyn std::error::Error>> {
+    let json = r#"[
+        {"name": "Larb", "ingredients": ["1 lb ground chicken", "1/2 cup cilantro", "2 limes", "3 green onions", "2 Tbsp fish sauce"]},
+        {"name": "Fish Tacos", "ingredients": ["1 lb cod", "1/4 cup cilantro", "1 lime", "2 garlic cloves"]},
+        {"name": "Scallion Pancakes", "ingredients": ["2 cups flour", "6 scallions"]},
+        {"name": "Pesto Pasta", "ingredients": ["1 lb pasta", "2 cups basil", "2 garlic cloves"]},
+        {"name": "Shrimp Salad", "ingredients": ["1 lb shrimp", "cilantro", "1 lime", "basil"]},
+        {"name": "Broken Curry", "needs_fixing": true, "ingredients": ["cilantro", "limes"]}
+    ]"#;
+    Ok(RecipeCollection::from_json(json)?)
+}
+
+#[test]
+fn test_perishables_use_canonical_names() -> TestResult {
+    let config = test_config()?;
+    let collection = collection()?;
+    let larb = collection.recipes().first().ok_or("missing recipe")?;
+    let found: Vec<String> = perishables(larb, &config).into_iter().collect();
+    // Garlic keeps and fish sauce isn't produce
+    assert_eq!(found, vec!["cilantro", "green_onions", "limes"]);
+    Ok(())
+}
+
+#[test]
+fn test_sharing_rewards_overlap() -> TestResult {
+    let config = test_config()?;
+    let collection = collection()?;
+    let recipes: Vec<&Recipe> = collection.recipes().iter().take(3).collect();
+    let sharing = Sharing::of(&recipes, &config);
+
+    assert_eq!(sharing.shared.keys().collect::<Vec<_>>(), vec!["cilantro", "green_onions", "limes"]);
+    assert_eq!(sharing.shared["limes"], vec!["Larb", "Fish Tacos"]);
+    assert!(sharing.leftover.is_empty());
+    assert!((sharing.score() - 100.0).abs() < f64::EPSILON);
+
+    let alone = Sharing::of(&recipes[..1], &config);
+    assert_eq!(alone.leftover.len(), 3);
+    assert!(alone.score().abs() < f64::EPSILON);
+    Ok(())
+}
+
+#[test]
+fn test_suggestions_use_up_leftovers() -> TestResult {
+    let config = test_config()?;
+    let collection = collection()?;
+    let household = HouseholdModel::from_yaml("family:\n  members: []\nstores: {}")?;
+    let diners = Diners::everyone(&household.family);
+    let larb = collection.find_by_name("larb").first().map(|(_, r, _)| *r).ok_or("no larb")?;
+
+    let context = ScoringContext {
+        diners,
+        today: None,
+    };
+    let suggestions = suggest_complements(&collection, &[larb], &config, &DimensionRegistry::builtin(), &context, 5);
+    let ranked: Vec<(&str, Vec<&str>, Vec<&str>)> = suggestions
+        .iter()
+        .map(|s| {
+            (
+                s.recipe.name.as_str(),
+                s.uses_up.iter().map(String::as_str).collect(),
+                s.adds.iter().map(String::as_str).collect(),
+            )
+        })
+        .collect();
+    // Pesto shares nothing; the curry needs fixing
+    assert_eq!(
+        ranked,
+        vec![
+            ("Fish Tacos", vec!["cilantro", "limes"], vec![]),
+            ("Shrimp Salad", vec!["cilantro", "limes"], vec!["basil"]),
+            ("Scallion Pancakes", vec!["green_onions"], vec![]),
+        ]
+    );
+    Ok(())
+}